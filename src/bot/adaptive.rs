@@ -0,0 +1,379 @@
+//! A bot that studies the player. It plays like the equity bot, but keeps a
+//! read on the player from their lifetime stats and the hands it has seen
+//! them play this session, and shifts its play to exploit it: more c-bet
+//! bluffs against players who fold to them, thinner value bets against
+//! calling stations, and tighter opens against players who 3-bet a lot.
+
+use rand::rngs::StdRng;
+
+use crate::game::actions::Action;
+use crate::game::state::{Street, HUMAN};
+use crate::game::view::PlayerView;
+use crate::stats::models::PlayerStats;
+
+use super::equity::{EquityBot, Tuning};
+use super::Strategy;
+
+/// Rates of a typical player, which the read starts from.
+const TYPICAL_VPIP: f64 = 0.30;
+const TYPICAL_THREE_BET: f64 = 0.07;
+const TYPICAL_FOLD_TO_CBET: f64 = 0.45;
+const TYPICAL_WTSD: f64 = 0.28;
+/// Share of bets and raises among bets, raises and calls (an AF of 1).
+const TYPICAL_AGGRESSION: f64 = 0.5;
+
+/// How many observations the typical player counts for.
+const PRIOR_WEIGHT: f64 = 10.0;
+
+/// Most observations the lifetime stats count for, so that this session's
+/// play can still move the read when the player changes gears.
+const LIFETIME_WEIGHT: u64 = 50;
+
+/// What the bot believes about the player, as rates from 0.0 to 1.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Read {
+    pub vpip: f64,
+    pub three_bet: f64,
+    pub fold_to_cbet: f64,
+    pub wtsd: f64,
+    /// Share of bets and raises among the player's bets, raises and calls.
+    pub aggression: f64,
+}
+
+impl Read {
+    /// Blend lifetime and session stats, each rate weighted by how much
+    /// has been seen of it.
+    pub fn new(lifetime: &PlayerStats, session: &PlayerStats) -> Self {
+        let aggressive = |s: &PlayerStats| (s.bets + s.raises, s.bets + s.raises + s.calls);
+        Self {
+            vpip: blend(
+                (lifetime.vpip_hands, lifetime.total_hands),
+                (session.vpip_hands, session.total_hands),
+                TYPICAL_VPIP,
+            ),
+            three_bet: blend(
+                (lifetime.three_bet_hands, lifetime.three_bet_opportunities),
+                (session.three_bet_hands, session.three_bet_opportunities),
+                TYPICAL_THREE_BET,
+            ),
+            fold_to_cbet: blend(
+                (
+                    lifetime.fold_to_cbet_hands,
+                    lifetime.fold_to_cbet_opportunities,
+                ),
+                (
+                    session.fold_to_cbet_hands,
+                    session.fold_to_cbet_opportunities,
+                ),
+                TYPICAL_FOLD_TO_CBET,
+            ),
+            wtsd: blend(
+                (lifetime.wtsd_hands, lifetime.wtsd_opportunities),
+                (session.wtsd_hands, session.wtsd_opportunities),
+                TYPICAL_WTSD,
+            ),
+            aggression: blend(
+                aggressive(lifetime),
+                aggressive(session),
+                TYPICAL_AGGRESSION,
+            ),
+        }
+    }
+
+    /// How far the player plays like a calling station: loose, passive and
+    /// going to showdown, from 0.0 (not at all) to 0.5.
+    pub fn calling_station(&self) -> f64 {
+        ((self.vpip - TYPICAL_VPIP)
+            + (self.wtsd - TYPICAL_WTSD)
+            + (TYPICAL_AGGRESSION - self.aggression) * 0.5)
+            .clamp(0.0, 0.5)
+    }
+
+    /// The adjustments that exploit this read.
+    pub fn tuning(&self) -> Tuning {
+        let station = self.calling_station();
+        Tuning {
+            // Up to 12% less equity to bet for value into a station
+            thinner_value: station * 0.25,
+            // and bluff it less
+            bluffs: (1.0 - 1.5 * station).max(0.25),
+            cbet_bluffs: (self.fold_to_cbet / TYPICAL_FOLD_TO_CBET).clamp(0.5, 2.0),
+            tighter_opens: ((self.three_bet - TYPICAL_THREE_BET) * 0.8).clamp(0.0, 0.1),
+        }
+    }
+}
+
+/// A rate from `(hits, opportunities)` counts, starting from `typical` and
+/// moving towards what has been seen.
+fn blend(lifetime: (u64, u64), session: (u64, u64), typical: f64) -> f64 {
+    let (hits, opportunities) = lifetime;
+    let lifetime_weight = opportunities.min(LIFETIME_WEIGHT) as f64;
+    let lifetime_rate = if opportunities == 0 {
+        typical
+    } else {
+        hits as f64 / opportunities as f64
+    };
+    let (hits, opportunities) = session;
+    (PRIOR_WEIGHT * typical + lifetime_weight * lifetime_rate + hits as f64)
+        / (PRIOR_WEIGHT + lifetime_weight + opportunities as f64)
+}
+
+pub struct AdaptiveBot {
+    bot: EquityBot,
+    /// The player's stats from before this session.
+    lifetime: PlayerStats,
+    /// The player's stats from the hands this bot has seen this session.
+    session: PlayerStats,
+}
+
+impl AdaptiveBot {
+    pub fn new(aggression: f64) -> Self {
+        Self::from_bot(EquityBot::new(aggression))
+    }
+
+    /// Create a bot whose randomized choices are fully determined by `seed`.
+    pub fn with_seed(aggression: f64, seed: u64) -> Self {
+        Self::from_bot(EquityBot::with_seed(aggression, seed))
+    }
+
+    pub fn with_rng(aggression: f64, rng: StdRng) -> Self {
+        Self::from_bot(EquityBot::with_rng(aggression, rng))
+    }
+
+    fn from_bot(bot: EquityBot) -> Self {
+        Self {
+            bot,
+            lifetime: PlayerStats::default(),
+            session: PlayerStats::default(),
+        }
+    }
+
+    /// The current read on the player.
+    pub fn read(&self) -> Read {
+        Read::new(&self.lifetime, &self.session)
+    }
+
+    /// Stats of the player's play this session, as far as this bot has
+    /// seen it.
+    pub fn session(&self) -> &PlayerStats {
+        &self.session
+    }
+}
+
+impl Strategy for AdaptiveBot {
+    fn aggression(&self) -> f64 {
+        self.bot.aggression
+    }
+
+    fn decide(&self, view: &PlayerView) -> Action {
+        self.bot.decide_with(view, &self.read().tuning())
+    }
+
+    fn player_stats(&mut self, stats: &PlayerStats) {
+        // A new session: what was seen of the last one is in the lifetime
+        // stats now
+        self.lifetime = stats.clone();
+        self.session = PlayerStats::default();
+    }
+
+    fn hand_ended(&mut self, view: &PlayerView) {
+        record_hand(&mut self.session, view, HUMAN);
+    }
+}
+
+/// Count what `player` did in the finished hand, the way the player's own
+/// stats are kept.
+fn record_hand(stats: &mut PlayerStats, view: &PlayerView, player: usize) {
+    if !view.seats.get(player).is_some_and(|seat| seat.in_hand) {
+        return;
+    }
+    stats.total_hands += 1;
+
+    let mut preflop_aggressor = None;
+    let mut folded_preflop = false;
+    let (mut vpip, mut pfr, mut three_bet_seen) = (false, false, false);
+    for street in &view.streets {
+        let preflop = street.street == Street::Preflop;
+        let mut max_bet = if preflop { view.big_blind } else { 0 };
+        // An opponent has raised preflop
+        let mut raised = false;
+        // The preflop raiser has bet the flop into the player
+        let mut facing_cbet = false;
+
+        for record in &street.actions {
+            let raise_to = match record.action {
+                Action::Bet(amount) | Action::Raise(amount) | Action::AllIn(amount)
+                    if amount > max_bet =>
+                {
+                    Some(amount)
+                }
+                _ => None,
+            };
+            let aggressive = raise_to.is_some();
+
+            if record.seat != player {
+                if aggressive && preflop {
+                    raised = true;
+                    preflop_aggressor = Some(record.seat);
+                } else if aggressive && street.street == Street::Flop && max_bet == 0 {
+                    facing_cbet = preflop_aggressor == Some(record.seat);
+                }
+            } else {
+                if preflop {
+                    if raised && !pfr && !three_bet_seen {
+                        three_bet_seen = true;
+                        stats.three_bet_opportunities += 1;
+                        stats.three_bet_hands += aggressive as u64;
+                    }
+                    if !vpip && !matches!(record.action, Action::Fold | Action::Check) {
+                        vpip = true;
+                        stats.vpip_hands += 1;
+                    }
+                    if aggressive {
+                        stats.pfr_hands += !pfr as u64;
+                        pfr = true;
+                        preflop_aggressor = Some(player);
+                    }
+                    folded_preflop |= record.action == Action::Fold;
+                }
+                if facing_cbet {
+                    facing_cbet = false;
+                    stats.fold_to_cbet_opportunities += 1;
+                    stats.fold_to_cbet_hands += (record.action == Action::Fold) as u64;
+                }
+                match record.action {
+                    Action::Bet(_) if !preflop => stats.bets += 1,
+                    _ if aggressive => stats.raises += 1,
+                    Action::Call(_) | Action::AllIn(_) => stats.calls += 1,
+                    _ => {}
+                }
+            }
+
+            if let Some(amount) = raise_to {
+                max_bet = amount;
+            }
+        }
+    }
+
+    if !folded_preflop && view.board.len() >= 3 {
+        stats.wtsd_opportunities += 1;
+        if let Some(outcome) = view.outcome.as_ref().filter(|o| o.showdown) {
+            if !view.seats[player].folded {
+                stats.wtsd_hands += 1;
+                stats.wsd_hands += (outcome.winnings(player) > 0) as u64;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::{GamePhase, GameState};
+
+    const BOT: usize = 1;
+
+    /// Let the player limp in first if it is their turn, then have the bot
+    /// raise to three big blinds.
+    fn bot_raises(state: &mut GameState) {
+        if state.to_act == HUMAN {
+            state.apply_action(HUMAN, Action::Call(state.amount_to_call(HUMAN)));
+        }
+        state.apply_action(BOT, Action::Raise(state.big_blind * 3));
+    }
+
+    fn recorded(state: &GameState) -> PlayerStats {
+        let mut stats = PlayerStats::default();
+        record_hand(&mut stats, &PlayerView::new(state, BOT), HUMAN);
+        stats
+    }
+
+    #[test]
+    fn test_records_a_three_bet() {
+        let mut state = GameState::with_seed(100, 1);
+        bot_raises(&mut state);
+        state.apply_action(HUMAN, Action::Raise(state.big_blind * 9));
+        state.apply_action(BOT, Action::Fold);
+        assert_eq!(state.phase, GamePhase::HandComplete);
+
+        let stats = recorded(&state);
+        assert_eq!(stats.total_hands, 1);
+        assert_eq!(stats.vpip_hands, 1);
+        assert_eq!(stats.pfr_hands, 1);
+        assert_eq!(stats.three_bet_opportunities, 1);
+        assert_eq!(stats.three_bet_hands, 1);
+        assert_eq!(stats.wtsd_opportunities, 0);
+    }
+
+    #[test]
+    fn test_records_a_fold_to_a_cbet() {
+        let mut state = GameState::with_seed(100, 1);
+        bot_raises(&mut state);
+        state.apply_action(HUMAN, Action::Call(state.amount_to_call(HUMAN)));
+        assert_eq!(state.phase, GamePhase::Flop);
+        if state.to_act == HUMAN {
+            state.apply_action(HUMAN, Action::Check);
+        }
+        state.apply_action(BOT, Action::Bet(state.big_blind * 3));
+        state.apply_action(HUMAN, Action::Fold);
+
+        let stats = recorded(&state);
+        assert_eq!(stats.three_bet_opportunities, 1);
+        assert_eq!(stats.three_bet_hands, 0);
+        assert_eq!(stats.fold_to_cbet_opportunities, 1);
+        assert_eq!(stats.fold_to_cbet_hands, 1);
+        assert_eq!(stats.wtsd_opportunities, 1);
+        assert_eq!(stats.wtsd_hands, 0);
+        assert_eq!(stats.bets + stats.raises, 0);
+    }
+
+    #[test]
+    fn test_no_read_means_default_play() {
+        let none = PlayerStats::default();
+        assert_eq!(Read::new(&none, &none).tuning(), Tuning::default());
+    }
+
+    #[test]
+    fn test_read_exploits_tendencies() {
+        // Plays half their hands, calls everything down and folds to c-bets
+        // when they miss, with plenty of 3-bets
+        let mut lifetime = PlayerStats::default();
+        lifetime.total_hands = 200;
+        lifetime.vpip_hands = 120;
+        lifetime.three_bet_opportunities = 50;
+        lifetime.three_bet_hands = 12;
+        lifetime.fold_to_cbet_opportunities = 40;
+        lifetime.fold_to_cbet_hands = 30;
+        lifetime.wtsd_opportunities = 100;
+        lifetime.wtsd_hands = 45;
+        lifetime.bets = 20;
+        lifetime.raises = 20;
+        lifetime.calls = 160;
+        let tuning = Read::new(&lifetime, &PlayerStats::default()).tuning();
+        assert!(tuning.thinner_value > 0.05, "{tuning:?}");
+        assert!(tuning.bluffs < 1.0, "{tuning:?}");
+        assert!(tuning.cbet_bluffs > 1.2, "{tuning:?}");
+        assert!(tuning.tighter_opens > 0.05, "{tuning:?}");
+    }
+
+    #[test]
+    fn test_session_moves_the_read() {
+        let mut lifetime = PlayerStats::default();
+        lifetime.fold_to_cbet_opportunities = 1000;
+        lifetime.fold_to_cbet_hands = 100;
+        let mut session = PlayerStats::default();
+        session.fold_to_cbet_opportunities = 30;
+        session.fold_to_cbet_hands = 30;
+        let before = Read::new(&lifetime, &PlayerStats::default()).fold_to_cbet;
+        let after = Read::new(&lifetime, &session).fold_to_cbet;
+        assert!(before < 0.2, "{before}");
+        assert!(after > 0.4, "{after}");
+
+        // A new session folds this one into the lifetime stats
+        let mut bot = AdaptiveBot::with_seed(0.5, 1);
+        bot.session = session;
+        bot.player_stats(&lifetime);
+        assert_eq!(bot.session().fold_to_cbet_opportunities, 0);
+        assert_eq!(bot.read().fold_to_cbet, before);
+    }
+}
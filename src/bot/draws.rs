@@ -48,6 +48,38 @@ pub fn detect_draws(hole_cards: &[Card], board: &[Card]) -> DrawInfo {
     info
 }
 
+/// Omaha draws: every draw has to be made with exactly two hole cards, so
+/// each pair of hole cards is checked against the board and the results
+/// combined. A flush draw needs two cards of the suit in hand.
+pub fn detect_omaha_draws(hole_cards: &[Card], board: &[Card]) -> DrawInfo {
+    if board.is_empty() {
+        return DrawInfo::default();
+    }
+
+    let mut info = DrawInfo::default();
+    for i in 0..hole_cards.len() {
+        for j in i + 1..hole_cards.len() {
+            let pair = [hole_cards[i], hole_cards[j]];
+            let draws = detect_draws(&pair, board);
+            info.oesd |= draws.oesd;
+            info.gutshot |= draws.gutshot;
+            info.backdoor_straight |= draws.backdoor_straight;
+            info.overcards = info.overcards.max(draws.overcards);
+
+            if pair[0].suit == pair[1].suit {
+                let board_count = board.iter().filter(|c| c.suit == pair[0].suit).count();
+                if board_count == 2 {
+                    info.flush_draw = true;
+                } else if board_count == 1 && board.len() == 3 {
+                    info.backdoor_flush = true;
+                }
+            }
+        }
+    }
+
+    info
+}
+
 fn detect_flush_draws(hole_cards: &[Card], board: &[Card], info: &mut DrawInfo) {
     let suits = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
 
@@ -286,4 +318,45 @@ mod tests {
         assert!(!info.gutshot);
         assert_eq!(info.overcards, 0);
     }
+
+    #[test]
+    fn test_omaha_flush_draw_needs_two_suited_hole_cards() {
+        let board = [
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::King, Suit::Hearts),
+            card(Rank::Five, Suit::Clubs),
+        ];
+        let one_heart = [
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Eight, Suit::Clubs),
+            card(Rank::Three, Suit::Diamonds),
+        ];
+        assert!(!detect_omaha_draws(&one_heart, &board).flush_draw);
+
+        let two_hearts = [
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Queen, Suit::Hearts),
+            card(Rank::Eight, Suit::Clubs),
+            card(Rank::Three, Suit::Diamonds),
+        ];
+        assert!(detect_omaha_draws(&two_hearts, &board).flush_draw);
+    }
+
+    #[test]
+    fn test_omaha_straight_draw_from_any_two() {
+        // 9-8 in hand with 7-6 on board
+        let hole = [
+            card(Rank::Nine, Suit::Spades),
+            card(Rank::Two, Suit::Hearts),
+            card(Rank::Eight, Suit::Clubs),
+            card(Rank::King, Suit::Diamonds),
+        ];
+        let board = [
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Six, Suit::Clubs),
+            card(Rank::Queen, Suit::Spades),
+        ];
+        assert!(detect_omaha_draws(&hole, &board).oesd);
+    }
 }
@@ -0,0 +1,464 @@
+//! A bot that plays by the numbers. It estimates its equity by simulation
+//! against a model of the opponent's range, built from how they have
+//! played the hand, then calls when the pot odds are right, bets and raises
+//! for value, and mixes in bluffs in proportion to its bet size.
+
+use std::cell::RefCell;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::game::actions::Action;
+use crate::game::deck::Card;
+use crate::game::equity::EquityCalculator;
+use crate::game::evaluator::evaluate;
+use crate::game::hand::HandRank;
+use crate::game::range::Range;
+use crate::game::state::{GamePhase, Street};
+use crate::game::variant::BettingLimit;
+use crate::game::view::PlayerView;
+
+use super::Strategy;
+
+/// Runouts simulated per decision.
+const ITERATIONS: u32 = 1_500;
+
+/// Random hands standing in for an Omaha opponent's range.
+const OMAHA_RANGE_SIZE: usize = 150;
+
+const ANY_TWO: &str = "22+, A2+, K2+, Q2+, J2+, T2+, 92+, 82+, 72+, 62+, 52+, 42+, 32";
+const CALLING_RANGE: &str = "22+, A2s+, K2s+, Q5s+, J7s+, T7s+, 96s+, 86s+, 75s+, 65s, 54s, \
+                             A2o+, K8o+, Q9o+, J9o+, T9o";
+const RAISING_RANGE: &str = "22+, A2s+, K9s+, Q9s+, J9s+, T9s, 98s, 87s, A9o+, KTo+, QTo+, JTo";
+const THREE_BET_RANGE: &str = "99+, AJs+, KQs, A5s-A4s, AQo+";
+
+/// Share of hands already weak on a street that an opponent still bets or
+/// raises with (bluffs and draws), and still calls with.
+const WEAK_BETTING: f64 = 0.4;
+const WEAK_CALLING: f64 = 0.7;
+
+/// How an opponent got into the pot preflop, which sets their range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    /// Blinds only, or nothing seen yet.
+    Blind,
+    Called,
+    Raised,
+    Reraised,
+}
+
+/// Shifts from the bot's default play, for exploiting a read on the
+/// opponent. The default changes nothing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tuning {
+    /// Taken off the equity needed to bet for value after the flop.
+    pub thinner_value: f64,
+    /// Scales how often the bot bluffs.
+    pub bluffs: f64,
+    /// Scales how often the bot bluffs a flop after raising preflop, on top
+    /// of `bluffs`.
+    pub cbet_bluffs: f64,
+    /// Added to the equity needed to enter an unraised pot preflop.
+    pub tighter_opens: f64,
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self {
+            thinner_value: 0.0,
+            bluffs: 1.0,
+            cbet_bluffs: 1.0,
+            tighter_opens: 0.0,
+        }
+    }
+}
+
+pub struct EquityBot {
+    pub aggression: f64,
+    rng: RefCell<StdRng>,
+}
+
+impl EquityBot {
+    pub fn new(aggression: f64) -> Self {
+        Self::with_rng(aggression, StdRng::from_entropy())
+    }
+
+    /// Create a bot whose randomized choices are fully determined by `seed`.
+    pub fn with_seed(aggression: f64, seed: u64) -> Self {
+        Self::with_rng(aggression, StdRng::seed_from_u64(seed))
+    }
+
+    pub fn with_rng(aggression: f64, rng: StdRng) -> Self {
+        Self {
+            aggression: aggression.clamp(0.0, 1.0),
+            rng: RefCell::new(rng),
+        }
+    }
+
+    /// Decide as `Strategy::decide` does, with the play shifted by `tuning`.
+    pub fn decide_with(&self, view: &PlayerView, tuning: &Tuning) -> Action {
+        if !matches!(
+            view.phase,
+            GamePhase::Preflop | GamePhase::Flop | GamePhase::Turn | GamePhase::River
+        ) {
+            return Action::Check;
+        }
+        let equity = self.equity(view);
+        if view.to_call == 0 {
+            self.bet_or_check(view, equity, tuning)
+        } else {
+            self.facing_bet(view, equity, tuning)
+        }
+    }
+
+    /// Chance of winning the pot at showdown against everyone still in.
+    fn equity(&self, view: &PlayerView) -> f64 {
+        let seed = self.rng.borrow_mut().gen();
+        let mut calculator = EquityCalculator::with_seed(view.variant, ITERATIONS, seed);
+        let range = self.opponent_range(view);
+        let heads_up = calculator
+            .hand_vs_range(&view.hole_cards, &range, &view.board)
+            .map(|equity| equity.equity)
+            .unwrap_or(0.5);
+        // Roughly, the hand has to beat each opponent in turn
+        heads_up.powi(view.opponents_in_hand.max(1) as i32)
+    }
+
+    /// The hands the opponent who matters most could hold: whoever is
+    /// betting, or the next player still in.
+    fn opponent_range(&self, view: &PlayerView) -> Vec<(Vec<Card>, f64)> {
+        if view.variant.is_omaha() {
+            return self.random_hands(view);
+        }
+
+        let opponent = view
+            .last_aggressor
+            .filter(|&seat| seat != view.seat && view.seats[seat].is_active())
+            .or_else(|| {
+                (1..view.seats.len())
+                    .map(|offset| (view.seat + offset) % view.seats.len())
+                    .find(|&seat| view.seats[seat].is_active())
+            });
+        modelled_range(view, opponent)
+    }
+
+    /// Omaha starting hands run too close together to model; any four
+    /// cards will do.
+    fn random_hands(&self, view: &PlayerView) -> Vec<(Vec<Card>, f64)> {
+        let mut stub: Vec<Card> = view
+            .variant
+            .deck()
+            .deal_n(52)
+            .into_iter()
+            .filter(|card| !view.hole_cards.contains(card) && !view.board.contains(card))
+            .collect();
+        let mut rng = self.rng.borrow_mut();
+        (0..OMAHA_RANGE_SIZE)
+            .map(|_| {
+                let (hand, _) = stub.partial_shuffle(&mut *rng, view.variant.hole_cards());
+                (hand.to_vec(), 1.0)
+            })
+            .collect()
+    }
+
+    fn bet_or_check(&self, view: &PlayerView, equity: f64, tuning: &Tuning) -> Action {
+        let value = if view.phase == GamePhase::Preflop {
+            0.58
+        } else {
+            0.62 - tuning.thinner_value
+        } - 0.08 * self.aggression;
+        if equity > value {
+            let size = if equity > 0.80 { 0.75 } else { 0.50 };
+            return self.bet(view, size);
+        }
+
+        // Bluff with hands that can't win at showdown, as often as it takes
+        // to keep the bets balanced
+        let size = 0.66;
+        let mut bluffs = tuning.bluffs;
+        if view.phase == GamePhase::Flop && view.preflop_aggressor == Some(view.seat) {
+            bluffs *= tuning.cbet_bluffs;
+        }
+        if equity < 0.35 && self.chance(bluff_ratio(size) * (0.5 + self.aggression) * bluffs) {
+            return self.bet(view, size);
+        }
+        Action::Check
+    }
+
+    fn facing_bet(&self, view: &PlayerView, equity: f64, tuning: &Tuning) -> Action {
+        // Nobody has raised yet: only the blinds are in
+        let opening = view.phase == GamePhase::Preflop && view.max_bet() <= view.big_blind;
+        let tighter = if opening { tuning.tighter_opens } else { 0.0 };
+        let raise_at = if view.phase == GamePhase::Preflop {
+            0.62
+        } else {
+            0.72
+        } - 0.08 * self.aggression
+            + tighter;
+        if equity > raise_at {
+            if let Some(raise) = self.raise(view, 0.75) {
+                return raise;
+            }
+        }
+
+        // Semi-bluff with draws while there are cards to come
+        if view.phase != GamePhase::River
+            && (0.30..0.45).contains(&equity)
+            && self.chance(bluff_ratio(0.75) * self.aggression * tuning.bluffs)
+        {
+            if let Some(raise) = self.raise(view, 0.75) {
+                return raise;
+            }
+        }
+
+        // Out of position the rest of the hand is harder to play, so ask
+        // for a little more than the bare price
+        let pot_odds = view.to_call as f64 / (view.pot + view.to_call) as f64;
+        let margin = if view.in_position { 0.0 } else { 0.03 } + tighter;
+        if equity >= pot_odds + margin {
+            self.call(view)
+        } else {
+            Action::Fold
+        }
+    }
+
+    /// Bet `fraction` of the pot, within the table's limits.
+    fn bet(&self, view: &PlayerView, fraction: f64) -> Action {
+        let Some(min_bet) = view.available.min_bet else {
+            return Action::Check;
+        };
+        let all_in = view.bet() + view.stack();
+        let amount = if view.variant.limit() == BettingLimit::FixedLimit {
+            view.available.max_raise
+        } else {
+            ((view.pot as f64 * fraction) as u32 + view.bet())
+                .max(min_bet)
+                .min(view.available.max_raise)
+        };
+        if amount >= all_in {
+            Action::AllIn(all_in)
+        } else {
+            Action::Bet(amount)
+        }
+    }
+
+    /// Raise by `fraction` of the pot after calling, if a raise is allowed.
+    fn raise(&self, view: &PlayerView, fraction: f64) -> Option<Action> {
+        let min_raise = view.available.min_raise?;
+        let all_in = view.bet() + view.stack();
+        let raise_to = if view.variant.limit() == BettingLimit::FixedLimit {
+            view.available.max_raise
+        } else {
+            view.max_bet() + ((view.pot + view.to_call) as f64 * fraction) as u32
+        };
+        let raise_to = raise_to.max(min_raise).min(view.available.max_raise);
+        Some(if raise_to >= all_in {
+            Action::AllIn(all_in)
+        } else {
+            Action::Raise(raise_to)
+        })
+    }
+
+    fn call(&self, view: &PlayerView) -> Action {
+        if view.to_call >= view.stack() {
+            Action::AllIn(view.bet() + view.stack())
+        } else {
+            Action::Call(view.to_call)
+        }
+    }
+
+    /// Index of one of `weights`, picked at random in proportion to them.
+    pub(crate) fn pick(&self, weights: &[f64]) -> usize {
+        let total: f64 = weights.iter().sum();
+        let mut point = self.rng.borrow_mut().gen::<f64>() * total;
+        for (index, &weight) in weights.iter().enumerate() {
+            if point < weight {
+                return index;
+            }
+            point -= weight;
+        }
+        weights.len().saturating_sub(1)
+    }
+
+    /// Returns true with probability `p`.
+    fn chance(&self, p: f64) -> bool {
+        self.rng.borrow_mut().gen_bool(p.clamp(0.0, 1.0))
+    }
+}
+
+impl Strategy for EquityBot {
+    fn aggression(&self) -> f64 {
+        self.aggression
+    }
+
+    fn decide(&self, view: &PlayerView) -> Action {
+        self.decide_with(view, &Tuning::default())
+    }
+}
+
+/// The hold'em hands `seat` could hold, judging by how they have played
+/// the hand: any two cards if there is no seat to go on.
+pub(crate) fn modelled_range(view: &PlayerView, seat: Option<usize>) -> Vec<(Vec<Card>, f64)> {
+    let notation = match seat.map_or(Entry::Blind, |seat| preflop_entry(view, seat)) {
+        Entry::Blind => ANY_TWO,
+        Entry::Called => CALLING_RANGE,
+        Entry::Raised => RAISING_RANGE,
+        Entry::Reraised => THREE_BET_RANGE,
+    };
+    let range = Range::parse(notation).expect("built-in ranges parse");
+    let deck = view.variant.deck().deal_n(52);
+    let ranking = view.variant.ranking();
+
+    range
+        .combos()
+        .into_iter()
+        .filter(|(cards, _)| cards.iter().all(|card| deck.contains(card)))
+        .map(|(cards, mut weight)| {
+            // Hands that were still nothing when the player put chips in
+            // are less likely, but bluffs and draws don't go away
+            if let Some(seat) = seat {
+                for street in view.streets.iter().filter(|s| s.street != Street::Preflop) {
+                    let weak =
+                        evaluate(&cards, &street.board, ranking).rank() == HandRank::HighCard;
+                    let actions = street.actions.iter().filter(|a| a.seat == seat);
+                    if !weak {
+                        continue;
+                    } else if actions.clone().any(|a| a.action.is_aggressive()) {
+                        weight *= WEAK_BETTING;
+                    } else if actions.clone().any(|a| matches!(a.action, Action::Call(_))) {
+                        weight *= WEAK_CALLING;
+                    }
+                }
+            }
+            (cards.to_vec(), weight)
+        })
+        .collect()
+}
+
+/// Bluffs per value bet that leave a caller indifferent: a bet of `size`
+/// times the pot offers odds of `size / (1 + 2 * size)`.
+fn bluff_ratio(size: f64) -> f64 {
+    size / (1.0 + 2.0 * size)
+}
+
+fn preflop_entry(view: &PlayerView, seat: usize) -> Entry {
+    let Some(preflop) = view.streets.iter().find(|s| s.street == Street::Preflop) else {
+        return Entry::Blind;
+    };
+    let mut entry = Entry::Blind;
+    let mut max_bet = view.big_blind;
+    let mut raises = 0;
+    for record in &preflop.actions {
+        match record.action {
+            Action::Bet(amount) | Action::Raise(amount) | Action::AllIn(amount)
+                if amount > max_bet =>
+            {
+                if record.seat == seat {
+                    entry = if raises > 0 {
+                        Entry::Reraised
+                    } else {
+                        Entry::Raised
+                    };
+                }
+                raises += 1;
+                max_bet = amount;
+            }
+            Action::Call(_) | Action::AllIn(_) if record.seat == seat && entry == Entry::Blind => {
+                entry = Entry::Called;
+            }
+            _ => {}
+        }
+    }
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::deck::{parse_cards, Rank};
+    use crate::game::state::{GameState, HUMAN};
+    use crate::game::variant::GameVariant;
+
+    const BOT: usize = 1;
+
+    /// A heads-up state where the bot faces a bet of `player_bet`.
+    fn facing_bet_state(bot_cards: &str, board: &str, pot: u32, player_bet: u32) -> GameState {
+        let mut state = GameState::new(100);
+        state.phase = match board.len() / 2 {
+            3 => GamePhase::Flop,
+            4 => GamePhase::Turn,
+            _ => GamePhase::River,
+        };
+        state.seats[BOT].cards = parse_cards(bot_cards).unwrap();
+        state.board = parse_cards(board).unwrap();
+        state.pot = pot;
+        state.seats[HUMAN].bet = player_bet;
+        state.seats[BOT].bet = 0;
+        state.to_act = BOT;
+        state.button = HUMAN;
+        state.seats[BOT].stack = 180;
+        state.seats[HUMAN].stack = 180;
+        state.last_aggressor = Some(HUMAN);
+        state.last_raise_size = player_bet;
+        state
+    }
+
+    fn decisions(state: &GameState, runs: u64) -> Vec<Action> {
+        (0..runs)
+            .map(|seed| EquityBot::with_seed(0.5, seed).decide(&PlayerView::new(state, BOT)))
+            .collect()
+    }
+
+    #[test]
+    fn test_nuts_never_fold() {
+        let state = facing_bet_state("AhKh", "QhJhTh2c3d", 60, 60);
+        for action in decisions(&state, 10) {
+            assert!(
+                matches!(action, Action::Raise(_) | Action::AllIn(_)),
+                "{action:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_air_folds_to_a_pot_sized_river_bet() {
+        let state = facing_bet_state("7s2h", "KdQc4s9hAc", 60, 60);
+        assert!(decisions(&state, 10).iter().all(|&a| a == Action::Fold));
+    }
+
+    #[test]
+    fn test_draw_calls_a_small_bet() {
+        // Nut flush draw getting 5 to 1
+        let state = facing_bet_state("AhTh", "Kh7h2c", 50, 10);
+        assert!(decisions(&state, 10).iter().all(|&a| a != Action::Fold));
+    }
+
+    #[test]
+    fn test_preflop_raise_narrows_the_range() {
+        let mut state = GameState::with_seed(100, 4);
+        let raiser = state.to_act;
+        state.apply_action(raiser, Action::Raise(state.big_blind * 3));
+        let bot = EquityBot::with_seed(0.5, 1);
+        let view = PlayerView::new(&state, state.to_act);
+        assert_eq!(preflop_entry(&view, raiser), Entry::Raised);
+        assert!(bot.opponent_range(&view).len() < 400);
+
+        let fresh = GameState::with_seed(100, 4);
+        let view = PlayerView::new(&fresh, fresh.to_act);
+        assert!(bot.opponent_range(&view).len() > 1000);
+    }
+
+    #[test]
+    fn test_short_deck_range_has_no_low_cards() {
+        let mut state = facing_bet_state("AhKh", "QhJh6c", 40, 10);
+        state.variant = GameVariant::ShortDeck;
+        let view = PlayerView::new(&state, BOT);
+        let range = EquityBot::with_seed(0.5, 1).opponent_range(&view);
+        assert!(range
+            .iter()
+            .flat_map(|(cards, _)| cards)
+            .all(|card| card.rank >= Rank::Six));
+        assert!(!range.is_empty());
+    }
+}
@@ -0,0 +1,472 @@
+//! A bot that runs as a separate program, in any language, the way a chess
+//! engine plugs into a GUI. The game talks to it over the program's stdin
+//! and stdout, one JSON object per line.
+//!
+//! The game sends `new_hand` (with the bot's seat, the blinds and every
+//! stack), then `hole_cards`; as the hand goes on it sends a `board`
+//! message for each new street and an `action` message for every action
+//! taken, the bot's own included. When it is the bot's turn the game sends
+//! `request_action`, and the bot answers with an action line: `"Fold"`,
+//! `"Check"`, `{"Call":2}`, `{"Bet":6}`, `{"Raise":12}` or `{"AllIn":200}`.
+//! Amounts are chips, and bets and raises are the total to go to. Once the
+//! hand is over the game sends `hand_end` with the final stacks and any
+//! cards shown down.
+//!
+//! An answer that isn't a legal action, or that doesn't come within the
+//! timeout, gets an `error` message back and the bot checks, or folds if
+//! it can't. When the session is over the program's stdin is closed. Its
+//! stderr is thrown away, since the table is drawn on the terminal.
+
+use std::cell::RefCell;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::game::actions::{Action, AvailableActions};
+use crate::game::deck::Card;
+use crate::game::state::Street;
+use crate::game::variant::GameVariant;
+use crate::game::view::PlayerView;
+
+use super::Strategy;
+
+/// How long the bot gets to answer, unless told otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long the bot gets to exit once the session is over.
+const EXIT_GRACE: Duration = Duration::from_millis(500);
+
+/// A line from the game to the bot.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Message<'a> {
+    NewHand {
+        hand: u32,
+        seat: usize,
+        game: GameVariant,
+        button: usize,
+        small_blind: u32,
+        big_blind: u32,
+        ante: u32,
+        /// Every seat's stack before the blinds and antes.
+        stacks: Vec<u32>,
+    },
+    HoleCards {
+        cards: Vec<String>,
+    },
+    Board {
+        street: Street,
+        cards: Vec<String>,
+    },
+    Action {
+        seat: usize,
+        action: Action,
+        /// Pot after the action.
+        pot: u32,
+    },
+    RequestAction {
+        pot: u32,
+        to_call: u32,
+        /// Every seat's stack and bet on this street.
+        stacks: Vec<u32>,
+        bets: Vec<u32>,
+        available: &'a AvailableActions,
+        timeout_ms: u128,
+    },
+    Error {
+        message: String,
+    },
+    HandEnd {
+        stacks: Vec<u32>,
+        shown: Vec<Shown>,
+    },
+}
+
+#[derive(Debug, Serialize)]
+struct Shown {
+    seat: usize,
+    cards: Vec<String>,
+}
+
+/// How much of the hand the bot has been told.
+#[derive(Debug, Default)]
+struct Told {
+    hand: Option<u32>,
+    streets: usize,
+    /// Actions told on the last street told.
+    actions: usize,
+}
+
+pub struct ExternalBot {
+    child: Child,
+    /// Taken and closed when the bot is dropped.
+    stdin: RefCell<Option<ChildStdin>>,
+    replies: Receiver<String>,
+    timeout: Duration,
+    told: RefCell<Told>,
+}
+
+impl ExternalBot {
+    /// Start `command` through the shell, with `timeout` to answer each
+    /// request.
+    pub fn spawn(command: &str, timeout: Duration) -> io::Result<Self> {
+        let mut child = shell(command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+
+        // Replies are read on their own thread, so waiting for one can time out
+        let (sender, replies) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if !line.trim().is_empty() && sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            child,
+            stdin: RefCell::new(Some(stdin)),
+            replies,
+            timeout,
+            told: RefCell::new(Told::default()),
+        })
+    }
+
+    fn send(&self, message: &Message) {
+        let line = serde_json::to_string(message).expect("messages serialize");
+        // A bot that has gone away just stops answering, which is handled
+        // when waiting for its reply
+        if let Some(stdin) = self.stdin.borrow_mut().as_mut() {
+            let _ = writeln!(stdin, "{line}").and_then(|()| stdin.flush());
+        }
+    }
+
+    /// Tell the bot whatever has happened in the hand since it was last
+    /// told, starting a new hand if `view` is in one it hasn't seen.
+    fn catch_up(&self, view: &PlayerView) {
+        let mut told = self.told.borrow_mut();
+        if told.hand != Some(view.hand_number) {
+            *told = Told {
+                hand: Some(view.hand_number),
+                ..Told::default()
+            };
+            self.send(&Message::NewHand {
+                hand: view.hand_number,
+                seat: view.seat,
+                game: view.variant,
+                button: view.button,
+                small_blind: view.small_blind,
+                big_blind: view.big_blind,
+                ante: view.ante,
+                stacks: starting_stacks(view),
+            });
+            self.send(&Message::HoleCards {
+                cards: card_names(&view.hole_cards),
+            });
+        }
+
+        for (index, street) in view
+            .streets
+            .iter()
+            .enumerate()
+            .skip(told.streets.saturating_sub(1))
+        {
+            if index >= told.streets {
+                if !street.board.is_empty() {
+                    self.send(&Message::Board {
+                        street: street.street,
+                        cards: card_names(&street.board),
+                    });
+                }
+                told.streets = index + 1;
+                told.actions = 0;
+            }
+            for record in &street.actions[told.actions..] {
+                self.send(&Message::Action {
+                    seat: record.seat,
+                    action: record.action,
+                    pot: record.pot,
+                });
+            }
+            told.actions = street.actions.len();
+        }
+    }
+
+    /// Ask the bot for its action, or say why there isn't a legal one.
+    fn request(&self, view: &PlayerView) -> Result<Action, String> {
+        // Drop any answer to an earlier request that came in too late
+        while self.replies.try_recv().is_ok() {}
+
+        self.send(&Message::RequestAction {
+            pot: view.pot,
+            to_call: view.to_call,
+            stacks: view.seats.iter().map(|seat| seat.stack).collect(),
+            bets: view.seats.iter().map(|seat| seat.bet).collect(),
+            available: &view.available,
+            timeout_ms: self.timeout.as_millis(),
+        });
+        let reply = match self.replies.recv_timeout(self.timeout) {
+            Ok(reply) => reply,
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(format!("no action within {}ms", self.timeout.as_millis()))
+            }
+            Err(RecvTimeoutError::Disconnected) => return Err("the bot has exited".to_string()),
+        };
+        let action: Action = serde_json::from_str(reply.trim())
+            .map_err(|e| format!("could not read action {:?}: {}", reply.trim(), e))?;
+        validate(view, action)
+    }
+}
+
+impl Strategy for ExternalBot {
+    /// Unknown: the middle of the scale.
+    fn aggression(&self) -> f64 {
+        0.5
+    }
+
+    fn decide(&self, view: &PlayerView) -> Action {
+        self.catch_up(view);
+        match self.request(view) {
+            Ok(action) => action,
+            Err(message) => {
+                self.send(&Message::Error { message });
+                if view.available.can_check {
+                    Action::Check
+                } else {
+                    Action::Fold
+                }
+            }
+        }
+    }
+
+    fn hand_started(&mut self, view: &PlayerView) {
+        // Hand numbers start again with a new session
+        self.told.get_mut().hand = None;
+        self.catch_up(view);
+    }
+
+    fn hand_ended(&mut self, view: &PlayerView) {
+        self.catch_up(view);
+        let Some(outcome) = &view.outcome else {
+            return;
+        };
+        self.send(&Message::HandEnd {
+            stacks: outcome.stacks.clone(),
+            shown: view
+                .shown_cards
+                .iter()
+                .map(|(seat, cards)| Shown {
+                    seat: *seat,
+                    cards: card_names(cards),
+                })
+                .collect(),
+        });
+    }
+}
+
+/// The bot's stdin is closed, which tells it the session is over, and it
+/// is stopped if it hasn't exited after `EXIT_GRACE`.
+impl Drop for ExternalBot {
+    fn drop(&mut self) {
+        self.stdin.get_mut().take();
+        let deadline = Instant::now() + EXIT_GRACE;
+        while Instant::now() < deadline {
+            if !matches!(self.child.try_wait(), Ok(None)) {
+                return;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(unix)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
+
+/// `action` as the table takes it, if it is one the seat to act in `view`
+/// may make.
+pub fn validate(view: &PlayerView, action: Action) -> Result<Action, String> {
+    let available = &view.available;
+    let all_in_to = view.bet() + view.stack();
+    match action {
+        Action::Fold if available.can_fold => Ok(action),
+        Action::Fold => Err("there is no bet to fold to: check instead".to_string()),
+        Action::Check if available.can_check => Ok(action),
+        Action::Check => Err(format!(
+            "can't check facing a bet: {} to call",
+            view.to_call
+        )),
+        Action::Call(amount) if amount != view.to_call || view.to_call == 0 => {
+            Err(format!("a call is {}, not {}", view.to_call, amount))
+        }
+        // Calling for the whole stack is going all in
+        Action::Call(_) if available.can_call.is_none() => Ok(Action::AllIn(all_in_to)),
+        Action::Call(_) => Ok(action),
+        Action::AllIn(to) if to != all_in_to => {
+            Err(format!("all in is to {}, not {}", all_in_to, to))
+        }
+        Action::AllIn(to) if to > view.max_bet() && to > available.max_raise => Err(format!(
+            "can't go all in: bets are up to {}",
+            available.max_raise
+        )),
+        Action::AllIn(_) => Ok(action),
+        Action::Bet(to) | Action::Raise(to) => {
+            let (min, kind) = match action {
+                Action::Bet(_) => (available.min_bet, "bet"),
+                _ => (available.min_raise, "raise"),
+            };
+            let allowed = match action {
+                Action::Bet(_) => available.can_check,
+                _ => !available.can_check,
+            };
+            if !allowed {
+                return Err(match action {
+                    Action::Bet(_) => "there is a bet already: raise instead".to_string(),
+                    _ => "there is no bet to raise: bet instead".to_string(),
+                });
+            }
+            if to == all_in_to && to <= available.max_raise {
+                return Ok(Action::AllIn(to));
+            }
+            match min {
+                Some(min) if (min..=available.max_raise).contains(&to) && to < all_in_to => {
+                    Ok(action)
+                }
+                Some(min) => Err(format!(
+                    "a {} is to between {} and {}, not {}",
+                    kind, min, available.max_raise, to
+                )),
+                None => Err(format!("can't {kind}: the only bigger bet is all in")),
+            }
+        }
+    }
+}
+
+/// Every seat's stack as the hand was dealt, before the blinds and antes.
+fn starting_stacks(view: &PlayerView) -> Vec<u32> {
+    view.seats
+        .iter()
+        .map(|seat| seat.stack + seat.committed)
+        .collect()
+}
+
+fn card_names(cards: &[Card]) -> Vec<String> {
+    cards.iter().map(Card::to_string).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::GameState;
+
+    #[test]
+    fn test_validate_against_available_actions() {
+        // Heads-up preflop the small blind acts first, 1 to call
+        let state = GameState::new(100);
+        let view = PlayerView::new(&state, state.to_act);
+        let all_in_to = view.bet() + view.stack();
+
+        assert_eq!(validate(&view, Action::Fold), Ok(Action::Fold));
+        assert_eq!(validate(&view, Action::Call(1)), Ok(Action::Call(1)));
+        assert_eq!(validate(&view, Action::Raise(6)), Ok(Action::Raise(6)));
+        assert_eq!(
+            validate(&view, Action::Raise(all_in_to)),
+            Ok(Action::AllIn(all_in_to))
+        );
+        assert!(validate(&view, Action::Check).is_err());
+        assert!(validate(&view, Action::Call(2)).is_err());
+        assert!(validate(&view, Action::Bet(6)).is_err());
+        assert!(validate(&view, Action::Raise(3)).is_err());
+        assert!(validate(&view, Action::AllIn(all_in_to + 1)).is_err());
+    }
+
+    #[test]
+    fn test_call_for_the_whole_stack_is_all_in() {
+        let mut state = GameState::new(100);
+        let seat = state.to_act;
+        state.seats[seat].stack = 1;
+        let view = PlayerView::new(&state, seat);
+        assert_eq!(
+            validate(&view, Action::Call(1)),
+            Ok(Action::AllIn(view.bet() + 1))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_bot_is_told_the_hand_and_answers() {
+        let log = std::env::temp_dir().join(format!("terminal-poker-bot-{}", std::process::id()));
+        let script = format!(
+            r#"while read -r line; do echo "$line" >> '{}'; case "$line" in *request_action*) echo '"Fold"';; esac; done"#,
+            log.display()
+        );
+        let mut state = GameState::new(100);
+        let seat = state.to_act;
+        let mut bot = ExternalBot::spawn(&script, Duration::from_secs(5)).unwrap();
+        bot.hand_started(&PlayerView::new(&state, seat));
+        let action = bot.decide(&PlayerView::new(&state, seat));
+        state.apply_action(seat, action);
+        bot.hand_ended(&PlayerView::new(&state, seat));
+        drop(bot);
+        let lines = std::fs::read_to_string(&log).unwrap_or_default();
+        let _ = std::fs::remove_file(&log);
+
+        assert_eq!(action, Action::Fold);
+        let types: Vec<String> = lines
+            .lines()
+            .map(|line| {
+                let message: serde_json::Value = serde_json::from_str(line).unwrap();
+                message["type"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(
+            types,
+            [
+                "new_hand",
+                "hole_cards",
+                "request_action",
+                "action",
+                "hand_end"
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_silent_or_illegal_bot_checks_or_folds() {
+        let state = GameState::new(100);
+        let view = PlayerView::new(&state, state.to_act);
+
+        let silent = ExternalBot::spawn("cat > /dev/null", Duration::from_millis(50)).unwrap();
+        assert_eq!(silent.decide(&view), Action::Fold);
+
+        let illegal = ExternalBot::spawn(
+            r#"while read -r line; do echo '{"Raise":1}'; done"#,
+            Duration::from_secs(5),
+        )
+        .unwrap();
+        assert_eq!(illegal.decide(&view), Action::Fold);
+    }
+}
@@ -1,3 +1,133 @@
+pub mod adaptive;
 pub mod draws;
+pub mod equity;
+pub mod external;
+pub mod personality;
 pub mod preflop;
+pub mod reasoning;
 pub mod rule_based;
+pub mod simulation;
+pub mod solver;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::actions::Action;
+use crate::game::view::PlayerView;
+use crate::stats::models::PlayerStats;
+
+use adaptive::AdaptiveBot;
+use equity::EquityBot;
+use personality::Personality;
+use reasoning::Reasoning;
+use rule_based::RuleBasedBot;
+use solver::SolverBot;
+
+/// How an opponent plays. Bots only see the table through a `PlayerView`.
+pub trait Strategy {
+    /// Aggression from 0.0 (passive) to 1.0 (aggressive).
+    fn aggression(&self) -> f64;
+
+    /// Pick an action for the seat to act, which is `view.seat`.
+    fn decide(&self, view: &PlayerView) -> Action;
+
+    /// Decide as `decide` does, with the bot's reasoning if it can give it.
+    fn decide_explained(&self, view: &PlayerView) -> (Action, Option<Reasoning>) {
+        (self.decide(view), None)
+    }
+
+    /// The player's lifetime stats, given when a session starts or resumes.
+    fn player_stats(&mut self, _stats: &PlayerStats) {}
+
+    /// A new hand has been dealt.
+    fn hand_started(&mut self, _view: &PlayerView) {}
+
+    /// The hand is over; `view` shows how it ended.
+    fn hand_ended(&mut self, _view: &PlayerView) {}
+}
+
+/// The bots there are to play against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BotKind {
+    /// Hand-strength rules with preflop charts and board texture reads.
+    #[default]
+    RuleBased,
+    /// Simulated equity against a modelled range, pot odds and balanced bluffs.
+    Equity,
+    /// The equity bot, adjusting to the player's stats as it learns them.
+    Adaptive,
+    /// Solved heads-up rivers and push/fold charts, the equity bot elsewhere.
+    Solver,
+}
+
+impl BotKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            BotKind::RuleBased => "Rule-based",
+            BotKind::Equity => "Equity",
+            BotKind::Adaptive => "Adaptive",
+            BotKind::Solver => "Solver",
+        }
+    }
+
+    /// A bot of this kind. With a `seed` its randomized choices are fully
+    /// determined.
+    pub fn create(self, aggression: f64, seed: Option<u64>) -> Box<dyn Strategy> {
+        match self {
+            BotKind::RuleBased => Box::new(match seed {
+                Some(seed) => RuleBasedBot::with_seed(aggression, seed),
+                None => RuleBasedBot::new(aggression),
+            }),
+            BotKind::Equity => Box::new(match seed {
+                Some(seed) => EquityBot::with_seed(aggression, seed),
+                None => EquityBot::new(aggression),
+            }),
+            BotKind::Adaptive => Box::new(match seed {
+                Some(seed) => AdaptiveBot::with_seed(aggression, seed),
+                None => AdaptiveBot::new(aggression),
+            }),
+            BotKind::Solver => Box::new(match seed {
+                Some(seed) => SolverBot::with_seed(aggression, seed),
+                None => SolverBot::new(aggression),
+            }),
+        }
+    }
+}
+
+/// How a bot was set up: plain enough to save with a session, and to build
+/// the bot again from.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BotConfig {
+    #[serde(default)]
+    pub kind: BotKind,
+    pub aggression: f64,
+    /// The player type the bot plays as, if it was given one.
+    #[serde(default)]
+    pub personality: Option<Personality>,
+}
+
+impl BotConfig {
+    pub fn new(kind: BotKind, aggression: f64) -> Self {
+        Self {
+            kind,
+            aggression,
+            personality: None,
+        }
+    }
+
+    /// A rule-based bot playing as `personality`.
+    pub fn playing_as(personality: Personality) -> Self {
+        Self {
+            kind: BotKind::RuleBased,
+            aggression: personality.aggression(),
+            personality: Some(personality),
+        }
+    }
+
+    /// The bot. With a `seed` its randomized choices are fully determined.
+    pub fn create(self, seed: Option<u64>) -> Box<dyn Strategy> {
+        match self.personality {
+            Some(personality) => personality.create(seed),
+            None => self.kind.create(self.aggression, seed),
+        }
+    }
+}
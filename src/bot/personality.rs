@@ -0,0 +1,137 @@
+//! Named player types for the rule-based bot to play as. Each bundles an
+//! aggression level with how many hands it plays, how readily it bets and
+//! calls, how big it bets and how often it bluffs.
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+use super::rule_based::RuleBasedBot;
+use super::Strategy;
+
+/// Adjustments to the rule-based bot's play. The default changes nothing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Profile {
+    /// Added to the preflop strength a hand needs to be played: higher
+    /// plays fewer hands.
+    pub preflop_tightness: f64,
+    /// Added to the strength needed to bet or raise after the flop.
+    pub bet_threshold: f64,
+    /// Added to the strength needed to call a bet after the flop.
+    pub call_threshold: f64,
+    /// Scales bet and raise sizes.
+    pub sizing: f64,
+    /// Scales how often the bot bluffs.
+    pub bluffs: f64,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            preflop_tightness: 0.0,
+            bet_threshold: 0.0,
+            call_threshold: 0.0,
+            sizing: 1.0,
+            bluffs: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Personality {
+    /// Plays only premium hands and gives up without them.
+    Nit,
+    /// Tight-aggressive: a solid range, played hard.
+    Tag,
+    /// Loose-aggressive: lots of hands, lots of pressure.
+    Lag,
+    /// Plays too many hands and calls them down.
+    CallingStation,
+    /// Raises almost anything, big and often.
+    Maniac,
+    /// Sound, middle-of-the-road play.
+    BalancedReg,
+}
+
+impl Personality {
+    pub const ALL: [Personality; 6] = [
+        Personality::Nit,
+        Personality::Tag,
+        Personality::Lag,
+        Personality::CallingStation,
+        Personality::Maniac,
+        Personality::BalancedReg,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Personality::Nit => "Nit",
+            Personality::Tag => "TAG",
+            Personality::Lag => "LAG",
+            Personality::CallingStation => "Calling station",
+            Personality::Maniac => "Maniac",
+            Personality::BalancedReg => "Balanced reg",
+        }
+    }
+
+    /// Aggression from 0.0 (passive) to 1.0 (aggressive).
+    pub fn aggression(self) -> f64 {
+        match self {
+            Personality::Nit => 0.2,
+            Personality::Tag => 0.65,
+            Personality::Lag => 0.8,
+            Personality::CallingStation => 0.1,
+            Personality::Maniac => 1.0,
+            Personality::BalancedReg => 0.5,
+        }
+    }
+
+    pub fn profile(self) -> Profile {
+        let (preflop_tightness, bet_threshold, call_threshold, sizing, bluffs) = match self {
+            Personality::Nit => (0.12, 0.08, 0.06, 0.9, 0.3),
+            Personality::Tag => (0.05, -0.03, 0.02, 1.0, 0.8),
+            Personality::Lag => (-0.08, -0.06, -0.02, 1.1, 1.5),
+            Personality::CallingStation => (-0.12, 0.10, -0.10, 0.8, 0.2),
+            Personality::Maniac => (-0.18, -0.12, -0.04, 1.4, 2.5),
+            Personality::BalancedReg => return Profile::default(),
+        };
+        Profile {
+            preflop_tightness,
+            bet_threshold,
+            call_threshold,
+            sizing,
+            bluffs,
+        }
+    }
+
+    /// A rule-based bot playing as this personality. With a `seed` its
+    /// randomized choices are fully determined.
+    pub fn create(self, seed: Option<u64>) -> Box<dyn Strategy> {
+        let rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Box::new(RuleBasedBot::with_personality(self, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_looser_types_play_more_hands() {
+        let tightness = |p: Personality| p.profile().preflop_tightness;
+        assert!(tightness(Personality::Nit) > tightness(Personality::Tag));
+        assert!(tightness(Personality::Tag) > tightness(Personality::BalancedReg));
+        assert!(tightness(Personality::BalancedReg) > tightness(Personality::Lag));
+        assert!(tightness(Personality::Lag) > tightness(Personality::Maniac));
+        assert!(tightness(Personality::CallingStation) < 0.0);
+    }
+
+    #[test]
+    fn test_balanced_reg_is_the_default_bot() {
+        assert_eq!(Personality::BalancedReg.profile(), Profile::default());
+        assert_eq!(Personality::BalancedReg.aggression(), 0.5);
+    }
+}
@@ -79,6 +79,47 @@ const OFFSUIT: [[u8; 13]; 13] = [
     [T, T, T, M, M, M, M, M, L, L, S, P, 0], // high=A
 ];
 
+/// Short-deck pair tiers indexed by `(rank as u8 - 6)`.
+/// Index: 0=66, 1=77, 2=88, 3=99, 4=TT, 5=JJ, 6=QQ, 7=KK, 8=AA
+#[rustfmt::skip]
+const SHORT_DECK_PAIR_TIER: [u8; 9] = [
+    M, M, L, L, S, S, P, P, P,
+];
+
+/// Short-deck suited tiers: SHORT_DECK_SUITED[low_rank_idx][high_rank_idx].
+/// With 2-5 gone, straights come more often and flushes less, so connected
+/// cards gain value and suitedness matters less than in the full deck.
+/// Indices: 0=6, 1=7, 2=8, 3=9, 4=T, 5=J, 6=Q, 7=K, 8=A
+#[rustfmt::skip]
+const SHORT_DECK_SUITED: [[u8; 9]; 9] = [
+    //  6  7  8  9  T  J  Q  K  A
+    [0, M, M, T, T, T, T, T, L], // low=6
+    [0, 0, M, M, T, T, T, M, L], // low=7
+    [0, 0, 0, L, M, M, T, M, L], // low=8
+    [0, 0, 0, 0, L, L, M, M, L], // low=9
+    [0, 0, 0, 0, 0, L, L, L, S], // low=T
+    [0, 0, 0, 0, 0, 0, S, S, S], // low=J
+    [0, 0, 0, 0, 0, 0, 0, S, S], // low=Q
+    [0, 0, 0, 0, 0, 0, 0, 0, P], // low=K
+    [0, 0, 0, 0, 0, 0, 0, 0, 0], // low=A (no suited hand with higher rank)
+];
+
+/// Short-deck offsuit tiers: SHORT_DECK_OFFSUIT[high_rank_idx][low_rank_idx].
+/// Indices: 0=6, 1=7, 2=8, 3=9, 4=T, 5=J, 6=Q, 7=K, 8=A
+#[rustfmt::skip]
+const SHORT_DECK_OFFSUIT: [[u8; 9]; 9] = [
+    //  6  7  8  9  T  J  Q  K  A
+    [0, 0, 0, 0, 0, 0, 0, 0, 0], // high=6 (no offsuit hand with lower rank)
+    [M, 0, 0, 0, 0, 0, 0, 0, 0], // high=7
+    [T, M, 0, 0, 0, 0, 0, 0, 0], // high=8
+    [T, T, M, 0, 0, 0, 0, 0, 0], // high=9
+    [T, T, M, M, 0, 0, 0, 0, 0], // high=T
+    [T, T, T, M, L, 0, 0, 0, 0], // high=J
+    [T, T, T, T, M, L, 0, 0, 0], // high=Q
+    [T, T, T, M, M, L, L, 0, 0], // high=K
+    [M, T, T, M, L, L, S, P, 0], // high=A
+];
+
 fn tier_from_code(code: u8) -> PreflopTier {
     match code {
         P => PreflopTier::Premium,
@@ -99,23 +140,51 @@ fn rank_index(rank: Rank) -> usize {
 /// Panics if `cards` does not contain exactly 2 cards.
 pub fn classify_preflop(cards: &[Card]) -> PreflopTier {
     assert_eq!(cards.len(), 2, "classify_preflop requires exactly 2 cards");
+    lookup_tier(cards, &PAIR_TIER, &SUITED, &OFFSUIT, rank_index)
+}
+
+/// Classify a two-card short-deck (6+) starting hand into a preflop tier.
+///
+/// # Panics
+/// Panics if `cards` does not contain exactly 2 cards, or either card is
+/// below a six.
+pub fn classify_short_deck_preflop(cards: &[Card]) -> PreflopTier {
+    assert_eq!(cards.len(), 2, "classify_short_deck_preflop requires exactly 2 cards");
+    lookup_tier(
+        cards,
+        &SHORT_DECK_PAIR_TIER,
+        &SHORT_DECK_SUITED,
+        &SHORT_DECK_OFFSUIT,
+        short_deck_rank_index,
+    )
+}
+
+fn short_deck_rank_index(rank: Rank) -> usize {
+    (rank as u8 - 6) as usize
+}
 
+fn lookup_tier<const N: usize>(
+    cards: &[Card],
+    pairs: &[u8; N],
+    suited: &[[u8; N]; N],
+    offsuit: &[[u8; N]; N],
+    index: fn(Rank) -> usize,
+) -> PreflopTier {
     let r0 = cards[0].rank;
     let r1 = cards[1].rank;
-    let suited = cards[0].suit == cards[1].suit;
 
     if r0 == r1 {
-        return tier_from_code(PAIR_TIER[rank_index(r0)]);
+        return tier_from_code(pairs[index(r0)]);
     }
 
     let (high, low) = if r0 > r1 { (r0, r1) } else { (r1, r0) };
-    let hi = rank_index(high);
-    let lo = rank_index(low);
+    let hi = index(high);
+    let lo = index(low);
 
-    let code = if suited {
-        SUITED[lo][hi]
+    let code = if cards[0].suit == cards[1].suit {
+        suited[lo][hi]
     } else {
-        OFFSUIT[hi][lo]
+        offsuit[hi][lo]
     };
 
     tier_from_code(code)
@@ -137,6 +206,73 @@ pub fn preflop_strength(cards: &[Card]) -> f64 {
     (base + kicker_bonus).min(1.0)
 }
 
+/// Short-deck counterpart of `preflop_strength`, with the kicker bonus
+/// spread over the nine ranks from six to ace.
+pub fn short_deck_preflop_strength(cards: &[Card]) -> f64 {
+    let tier = classify_short_deck_preflop(cards);
+    let base = tier.base_strength();
+
+    let high_rank = cards[0].rank.max(cards[1].rank);
+    let low_rank = cards[0].rank.min(cards[1].rank);
+    let kicker_bonus = short_deck_rank_index(high_rank) as f64 / 8.0 * 0.04
+        + short_deck_rank_index(low_rank) as f64 / 8.0 * 0.01;
+
+    (base + kicker_bonus).min(1.0)
+}
+
+/// Rough Omaha starting-hand strength (0.0 to 1.0), on the same scale as
+/// `preflop_strength`. Rewards high cards, high pairs, suitedness (double-
+/// suited and suited aces most) and connected ranks; trips are penalised
+/// since they leave only one useful card of that rank.
+///
+/// # Panics
+/// Panics if `cards` does not contain exactly 4 cards.
+pub fn omaha_preflop_strength(cards: &[Card]) -> f64 {
+    assert_eq!(cards.len(), 4, "omaha_preflop_strength requires exactly 4 cards");
+
+    let mut score = 0.25;
+
+    let high_cards: f64 = cards.iter().map(|c| rank_index(c.rank) as f64 / 12.0).sum();
+    score += high_cards / 4.0 * 0.25;
+
+    let mut rank_counts = [0u8; 13];
+    for card in cards {
+        rank_counts[rank_index(card.rank)] += 1;
+    }
+    for (index, &count) in rank_counts.iter().enumerate() {
+        match count {
+            2 => score += 0.02 + index as f64 / 12.0 * 0.18,
+            3.. => score -= 0.10,
+            _ => {}
+        }
+    }
+
+    let mut suit_counts = [0u8; 4];
+    for card in cards {
+        suit_counts[card.suit as usize] += 1;
+    }
+    let suited_ace = cards
+        .iter()
+        .any(|c| c.rank == Rank::Ace && suit_counts[c.suit as usize] >= 2);
+    score += suit_counts.iter().filter(|&&n| n == 2).count() as f64 * 0.06;
+    if suit_counts.iter().any(|&n| n >= 3) {
+        score += 0.02;
+    }
+    if suited_ace {
+        score += 0.04;
+    }
+
+    let mut ranks: Vec<usize> = (0..13).filter(|&i| rank_counts[i] > 0).collect();
+    ranks.sort_unstable();
+    let close_gaps = ranks.windows(2).filter(|w| w[1] - w[0] <= 2).count();
+    score += close_gaps as f64 * 0.02;
+    if ranks.len() == 4 && ranks[3] - ranks[0] <= 4 {
+        score += 0.10; // Rundown
+    }
+
+    score.clamp(0.0, 0.95)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -316,4 +452,91 @@ mod tests {
         let h4 = offsuit(Rank::Two, Rank::Seven);
         assert_eq!(classify_preflop(&h3), classify_preflop(&h4));
     }
+
+    #[test]
+    fn test_omaha_starting_hands_are_ordered() {
+        let aakk_ds = [
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Spades),
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::King, Suit::Hearts),
+        ];
+        let rundown_ds = [
+            card(Rank::Jack, Suit::Spades),
+            card(Rank::Ten, Suit::Spades),
+            card(Rank::Nine, Suit::Hearts),
+            card(Rank::Eight, Suit::Hearts),
+        ];
+        let ace_rag = [
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+            card(Rank::Seven, Suit::Diamonds),
+            card(Rank::Two, Suit::Clubs),
+        ];
+        let trash = [
+            card(Rank::Seven, Suit::Spades),
+            card(Rank::Three, Suit::Hearts),
+            card(Rank::Two, Suit::Diamonds),
+            card(Rank::Two, Suit::Clubs),
+        ];
+        let strengths: Vec<f64> = [aakk_ds, rundown_ds, ace_rag, trash]
+            .iter()
+            .map(|h| omaha_preflop_strength(h))
+            .collect();
+        assert!(strengths.windows(2).all(|w| w[0] > w[1]), "{strengths:?}");
+        assert!(strengths[0] <= 0.95);
+        assert!(strengths[3] < 0.45);
+    }
+
+    #[test]
+    fn test_omaha_trips_are_penalised() {
+        let trips = [
+            card(Rank::King, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+            card(Rank::King, Suit::Diamonds),
+            card(Rank::Queen, Suit::Clubs),
+        ];
+        let pair = [
+            card(Rank::King, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+            card(Rank::Jack, Suit::Diamonds),
+            card(Rank::Queen, Suit::Clubs),
+        ];
+        assert!(omaha_preflop_strength(&trips) < omaha_preflop_strength(&pair));
+    }
+
+    #[test]
+    fn test_short_deck_tiers() {
+        assert_eq!(classify_short_deck_preflop(&pair(Rank::Queen)), PreflopTier::Premium);
+        assert_eq!(classify_short_deck_preflop(&pair(Rank::Six)), PreflopTier::Marginal);
+        // Connectors and the A-6 "wheel" hand play better without 2-5
+        assert_eq!(
+            classify_short_deck_preflop(&suited(Rank::Nine, Rank::Eight)),
+            PreflopTier::Playable
+        );
+        assert_eq!(
+            classify_short_deck_preflop(&suited(Rank::Ace, Rank::Six)),
+            PreflopTier::Playable
+        );
+        assert_eq!(
+            classify_short_deck_preflop(&offsuit(Rank::Ace, Rank::Six)),
+            PreflopTier::Marginal
+        );
+        assert_eq!(
+            classify_short_deck_preflop(&offsuit(Rank::Queen, Rank::Seven)),
+            PreflopTier::Trash
+        );
+    }
+
+    #[test]
+    fn test_short_deck_strength_ordering() {
+        let aa = short_deck_preflop_strength(&pair(Rank::Ace));
+        let aks = short_deck_preflop_strength(&suited(Rank::Ace, Rank::King));
+        let jj = short_deck_preflop_strength(&pair(Rank::Jack));
+        let sixes = short_deck_preflop_strength(&pair(Rank::Six));
+        let q7o = short_deck_preflop_strength(&offsuit(Rank::Queen, Rank::Seven));
+
+        assert!(aa > aks && aks > jj && jj > sixes && sixes > q7o);
+        assert!(aa <= 1.0);
+    }
 }
@@ -0,0 +1,215 @@
+//! Why a bot made a decision: what it made of its hand and the board, how
+//! it adjusted the hand's strength, and which rule the result triggered.
+//! The TUI shows these after a hand, so the player can see why the bot
+//! barrelled or folded.
+
+use crate::game::actions::Action;
+
+use super::draws::DrawInfo;
+use super::preflop::PreflopTier;
+
+/// How wet the board is: how many draws it makes possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardTexture {
+    Dry,
+    Medium,
+    Wet,
+}
+
+impl BoardTexture {
+    pub fn name(self) -> &'static str {
+        match self {
+            BoardTexture::Dry => "dry",
+            BoardTexture::Medium => "medium",
+            BoardTexture::Wet => "wet",
+        }
+    }
+}
+
+/// The parts that add up to the strength a bot's rules compare against
+/// their thresholds.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Strength {
+    /// The hand on its own: its preflop strength, or its made-hand strength
+    /// after the flop.
+    pub hand: f64,
+    /// What the draws add on the flop and turn.
+    pub draws: f64,
+    /// Acting last after the flop is worth more than acting first.
+    pub position: f64,
+    pub aggression: f64,
+    /// Taken off for each opponent beyond the first.
+    pub multiway: f64,
+    /// The bot's personality tightening (or loosening) its preflop play.
+    pub profile: f64,
+    /// A little randomness, so the bot is harder to read.
+    pub noise: f64,
+}
+
+impl Strength {
+    pub fn adjusted(&self) -> f64 {
+        self.hand + self.draws + self.position + self.aggression + self.noise
+            - self.multiway
+            - self.profile
+    }
+}
+
+/// The line the adjusted strength crossed for the rule to fire.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Threshold {
+    Above(f64),
+    Below(f64),
+}
+
+/// A bot's account of one decision.
+#[derive(Debug, Clone)]
+pub struct Reasoning {
+    pub action: Action,
+    /// The starting hand's tier, preflop in Hold'em and short deck.
+    pub tier: Option<PreflopTier>,
+    /// The best hand made so far, after the flop.
+    pub made_hand: Option<String>,
+    pub draws: Option<DrawInfo>,
+    pub texture: Option<BoardTexture>,
+    /// None when the bot didn't weigh its hand, as when playing a
+    /// push/fold chart.
+    pub strength: Option<Strength>,
+    /// The rule that decided the action, in words.
+    pub rule: &'static str,
+    pub threshold: Option<Threshold>,
+}
+
+impl Reasoning {
+    pub fn new(action: Action) -> Self {
+        Self {
+            action,
+            tier: None,
+            made_hand: None,
+            draws: None,
+            texture: None,
+            strength: None,
+            rule: "",
+            threshold: None,
+        }
+    }
+
+    /// Record the rule that fired.
+    pub fn fired(&mut self, rule: &'static str, threshold: Option<Threshold>) {
+        self.rule = rule;
+        self.threshold = threshold;
+    }
+
+    /// The reasoning as lines of text, hand first and rule last.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if let Some(tier) = self.tier {
+            lines.push(format!("Starting hand: {:?}", tier));
+        }
+        if let Some(made_hand) = &self.made_hand {
+            lines.push(format!("Made hand: {}", made_hand));
+        }
+        if let Some(draws) = &self.draws {
+            lines.push(format!("Draws: {}", describe_draws(draws)));
+        }
+        if let Some(texture) = self.texture {
+            lines.push(format!("Board: {}", texture.name()));
+        }
+        if let Some(strength) = &self.strength {
+            lines.push(describe_strength(strength));
+        }
+        let rule = match (self.threshold, &self.strength) {
+            (Some(Threshold::Above(at)), Some(s)) => {
+                format!("{} ({:.2} > {:.2})", self.rule, s.adjusted(), at)
+            }
+            (Some(Threshold::Below(at)), Some(s)) => {
+                format!("{} ({:.2} < {:.2})", self.rule, s.adjusted(), at)
+            }
+            _ => self.rule.to_string(),
+        };
+        lines.push(format!("Rule: {}", rule));
+        lines
+    }
+}
+
+fn describe_draws(draws: &DrawInfo) -> String {
+    let mut parts = Vec::new();
+    if draws.flush_draw {
+        parts.push("flush draw".to_string());
+    }
+    if draws.oesd {
+        parts.push("open-ended straight draw".to_string());
+    } else if draws.gutshot {
+        parts.push("gutshot".to_string());
+    }
+    match draws.overcards {
+        0 => {}
+        1 => parts.push("1 overcard".to_string()),
+        n => parts.push(format!("{} overcards", n)),
+    }
+    if draws.backdoor_flush {
+        parts.push("backdoor flush".to_string());
+    }
+    if draws.backdoor_straight {
+        parts.push("backdoor straight".to_string());
+    }
+    if parts.is_empty() {
+        "none".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// "Strength 0.41 = hand 0.35 position +0.06 ...", leaving out the parts
+/// that were zero.
+fn describe_strength(strength: &Strength) -> String {
+    let mut text = format!(
+        "Strength {:.2} = hand {:.2}",
+        strength.adjusted(),
+        strength.hand
+    );
+    let parts = [
+        ("draws", strength.draws),
+        ("position", strength.position),
+        ("aggression", strength.aggression),
+        ("multiway", -strength.multiway),
+        ("personality", -strength.profile),
+        ("noise", strength.noise),
+    ];
+    for (name, value) in parts {
+        if value != 0.0 {
+            text.push_str(&format!(" {} {:+.2}", name, value));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_show_the_sum_and_the_threshold() {
+        let mut reasoning = Reasoning::new(Action::Fold);
+        reasoning.made_hand = Some("kings high".to_string());
+        reasoning.draws = Some(DrawInfo::default());
+        reasoning.texture = Some(BoardTexture::Dry);
+        reasoning.strength = Some(Strength {
+            hand: 0.09,
+            position: -0.04,
+            noise: 0.01,
+            ..Strength::default()
+        });
+        reasoning.fired("Too weak to call", Some(Threshold::Below(0.12)));
+
+        assert_eq!(
+            reasoning.lines(),
+            [
+                "Made hand: kings high",
+                "Draws: none",
+                "Board: dry",
+                "Strength 0.06 = hand 0.09 position -0.04 noise +0.01",
+                "Rule: Too weak to call (0.06 < 0.12)",
+            ]
+        );
+    }
+}
@@ -1,19 +1,24 @@
 use crate::game::actions::Action;
 use crate::game::deck::{Card, Suit};
-use crate::game::hand::evaluate_hand;
-use crate::game::state::{GamePhase, GameState, Player, BIG_BLIND};
-
-use super::draws::detect_draws;
-use super::preflop::preflop_strength;
-
-use rand::Rng;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum BoardTexture {
-    Dry,
-    Medium,
-    Wet,
-}
+use crate::game::hand::{evaluate_hand_with, HandRank};
+use crate::game::pushfold::{self, Position, MAX_STACK_BB};
+use crate::game::state::GamePhase;
+use crate::game::variant::{BettingLimit, GameVariant};
+use crate::game::view::PlayerView;
+
+use super::draws::{detect_draws, detect_omaha_draws};
+use super::personality::{Personality, Profile};
+use super::preflop::{
+    classify_preflop, classify_short_deck_preflop, omaha_preflop_strength, preflop_strength,
+    short_deck_preflop_strength,
+};
+use super::reasoning::{BoardTexture, Reasoning, Strength, Threshold};
+use super::Strategy;
+
+use std::cell::RefCell;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 #[derive(Debug, Clone, Copy)]
 enum BetSize {
@@ -34,126 +39,225 @@ impl BetSize {
 
 pub struct RuleBasedBot {
     pub aggression: f64,
+    /// The player type the bot plays as, if it was given one.
+    pub personality: Option<Personality>,
+    profile: Profile,
+    rng: RefCell<StdRng>,
 }
 
 impl RuleBasedBot {
     pub fn new(aggression: f64) -> Self {
+        Self::with_rng(aggression, StdRng::from_entropy())
+    }
+
+    /// Create a bot whose randomized choices are fully determined by `seed`.
+    pub fn with_seed(aggression: f64, seed: u64) -> Self {
+        Self::with_rng(aggression, StdRng::seed_from_u64(seed))
+    }
+
+    pub fn with_rng(aggression: f64, rng: StdRng) -> Self {
         Self {
             aggression: aggression.clamp(0.0, 1.0),
+            personality: None,
+            profile: Profile::default(),
+            rng: RefCell::new(rng),
         }
     }
 
-    pub fn decide(&self, state: &GameState) -> Action {
-        match state.phase {
-            GamePhase::Preflop => self.decide_preflop(state),
-            GamePhase::Flop | GamePhase::Turn => self.decide_postflop(state),
-            GamePhase::River => self.decide_river(state),
-            _ => Action::Check,
+    /// Create a bot that plays as `personality`, with its aggression.
+    pub fn with_personality(personality: Personality, rng: StdRng) -> Self {
+        Self {
+            personality: Some(personality),
+            profile: personality.profile(),
+            ..Self::with_rng(personality.aggression(), rng)
         }
     }
 
+    /// Decide, noting in the reasoning what the hand was worth and which
+    /// rule fired.
+    fn explain(&self, view: &PlayerView) -> Reasoning {
+        let mut why = Reasoning::new(Action::Check);
+        why.action = match view.phase {
+            GamePhase::Preflop => self.decide_preflop(view, &mut why),
+            GamePhase::Flop | GamePhase::Turn => self.decide_postflop(view, &mut why),
+            GamePhase::River => self.decide_river(view, &mut why),
+            _ => Action::Check,
+        };
+        why
+    }
+
     // ── Preflop ─────────────────────────────────────────────
 
-    fn decide_preflop(&self, state: &GameState) -> Action {
-        let strength = preflop_strength(&state.bot_cards);
-        let to_call = state.amount_to_call(Player::Bot);
-        let available = state.available_actions();
-        let stack = state.bot_stack;
-        let bot_bet = state.bot_bet;
+    fn decide_preflop(&self, view: &PlayerView, why: &mut Reasoning) -> Action {
+        let cards = &view.hole_cards;
+        if view.variant.is_short_deck() {
+            why.tier = Some(classify_short_deck_preflop(cards));
+        } else if !view.variant.is_omaha() {
+            why.tier = Some(classify_preflop(cards));
+        }
+        if let Some(action) = push_fold(view) {
+            why.fired("Short stack: jam or fold from the push/fold chart", None);
+            return action;
+        }
+        let strength = if view.variant.is_omaha() {
+            omaha_preflop_strength(cards)
+        } else if view.variant.is_short_deck() {
+            short_deck_preflop_strength(cards)
+        } else {
+            preflop_strength(cards)
+        };
+        let to_call = view.to_call;
+        let available = &view.available;
+        let stack = view.stack();
+        let bot_bet = view.bet();
         let max_bet = bot_bet + stack;
 
-        let mut rng = rand::thread_rng();
-        let noise: f64 = rng.gen_range(-0.05..0.05);
-        let aggression_adj = (self.aggression - 0.5) * 0.10;
-        let adjusted = strength + aggression_adj + noise;
+        let parts = Strength {
+            hand: strength,
+            aggression: (self.aggression - 0.5) * 0.10,
+            noise: self.noise(),
+            multiway: multiway_penalty(view),
+            profile: self.profile.preflop_tightness,
+            ..Strength::default()
+        };
+        why.strength = Some(parts);
+        let adjusted = parts.adjusted();
+        let bluffs = self.profile.bluffs;
 
         if to_call == 0 {
             // BB option: check or raise
             if adjusted > 0.70 && self.aggression > 0.2 {
-                return self.preflop_raise(3.0, state);
+                why.fired(
+                    "Strong hand: raise the option",
+                    Some(Threshold::Above(0.70)),
+                );
+                return self.preflop_raise(3.0, view);
             }
             if adjusted > 0.55 && self.aggression > 0.3 {
-                return self.preflop_raise(2.5, state);
+                why.fired("Good hand: raise the option", Some(Threshold::Above(0.55)));
+                return self.preflop_raise(2.5, view);
             }
-            if adjusted > 0.45 && self.aggression > 0.5 && rng.gen_bool(0.25) {
-                return self.preflop_raise(2.5, state);
+            if adjusted > 0.45 && self.aggression > 0.5 && self.chance(0.25) {
+                why.fired(
+                    "Decent hand: raise the option a quarter of the time",
+                    Some(Threshold::Above(0.45)),
+                );
+                return self.preflop_raise(2.5, view);
             }
+            why.fired("Not raising: check the option", None);
             return Action::Check;
         }
 
-        let facing_raise = state.last_aggressor.is_some();
+        let facing_raise = view.last_aggressor.is_some();
 
         if !facing_raise {
             // SB open: Playable+ raises, Marginal limps, Trash folds
             if adjusted > 0.50 && self.aggression > 0.15 {
+                why.fired(
+                    "Playable or better: open with a raise",
+                    Some(Threshold::Above(0.50)),
+                );
                 let mult = if adjusted > 0.80 { 3.0 } else { 2.5 };
-                return self.preflop_raise(mult, state);
+                return self.preflop_raise(mult, view);
             }
             if adjusted > 0.35 {
+                why.fired("Marginal hand: limp in", Some(Threshold::Above(0.35)));
                 return self.make_call(to_call, stack, bot_bet);
             }
-            if self.aggression > 0.7 && rng.gen_bool(0.08) {
-                return self.preflop_raise(3.0, state);
+            if self.aggression > 0.7 && self.chance(0.08 * bluffs) {
+                why.fired(
+                    "Bluff: open a weak hand with a raise",
+                    Some(Threshold::Below(0.35)),
+                );
+                return self.preflop_raise(3.0, view);
             }
+            why.fired("Too weak to open: fold", Some(Threshold::Below(0.35)));
             return Action::Fold;
         }
 
         // Facing a raise
         if adjusted > 0.80 {
             if let Some(min_raise) = available.min_raise {
-                let raise_to = ((state.player_bet as f64) * 3.0) as u32;
-                let raise_to = raise_to.max(min_raise);
+                why.fired("Premium hand: re-raise", Some(Threshold::Above(0.80)));
+                let raise_to = (view.max_bet() as f64 * 3.0 * self.profile.sizing) as u32;
+                let raise_to = raise_to.max(min_raise).min(available.max_raise);
                 if raise_to >= max_bet {
                     return Action::AllIn(max_bet);
                 }
                 return Action::Raise(raise_to);
             }
+            why.fired(
+                "Premium hand, but no raise left: call",
+                Some(Threshold::Above(0.80)),
+            );
             return self.make_call(to_call, stack, bot_bet);
         }
 
         if adjusted > 0.65 {
-            if available.min_raise.is_some() && self.aggression > 0.5 && rng.gen_bool(0.25) {
-                let min_raise = available.min_raise.unwrap();
-                let raise_to = ((state.player_bet as f64) * 2.5) as u32;
-                let raise_to = raise_to.max(min_raise);
-                if raise_to < max_bet {
-                    return Action::Raise(raise_to);
+            if let Some(min_raise) = available.min_raise {
+                if self.aggression > 0.5 && self.chance(0.25) {
+                    let raise_to = (view.max_bet() as f64 * 2.5 * self.profile.sizing) as u32;
+                    let raise_to = raise_to.max(min_raise).min(available.max_raise);
+                    if raise_to < max_bet {
+                        why.fired(
+                            "Strong hand: re-raise a quarter of the time",
+                            Some(Threshold::Above(0.65)),
+                        );
+                        return Action::Raise(raise_to);
+                    }
                 }
             }
+            why.fired("Strong hand: call the raise", Some(Threshold::Above(0.65)));
             return self.make_call(to_call, stack, bot_bet);
         }
 
         if adjusted > 0.50 {
+            why.fired(
+                "Good enough to call the raise",
+                Some(Threshold::Above(0.50)),
+            );
             return self.make_call(to_call, stack, bot_bet);
         }
 
-        if adjusted > 0.35 && to_call <= BIG_BLIND * 3 {
+        if adjusted > 0.35 && to_call <= view.big_blind * 3 {
+            why.fired(
+                "Marginal hand, but the raise is small: call",
+                Some(Threshold::Above(0.35)),
+            );
             return self.make_call(to_call, stack, bot_bet);
         }
 
-        if self.aggression > 0.7 && rng.gen_bool(0.05) {
+        if self.aggression > 0.7 && self.chance(0.05 * bluffs) {
             if let Some(min_raise) = available.min_raise {
-                let raise_to = (BIG_BLIND * 7).max(min_raise);
+                let raise_to = ((view.big_blind * 7) as f64 * self.profile.sizing) as u32;
+                let raise_to = raise_to
+                    .max(min_raise)
+                    .min(available.max_raise);
                 if raise_to < max_bet {
+                    why.fired("Bluff: re-raise a weak hand", Some(Threshold::Below(0.50)));
                     return Action::Raise(raise_to);
                 }
             }
         }
 
+        why.fired(
+            "Too weak to call the raise: fold",
+            Some(Threshold::Below(0.50)),
+        );
         Action::Fold
     }
 
-    fn preflop_raise(&self, bb_multiplier: f64, state: &GameState) -> Action {
-        let available = state.available_actions();
-        let stack = state.bot_stack;
-        let bot_bet = state.bot_bet;
+    fn preflop_raise(&self, bb_multiplier: f64, view: &PlayerView) -> Action {
+        let available = &view.available;
+        let stack = view.stack();
+        let bot_bet = view.bet();
         let max_bet = bot_bet + stack;
-        let raise_to = (BIG_BLIND as f64 * bb_multiplier) as u32;
+        let raise_to = (view.big_blind as f64 * bb_multiplier * self.profile.sizing) as u32;
 
-        if state.amount_to_call(Player::Bot) == 0 {
+        if view.to_call == 0 {
             // BB option — emit Bet (raise over posted blind)
-            let min = available.min_bet.unwrap_or(BIG_BLIND);
-            let amount = raise_to.max(min);
+            let min = available.min_bet.unwrap_or(view.big_blind);
+            let amount = raise_to.max(min).min(available.max_raise);
             if amount >= max_bet {
                 Action::AllIn(max_bet)
             } else {
@@ -162,7 +266,7 @@ impl RuleBasedBot {
         } else {
             // SB or facing bet — emit Raise
             let min = available.min_raise.unwrap_or(raise_to);
-            let amount = raise_to.max(min);
+            let amount = raise_to.max(min).min(available.max_raise);
             if amount >= max_bet {
                 Action::AllIn(max_bet)
             } else {
@@ -173,24 +277,30 @@ impl RuleBasedBot {
 
     // ── Postflop (Flop / Turn) ──────────────────────────────
 
-    fn decide_postflop(&self, state: &GameState) -> Action {
-        let made = evaluate_hand(&state.bot_cards, &state.board).strength();
-        let street_factor = match state.phase {
+    fn decide_postflop(&self, view: &PlayerView, why: &mut Reasoning) -> Action {
+        let made = self.made_strength(view, why);
+        let street_factor = match view.phase {
             GamePhase::Flop => 1.0,
             GamePhase::Turn => 0.5,
             _ => 0.0,
         };
-        let draws = detect_draws(&state.bot_cards, &state.board);
+        let cards = &view.hole_cards;
+        let draws = if view.variant.is_omaha() {
+            detect_omaha_draws(cards, &view.board)
+        } else {
+            detect_draws(cards, &view.board)
+        };
         let draw_boost = draws.equity_boost(street_factor);
-        let effective = made + draw_boost;
-        let adjusted = self.adjust_strength(effective, state);
-        let texture = analyze_board_texture(&state.board);
-        let to_call = state.amount_to_call(Player::Bot);
+        why.draws = Some(draws);
+        let adjusted = self.adjust_strength(made, draw_boost, view, why);
+        let texture = analyze_board_texture(&view.board);
+        why.texture = Some(texture);
+        let to_call = view.to_call;
 
         if to_call == 0 {
-            self.postflop_bet_or_check(adjusted, texture, state)
+            self.postflop_bet_or_check(adjusted, texture, view, why)
         } else {
-            self.postflop_facing_bet(adjusted, to_call, state)
+            self.postflop_facing_bet(adjusted, to_call, view, why)
         }
     }
 
@@ -198,132 +308,225 @@ impl RuleBasedBot {
         &self,
         adjusted: f64,
         texture: BoardTexture,
-        state: &GameState,
+        view: &PlayerView,
+        why: &mut Reasoning,
     ) -> Action {
-        let mut rng = rand::thread_rng();
-
-        if adjusted > 0.45 {
-            return self.make_bet(BetSize::Large, state);
+        let bet_at = self.profile.bet_threshold;
+        if adjusted > 0.45 + bet_at {
+            why.fired(
+                "Strong hand: bet big for value",
+                Some(Threshold::Above(0.45 + bet_at)),
+            );
+            return self.make_bet(BetSize::Large, view);
         }
 
-        if adjusted > 0.25 {
+        if adjusted > 0.25 + bet_at {
+            why.fired(
+                "Good hand: bet, bigger the wetter the board",
+                Some(Threshold::Above(0.25 + bet_at)),
+            );
             let size = match texture {
                 BoardTexture::Dry => BetSize::Small,
                 BoardTexture::Medium => BetSize::Medium,
                 BoardTexture::Wet => BetSize::Large,
             };
-            return self.make_bet(size, state);
+            return self.make_bet(size, view);
         }
 
-        if adjusted > 0.15 && self.aggression > 0.4 {
-            return self.make_bet(BetSize::Small, state);
+        if adjusted > 0.15 + bet_at && self.aggression > 0.4 {
+            why.fired(
+                "Thin value: bet small",
+                Some(Threshold::Above(0.15 + bet_at)),
+            );
+            return self.make_bet(BetSize::Small, view);
         }
 
-        if adjusted < 0.10 && self.aggression > 0.6 && rng.gen_bool(0.20) {
+        if adjusted < 0.10 && self.aggression > 0.6 && self.chance(0.20 * self.profile.bluffs) {
+            why.fired("Weak hand: bluff", Some(Threshold::Below(0.10)));
             let size = match texture {
                 BoardTexture::Dry => BetSize::Small,
                 _ => BetSize::Medium,
             };
-            return self.make_bet(size, state);
+            return self.make_bet(size, view);
         }
 
+        let bet_from = if self.aggression > 0.4 { 0.15 } else { 0.25 };
+        why.fired(
+            "Not worth a bet: check",
+            Some(Threshold::Below(bet_from + bet_at)),
+        );
         Action::Check
     }
 
     // ── River ───────────────────────────────────────────────
 
-    fn decide_river(&self, state: &GameState) -> Action {
-        let made = evaluate_hand(&state.bot_cards, &state.board).strength();
-        let adjusted = self.adjust_strength(made, state);
-        let to_call = state.amount_to_call(Player::Bot);
+    fn decide_river(&self, view: &PlayerView, why: &mut Reasoning) -> Action {
+        let made = self.made_strength(view, why);
+        let adjusted = self.adjust_strength(made, 0.0, view, why);
+        why.texture = Some(analyze_board_texture(&view.board));
+        let to_call = view.to_call;
 
         if to_call == 0 {
-            self.river_bet_or_check(adjusted, state)
+            self.river_bet_or_check(adjusted, view, why)
         } else {
-            self.postflop_facing_bet(adjusted, to_call, state)
+            self.postflop_facing_bet(adjusted, to_call, view, why)
         }
     }
 
-    fn river_bet_or_check(&self, adjusted: f64, state: &GameState) -> Action {
-        let mut rng = rand::thread_rng();
-
-        if adjusted > 0.45 {
-            return self.make_bet(BetSize::Large, state);
+    fn river_bet_or_check(&self, adjusted: f64, view: &PlayerView, why: &mut Reasoning) -> Action {
+        let bet_at = self.profile.bet_threshold;
+        if adjusted > 0.45 + bet_at {
+            why.fired(
+                "Strong hand: bet big for value",
+                Some(Threshold::Above(0.45 + bet_at)),
+            );
+            return self.make_bet(BetSize::Large, view);
         }
-        if adjusted > 0.20 {
-            return self.make_bet(BetSize::Small, state);
+        if adjusted > 0.20 + bet_at {
+            why.fired(
+                "Good hand: bet small for value",
+                Some(Threshold::Above(0.20 + bet_at)),
+            );
+            return self.make_bet(BetSize::Small, view);
         }
-        if adjusted < 0.08 && self.aggression > 0.6 && rng.gen_bool(0.15) {
-            return self.make_bet(BetSize::Large, state);
+        if adjusted < 0.08 && self.aggression > 0.6 && self.chance(0.15 * self.profile.bluffs) {
+            why.fired("Missed hand: bluff the river", Some(Threshold::Below(0.08)));
+            return self.make_bet(BetSize::Large, view);
         }
+        why.fired(
+            "Not worth a bet: check",
+            Some(Threshold::Below(0.20 + bet_at)),
+        );
         Action::Check
     }
 
     // ── Facing a bet (all postflop streets) ─────────────────
 
-    fn postflop_facing_bet(&self, adjusted: f64, to_call: u32, state: &GameState) -> Action {
-        let available = state.available_actions();
-        let stack = state.bot_stack;
-        let bot_bet = state.bot_bet;
+    fn postflop_facing_bet(
+        &self,
+        adjusted: f64,
+        to_call: u32,
+        view: &PlayerView,
+        why: &mut Reasoning,
+    ) -> Action {
+        let available = &view.available;
+        let stack = view.stack();
+        let bot_bet = view.bet();
         let max_bet = bot_bet + stack;
-        let mut rng = rand::thread_rng();
+        let raise_at = self.profile.bet_threshold;
+        let call_at = 0.12 + self.profile.call_threshold;
 
-        if adjusted > 0.35 {
+        if adjusted > 0.35 + raise_at {
             if let Some(min_raise) = available.min_raise {
-                let raise_to = self.calculate_raise_size(min_raise, state.pot, stack, bot_bet);
+                why.fired(
+                    "Strong hand: raise",
+                    Some(Threshold::Above(0.35 + raise_at)),
+                );
+                let raise_to = self.calculate_raise_size(min_raise, view.pot, bot_bet, available.max_raise);
                 if raise_to >= max_bet {
                     return Action::AllIn(max_bet);
                 }
                 return Action::Raise(raise_to);
             }
+            why.fired(
+                "Strong hand, but no raise left: call",
+                Some(Threshold::Above(0.35 + raise_at)),
+            );
             return self.make_call(to_call, stack, bot_bet);
         }
 
-        if adjusted > 0.20 {
-            if available.min_raise.is_some() && self.aggression > 0.5 && rng.gen_bool(0.30) {
-                let min_raise = available.min_raise.unwrap();
-                let raise_to = self.calculate_raise_size(min_raise, state.pot, stack, bot_bet);
-                if raise_to < max_bet {
-                    return Action::Raise(raise_to);
+        if adjusted > 0.20 + raise_at {
+            if let Some(min_raise) = available.min_raise {
+                if self.aggression > 0.5 && self.chance(0.30) {
+                    let raise_to = self.calculate_raise_size(min_raise, view.pot, bot_bet, available.max_raise);
+                    if raise_to < max_bet {
+                        why.fired(
+                            "Good hand: raise about a third of the time",
+                            Some(Threshold::Above(0.20 + raise_at)),
+                        );
+                        return Action::Raise(raise_to);
+                    }
                 }
             }
+            why.fired("Good hand: call", Some(Threshold::Above(0.20 + raise_at)));
             return self.make_call(to_call, stack, bot_bet);
         }
 
-        if adjusted > 0.12 {
+        if adjusted > call_at {
+            why.fired("Strong enough to call", Some(Threshold::Above(call_at)));
             return self.make_call(to_call, stack, bot_bet);
         }
 
-        if adjusted < 0.08 && self.aggression > 0.7 && rng.gen_bool(0.10) {
+        if adjusted < 0.08 && self.aggression > 0.7 && self.chance(0.10 * self.profile.bluffs) {
             if let Some(min_raise) = available.min_raise {
-                let raise_to = self.calculate_raise_size(min_raise, state.pot, stack, bot_bet);
+                let raise_to = self.calculate_raise_size(min_raise, view.pot, bot_bet, available.max_raise);
                 if raise_to < max_bet {
+                    why.fired("Weak hand: bluff-raise", Some(Threshold::Below(0.08)));
                     return Action::Raise(raise_to);
                 }
             }
         }
 
+        why.fired("Too weak to call: fold", Some(Threshold::Below(call_at)));
         Action::Fold
     }
 
     // ── Helpers ─────────────────────────────────────────────
 
-    fn adjust_strength(&self, effective: f64, state: &GameState) -> f64 {
-        let mut rng = rand::thread_rng();
-        let noise: f64 = rng.gen_range(-0.05..0.05);
-        let position = if state.button == Player::Bot {
-            0.06 // In position postflop (button acts last)
+    /// Small random perturbation applied to hand strength.
+    fn noise(&self) -> f64 {
+        self.rng.borrow_mut().gen_range(-0.05..0.05)
+    }
+
+    /// Returns true with probability `p`.
+    fn chance(&self, p: f64) -> bool {
+        self.rng.borrow_mut().gen_bool(p.clamp(0.0, 1.0))
+    }
+
+    /// Made-hand strength for the seat to act. Omaha hands run much closer
+    /// together, so one and two pair are worth a lot less than in Hold'em.
+    fn made_strength(&self, view: &PlayerView, why: &mut Reasoning) -> f64 {
+        let ranking = view.variant.ranking();
+        let eval = evaluate_hand_with(&view.hole_cards, &view.board, ranking);
+        let strength = if view.variant.is_omaha() && eval.rank <= HandRank::TwoPair {
+            eval.strength_with(ranking) * 0.7
+        } else {
+            eval.strength_with(ranking)
+        };
+        why.made_hand = Some(eval.description);
+        strength
+    }
+
+    fn adjust_strength(
+        &self,
+        made: f64,
+        draws: f64,
+        view: &PlayerView,
+        why: &mut Reasoning,
+    ) -> f64 {
+        let noise = self.noise();
+        let position = if view.in_position {
+            0.06 // In position postflop (acts last)
         } else {
             -0.04 // Out of position
         };
-        let aggression_adj = (self.aggression - 0.5) * 0.12;
-        effective + position + aggression_adj + noise
+        let parts = Strength {
+            hand: made,
+            draws,
+            position,
+            aggression: (self.aggression - 0.5) * 0.12,
+            multiway: multiway_penalty(view),
+            noise,
+            ..Strength::default()
+        };
+        why.strength = Some(parts);
+        parts.adjusted()
     }
 
-    fn make_bet(&self, size: BetSize, state: &GameState) -> Action {
-        let available = state.available_actions();
-        let stack = state.bot_stack;
-        let bot_bet = state.bot_bet;
+    fn make_bet(&self, size: BetSize, view: &PlayerView) -> Action {
+        let available = &view.available;
+        let stack = view.stack();
+        let bot_bet = view.bet();
         let max_bet = bot_bet + stack;
 
         let min_bet = match available.min_bet {
@@ -331,8 +534,13 @@ impl RuleBasedBot {
             None => return Action::Check,
         };
 
-        let raw = (state.pot as f64 * size.pot_fraction()) as u32;
-        let amount = raw.max(min_bet).min(stack);
+        // Limit bets only come in the one fixed size
+        let amount = if view.variant.limit() == BettingLimit::FixedLimit {
+            available.max_raise.min(stack)
+        } else {
+            let raw = (view.pot as f64 * size.pot_fraction() * self.profile.sizing) as u32;
+            raw.max(min_bet).min(stack).min(available.max_raise)
+        };
 
         if amount >= stack {
             Action::AllIn(max_bet)
@@ -349,13 +557,73 @@ impl RuleBasedBot {
         }
     }
 
-    fn calculate_raise_size(&self, min_raise_to: u32, pot: u32, stack: u32, bot_bet: u32) -> u32 {
-        let raise_to = (pot as f64 * 0.70) as u32 + bot_bet;
-        let max_bet = bot_bet + stack;
-        raise_to.max(min_raise_to).min(max_bet)
+    /// Raise to 70% of the pot, within the table's limits. In fixed-limit the
+    /// min and max raise are the same, so that's the only size.
+    fn calculate_raise_size(
+        &self,
+        min_raise_to: u32,
+        pot: u32,
+        bot_bet: u32,
+        max_raise_to: u32,
+    ) -> u32 {
+        let raise_to = (pot as f64 * 0.70 * self.profile.sizing) as u32 + bot_bet;
+        raise_to.max(min_raise_to).min(max_raise_to)
+    }
+}
+
+impl Strategy for RuleBasedBot {
+    fn aggression(&self) -> f64 {
+        self.aggression
+    }
+
+    fn decide(&self, view: &PlayerView) -> Action {
+        self.explain(view).action
+    }
+
+    fn decide_explained(&self, view: &PlayerView) -> (Action, Option<Reasoning>) {
+        let why = self.explain(view);
+        (why.action, Some(why))
     }
 }
 
+/// Heads-up Hold'em with 20 big blinds or less is played from the
+/// push/fold charts: the small blind jams or folds, and the big blind
+/// answers a raise by going all in or folding. A limped pot is played
+/// as usual.
+pub(crate) fn push_fold(view: &PlayerView) -> Option<Action> {
+    if view.variant != GameVariant::NoLimitHoldem || view.to_call == 0 {
+        return None;
+    }
+    let mut dealt_in = (0..view.seats.len()).filter(|&seat| view.seats[seat].in_hand);
+    let (Some(first), Some(second), None) = (dealt_in.next(), dealt_in.next(), dealt_in.next())
+    else {
+        return None;
+    };
+    let chips = |seat: usize| view.seats[seat].stack + view.seats[seat].bet;
+    let stack_bb = chips(first).min(chips(second)) as f64 / view.big_blind as f64;
+    if stack_bb > MAX_STACK_BB as f64 {
+        return None;
+    }
+
+    let position = if view.max_bet() > view.big_blind {
+        Position::BigBlind
+    } else {
+        Position::SmallBlind
+    };
+    Some(if pushfold::plays(position, &view.hole_cards, stack_bb) {
+        Action::AllIn(view.bet() + view.stack())
+    } else {
+        Action::Fold
+    })
+}
+
+/// Each extra opponent makes a given hand less likely to be best, so play
+/// tighter in multiway pots.
+fn multiway_penalty(view: &PlayerView) -> f64 {
+    let opponents = view.opponents_in_hand;
+    opponents.saturating_sub(1) as f64 * 0.04
+}
+
 // ── Board texture analysis ──────────────────────────────────
 
 fn analyze_board_texture(board: &[Card]) -> BoardTexture {
@@ -408,6 +676,9 @@ fn analyze_board_texture(board: &[Card]) -> BoardTexture {
 mod tests {
     use super::*;
     use crate::game::deck::{Card, Rank, Suit};
+    use crate::game::state::{GameState, HUMAN};
+
+    const BOT: usize = 1;
 
     #[test]
     fn test_bot_creation() {
@@ -472,16 +743,16 @@ mod tests {
     ) -> GameState {
         let mut state = GameState::new(100);
         state.phase = phase;
-        state.bot_cards = bot_cards;
+        state.seats[BOT].cards = bot_cards;
         state.board = board;
         state.pot = pot;
-        state.player_bet = player_bet;
-        state.bot_bet = 0;
-        state.to_act = Player::Bot;
-        state.button = if bot_is_ip { Player::Bot } else { Player::Human };
-        state.bot_stack = 180;
-        state.player_stack = 180;
-        state.last_aggressor = Some(Player::Human);
+        state.seats[HUMAN].bet = player_bet;
+        state.seats[BOT].bet = 0;
+        state.to_act = BOT;
+        state.button = if bot_is_ip { BOT } else { HUMAN };
+        state.seats[BOT].stack = 180;
+        state.seats[HUMAN].stack = 180;
+        state.last_aggressor = Some(HUMAN);
         state.last_raise_size = player_bet;
         state
     }
@@ -511,7 +782,7 @@ mod tests {
                 10,
                 false, // OOP — harder case
             );
-            let action = bot.decide(&state);
+            let action = bot.decide(&PlayerView::new(&state, state.to_act));
             assert_ne!(action, Action::Fold, "Trips should never fold to a bet");
         }
     }
@@ -541,11 +812,64 @@ mod tests {
                 10,
                 false, // OOP
             );
-            let action = bot.decide(&state);
+            let action = bot.decide(&PlayerView::new(&state, state.to_act));
             assert_eq!(action, Action::Fold, "Air OOP should fold to a bet");
         }
     }
 
+    #[test]
+    fn test_air_fold_is_explained() {
+        // Same spot as above: the fold comes from missing the call threshold
+        let bot = RuleBasedBot::with_seed(0.5, 4);
+        let bot_cards = vec![
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+        let board = vec![
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Hearts),
+        ];
+        let state = facing_bet_state(bot_cards, board, GamePhase::Turn, 40, 10, false);
+
+        let (action, why) = bot.decide_explained(&PlayerView::new(&state, BOT));
+        let why = why.unwrap();
+        assert_eq!(action, Action::Fold);
+        assert_eq!(why.action, Action::Fold);
+        assert_eq!(why.tier, None);
+        assert_eq!(why.made_hand.as_deref(), Some("kings high"));
+        assert!(!why.draws.as_ref().unwrap().flush_draw);
+        assert_eq!(why.texture, Some(BoardTexture::Dry));
+        assert_eq!(why.threshold, Some(Threshold::Below(0.12)));
+        let strength = why.strength.unwrap();
+        assert_eq!(strength.position, -0.04);
+        assert!(strength.adjusted() < 0.12);
+        assert!(why
+            .lines()
+            .last()
+            .unwrap()
+            .starts_with("Rule: Too weak to call"));
+    }
+
+    #[test]
+    fn test_explaining_does_not_change_the_decision() {
+        let cards = |text: &str| crate::game::deck::parse_cards(text).unwrap();
+        let mut state = GameState::with_seed(100, 2);
+        state.seats[state.to_act].cards = cards("AsAh");
+        let view = PlayerView::new(&state, state.to_act);
+
+        for seed in 0..20 {
+            let action = RuleBasedBot::with_seed(0.5, seed).decide(&view);
+            let (explained, why) = RuleBasedBot::with_seed(0.5, seed).decide_explained(&view);
+            let why = why.unwrap();
+            assert_eq!(explained, action);
+            assert_eq!(why.tier, Some(crate::bot::preflop::PreflopTier::Premium));
+            assert_eq!(why.made_hand, None);
+            assert_eq!(why.threshold, Some(Threshold::Above(0.50)));
+        }
+    }
+
     #[test]
     fn test_top_pair_facing_bet_calls() {
         // K♠ 7♥ on K♦ 5♣ 2♠ 9♥ — top pair
@@ -571,7 +895,7 @@ mod tests {
                 10,
                 true, // IP
             );
-            let action = bot.decide(&state);
+            let action = bot.decide(&PlayerView::new(&state, state.to_act));
             assert_ne!(action, Action::Fold, "Top pair IP should not fold to a bet");
         }
     }
@@ -600,7 +924,7 @@ mod tests {
                 10,
                 true, // IP
             );
-            let action = bot.decide(&state);
+            let action = bot.decide(&PlayerView::new(&state, state.to_act));
             assert_ne!(
                 action,
                 Action::Fold,
@@ -608,4 +932,61 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_personalities_call_down_differently() {
+        // K♠ 4♥ on Q♦ 9♣ 5♣ 2♥ 7♠ — king high facing a pot-sized river bet
+        // strength ≈ 0.09: a station calls at 0.02, a nit wants 0.18
+        let bot_cards = vec![
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Four, Suit::Hearts),
+        ];
+        let board = vec![
+            Card::new(Rank::Queen, Suit::Diamonds),
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Spades),
+        ];
+        let state = facing_bet_state(bot_cards, board, GamePhase::River, 40, 40, true);
+        let decide = |personality: Personality, seed: u64| {
+            RuleBasedBot::with_personality(personality, StdRng::seed_from_u64(seed))
+                .decide(&PlayerView::new(&state, BOT))
+        };
+
+        for seed in 0..50 {
+            assert_ne!(decide(Personality::CallingStation, seed), Action::Fold);
+            assert_eq!(decide(Personality::Nit, seed), Action::Fold);
+        }
+    }
+
+    #[test]
+    fn test_short_stacks_play_push_fold() {
+        let cards = |text: &str| crate::game::deck::parse_cards(text).unwrap();
+        let bot = RuleBasedBot::with_seed(0.5, 1);
+        let decide = |state: &GameState| bot.decide(&PlayerView::new(state, state.to_act));
+
+        // 10BB deep, first to act on the button: jam or fold, never limp
+        let mut state = GameState::with_seed(10, 1);
+        let small_blind = state.to_act;
+        let all_in = state.seats[small_blind].stack + state.seats[small_blind].bet;
+        state.seats[small_blind].cards = cards("AsAh");
+        assert_eq!(decide(&state), Action::AllIn(all_in));
+        state.seats[small_blind].cards = cards("7s2h");
+        assert_eq!(decide(&state), Action::Fold);
+
+        // K2o calls a jam at 5BB but not at 10BB
+        for (stack_bb, calls) in [(5, true), (10, false)] {
+            let mut state = GameState::with_seed(stack_bb, 1);
+            let jam = state.seats[state.to_act].stack + state.seats[state.to_act].bet;
+            state.apply_action(state.to_act, Action::AllIn(jam));
+            state.seats[state.to_act].cards = cards("Kd2c");
+            assert_eq!(decide(&state) != Action::Fold, calls, "{stack_bb}BB");
+        }
+
+        // Deep stacks play as usual
+        let mut state = GameState::with_seed(100, 1);
+        state.seats[state.to_act].cards = cards("AsAh");
+        assert!(matches!(decide(&state), Action::Raise(_)));
+    }
 }
@@ -0,0 +1,406 @@
+//! Two bots playing each other heads-up with no one watching, to measure
+//! how they do against each other. Every hand is dealt fresh from full
+//! stacks, and the bots take turns on the button. In duplicate mode each
+//! deal is played twice, the second time with the bots in each other's
+//! seats, so most of the luck of the cards cancels out.
+
+use std::io::{self, IsTerminal, Write};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::game::actions::Action;
+use crate::game::state::{GameConfig, GamePhase, GameState, SeatOutcome, Street};
+use crate::game::view::PlayerView;
+
+use super::Strategy;
+
+/// Streets in the order they are dealt, as the report indexes them.
+pub const STREETS: [Street; 4] = [Street::Preflop, Street::Flop, Street::Turn, Street::River];
+
+/// How often one bot took each action.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActionCounts {
+    pub folds: u64,
+    pub checks: u64,
+    pub calls: u64,
+    pub bets: u64,
+    pub raises: u64,
+}
+
+impl ActionCounts {
+    pub fn total(&self) -> u64 {
+        self.folds + self.checks + self.calls + self.bets + self.raises
+    }
+
+    /// Count `action`, made when the largest bet was `max_bet`. An all-in
+    /// counts as the call, bet or raise it amounts to.
+    fn add(&mut self, action: Action, max_bet: u32) {
+        let count = match action {
+            Action::Fold => &mut self.folds,
+            Action::Check => &mut self.checks,
+            Action::Call(_) => &mut self.calls,
+            Action::AllIn(to) if to <= max_bet => &mut self.calls,
+            Action::Bet(_) | Action::AllIn(_) if max_bet == 0 => &mut self.bets,
+            Action::Bet(_) | Action::Raise(_) | Action::AllIn(_) => &mut self.raises,
+        };
+        *count += 1;
+    }
+}
+
+/// What happened over a simulation. Results are bot A's; bot B's are the
+/// same with the sign turned round.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    pub hands: u64,
+    /// Deals played: one hand each, or two in duplicate.
+    pub deals: u64,
+    /// Bot A's winnings in big blinds, summed over the deals, and the sum of
+    /// each deal's winnings squared.
+    pub won_bb: f64,
+    pub won_squared: f64,
+    /// Hands that went to showdown, and how many of those bot A won or
+    /// split.
+    pub showdowns: u64,
+    pub showdowns_won: u64,
+    pub showdowns_split: u64,
+    /// Each bot's actions street by street, indexed as `STREETS`.
+    pub actions: [[ActionCounts; 4]; 2],
+}
+
+impl Report {
+    /// Bot A's win rate in big blinds per 100 hands.
+    pub fn bb_per_100(&self) -> f64 {
+        if self.hands == 0 {
+            return 0.0;
+        }
+        self.won_bb / self.hands as f64 * 100.0
+    }
+
+    /// Half the width of the 95% confidence interval around `bb_per_100`,
+    /// from the spread of the deals' results. Infinite until there are two
+    /// deals to compare.
+    pub fn confidence_95(&self) -> f64 {
+        if self.deals < 2 {
+            return f64::INFINITY;
+        }
+        let deals = self.deals as f64;
+        let mean = self.won_bb / deals;
+        let variance = (self.won_squared - deals * mean * mean).max(0.0) / (deals - 1.0);
+        let hands_per_deal = self.hands as f64 / deals;
+        1.96 * (variance / deals).sqrt() / hands_per_deal * 100.0
+    }
+
+    /// The report as `poker simulate` prints it, with each bot's action
+    /// frequencies street by street. `labels` name bots A and B.
+    pub fn write(&self, out: &mut impl Write, labels: [&str; 2], seed: u64) -> io::Result<()> {
+        writeln!(out, "A  {}", labels[0])?;
+        writeln!(out, "B  {}", labels[1])?;
+        // Duplicate deals are two hands each
+        let mode = if self.hands > self.deals {
+            ", in duplicate"
+        } else {
+            ""
+        };
+        writeln!(out, "{} hands{}, seed {}", self.hands, mode, seed)?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "A wins {:+.2} BB/100, ± {:.2} at 95% confidence",
+            self.bb_per_100(),
+            self.confidence_95()
+        )?;
+        let percent = |part: u64, whole: u64| {
+            if whole == 0 {
+                0.0
+            } else {
+                part as f64 / whole as f64 * 100.0
+            }
+        };
+        writeln!(
+            out,
+            "Showdowns: {:.1}% of hands; A won {:.1}% of them and split {:.1}%",
+            percent(self.showdowns, self.hands),
+            percent(self.showdowns_won, self.showdowns),
+            percent(self.showdowns_split, self.showdowns)
+        )?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "{:<12}{:>7}{:>7}{:>7}{:>7}{:>7}{:>10}",
+            "", "Fold", "Check", "Call", "Bet", "Raise", "Actions"
+        )?;
+        for (bot, name) in ["A", "B"].iter().enumerate() {
+            for (street, counts) in STREETS.iter().zip(&self.actions[bot]) {
+                let total = counts.total();
+                let cells = [
+                    counts.folds,
+                    counts.checks,
+                    counts.calls,
+                    counts.bets,
+                    counts.raises,
+                ]
+                .map(|count| format!("{:.1}%", percent(count, total)));
+                writeln!(
+                    out,
+                    "{:<12}{:>7}{:>7}{:>7}{:>7}{:>7}{:>10}",
+                    format!("{} {:?}", name, street),
+                    cells[0],
+                    cells[1],
+                    cells[2],
+                    cells[3],
+                    cells[4],
+                    total
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Simulation {
+    config: GameConfig,
+    /// Bot A, then bot B.
+    bots: [Box<dyn Strategy>; 2],
+    rng: StdRng,
+    duplicate: bool,
+    report: Report,
+}
+
+impl Simulation {
+    /// A simulation of `bots` (A, then B) at the table `config` sets up,
+    /// played heads-up. The deals are fully determined by `seed`.
+    pub fn new(
+        config: GameConfig,
+        bots: [Box<dyn Strategy>; 2],
+        seed: u64,
+        duplicate: bool,
+    ) -> Self {
+        Self {
+            config: GameConfig {
+                seats: 2,
+                tournament: None,
+                ..config
+            },
+            bots,
+            rng: StdRng::seed_from_u64(seed),
+            duplicate,
+            report: Report::default(),
+        }
+    }
+
+    /// Play the next deal: one hand, or two in duplicate. Bot A takes seat
+    /// 0, and with it the button, on every other deal.
+    pub fn play_deal(&mut self) {
+        let seed = self.rng.gen();
+        let first_seat = (self.report.deals % 2) as usize;
+        let mut won = self.play_hand(seed, first_seat);
+        if self.duplicate {
+            won += self.play_hand(seed, 1 - first_seat);
+        }
+        self.report.deals += 1;
+        self.report.won_bb += won;
+        self.report.won_squared += won * won;
+    }
+
+    /// Play deals until at least `hands` hands have been played, with a
+    /// running count on stderr when it is a terminal.
+    pub fn play(&mut self, hands: u64) {
+        let deals = if self.duplicate {
+            hands.div_ceil(2)
+        } else {
+            hands
+        };
+        let total = deals * (1 + self.duplicate as u64);
+        let progress = io::stderr().is_terminal();
+        for deal in 1..=deals {
+            self.play_deal();
+            if progress && deal % (deals / 100).max(1) == 0 {
+                eprint!("\rPlayed {} of {} hands", self.report.hands, total);
+            }
+        }
+        if progress {
+            eprint!("\r\x1b[K");
+        }
+    }
+
+    pub fn report(&self) -> &Report {
+        &self.report
+    }
+
+    /// Play one hand dealt from `seed` with bot A in `a_seat`, and return
+    /// bot A's winnings in big blinds.
+    fn play_hand(&mut self, seed: u64, a_seat: usize) -> f64 {
+        let mut state = GameState::with_config(self.config.clone(), StdRng::seed_from_u64(seed));
+        let starting_stack = state.seats[a_seat].stack + state.seats[a_seat].committed;
+        let big_blind = state.big_blind;
+        // Bot A is player 0, bot B player 1
+        let player = |seat: usize| if seat == a_seat { 0 } else { 1 };
+
+        for seat in 0..2 {
+            self.bots[player(seat)].hand_started(&PlayerView::new(&state, seat));
+        }
+        while !matches!(state.phase, GamePhase::Showdown | GamePhase::HandComplete) {
+            let seat = state.to_act;
+            let action = self.bots[player(seat)].decide(&PlayerView::new(&state, seat));
+            if let Some(street) = Option::<Street>::from(state.phase) {
+                let street = STREETS.iter().position(|&s| s == street).unwrap_or(0);
+                self.report.actions[player(seat)][street].add(action, state.max_bet());
+            }
+            state.apply_action(seat, action);
+        }
+        for seat in 0..2 {
+            self.bots[player(seat)].hand_ended(&PlayerView::new(&state, seat));
+        }
+
+        self.report.hands += 1;
+        if let Some(result) = &state.showdown_result {
+            self.report.showdowns += 1;
+            match result.outcome(a_seat) {
+                SeatOutcome::Won => self.report.showdowns_won += 1,
+                SeatOutcome::Split => self.report.showdowns_split += 1,
+                SeatOutcome::Lost => {}
+            }
+        }
+        let stack = state
+            .current_hand
+            .result
+            .as_ref()
+            .map_or(state.seats[a_seat].stack, |outcome| outcome.stacks[a_seat]);
+        (stack as f64 - starting_stack as f64) / big_blind as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::rule_based::RuleBasedBot;
+
+    /// Always checks or calls.
+    struct Caller;
+
+    impl Strategy for Caller {
+        fn aggression(&self) -> f64 {
+            0.0
+        }
+        fn decide(&self, view: &PlayerView) -> Action {
+            match view.to_call {
+                0 => Action::Check,
+                to_call => Action::Call(to_call),
+            }
+        }
+    }
+
+    /// Moves all in whenever asked.
+    struct Jammer;
+
+    impl Strategy for Jammer {
+        fn aggression(&self) -> f64 {
+            1.0
+        }
+        fn decide(&self, view: &PlayerView) -> Action {
+            Action::AllIn(view.stack() + view.bet())
+        }
+    }
+
+    fn simulation(seed: u64, duplicate: bool) -> Simulation {
+        let bots: [Box<dyn Strategy>; 2] = [
+            Box::new(RuleBasedBot::with_seed(0.5, 1)),
+            Box::new(RuleBasedBot::with_seed(0.5, 2)),
+        ];
+        Simulation::new(GameConfig::heads_up(100), bots, seed, duplicate)
+    }
+
+    #[test]
+    fn test_hands_and_showdowns_are_counted() {
+        let mut sim = simulation(3, true);
+        for _ in 0..50 {
+            sim.play_deal();
+        }
+        let report = sim.report();
+        assert_eq!(report.deals, 50);
+        assert_eq!(report.hands, 100);
+        assert!(report.showdowns > 0 && report.showdowns < report.hands);
+        assert!(report.showdowns_won + report.showdowns_split <= report.showdowns);
+        // Every hand starts with someone acting preflop
+        let preflop: u64 = (0..2).map(|bot| report.actions[bot][0].total()).sum();
+        assert!(preflop >= report.hands);
+        assert!(report.confidence_95().is_finite());
+    }
+
+    #[test]
+    fn test_duplicate_mirror_cancels_the_cards() {
+        // Two bots that always check or call play every deal to showdown,
+        // and the mirrored hand hands back whatever the cards gave
+        let mut sim = Simulation::new(
+            GameConfig::heads_up(100),
+            [Box::new(Caller), Box::new(Caller)],
+            7,
+            true,
+        );
+        for _ in 0..20 {
+            sim.play_deal();
+        }
+        let report = sim.report();
+        assert_eq!(report.showdowns, 40);
+        assert_eq!(report.won_bb, 0.0);
+        assert_eq!(report.actions[0][0].folds + report.actions[1][0].folds, 0);
+    }
+
+    #[test]
+    fn test_nobody_is_asked_to_act_once_all_in() {
+        let mut sim = Simulation::new(
+            GameConfig::heads_up(100),
+            [Box::new(Jammer), Box::new(Caller)],
+            5,
+            false,
+        );
+        for _ in 0..20 {
+            sim.play_deal();
+        }
+        let report = sim.report();
+        assert_eq!(report.showdowns, 20);
+        for bot in 0..2 {
+            for street in 1..4 {
+                assert_eq!(report.actions[bot][street].total(), 0);
+            }
+        }
+        // The jam is the only decision the jammer gets each hand
+        assert_eq!(report.actions[0][0].total(), 20);
+        assert_eq!(report.actions[0][0].raises, 20);
+    }
+
+    #[test]
+    fn test_report_shows_every_street() {
+        let mut sim = simulation(2, true);
+        sim.play(9);
+        assert_eq!(sim.report().hands, 10);
+        let mut out = Vec::new();
+        sim.report()
+            .write(&mut out, ["rule", "also rule"], 2)
+            .unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[..3],
+            ["A  rule", "B  also rule", "10 hands, in duplicate, seed 2"]
+        );
+        assert!(lines[4].starts_with("A wins "));
+        assert!(lines[5].starts_with("Showdowns: "));
+        assert_eq!(lines.len(), 8 + 2 * STREETS.len());
+        assert!(lines[8].starts_with("A Preflop"));
+        assert!(lines[15].starts_with("B River"));
+    }
+
+    #[test]
+    fn test_same_seed_same_report() {
+        let play = |seed| {
+            let mut sim = simulation(seed, false);
+            for _ in 0..30 {
+                sim.play_deal();
+            }
+            sim.report().clone()
+        };
+        assert_eq!(play(9), play(9));
+    }
+}
@@ -0,0 +1,238 @@
+//! A bot that plays close to an equilibrium where it can work one out.
+//! Heads-up on a hold'em river it models both players' ranges from how the
+//! hand has gone, solves the river from where it stands and samples its
+//! action from the solution. Short-stacked preflop it plays the push/fold
+//! charts, and anywhere else it plays as the equity bot.
+
+use rand::rngs::StdRng;
+
+use crate::game::actions::Action;
+use crate::game::deck::Card;
+use crate::game::state::GamePhase;
+use crate::game::variant::BettingLimit;
+use crate::game::view::PlayerView;
+use crate::solver::river::RiverSpot;
+use crate::solver::tree::{BetSizes, Start};
+
+use super::equity::{modelled_range, EquityBot};
+use super::rule_based::push_fold;
+use super::Strategy;
+
+/// CFR iterations per river decision: enough to get within a few percent
+/// of the pot, in well under a second.
+const ITERATIONS: u32 = 200;
+
+/// Weight of the bot's own hand in its range when the model left it out,
+/// so the solution still covers it.
+const OWN_HAND_WEIGHT: f64 = 0.05;
+
+pub struct SolverBot {
+    bot: EquityBot,
+}
+
+impl SolverBot {
+    pub fn new(aggression: f64) -> Self {
+        Self {
+            bot: EquityBot::new(aggression),
+        }
+    }
+
+    /// Create a bot whose randomized choices are fully determined by `seed`.
+    pub fn with_seed(aggression: f64, seed: u64) -> Self {
+        Self {
+            bot: EquityBot::with_seed(aggression, seed),
+        }
+    }
+
+    pub fn with_rng(aggression: f64, rng: StdRng) -> Self {
+        Self {
+            bot: EquityBot::with_rng(aggression, rng),
+        }
+    }
+
+    /// The river spot as it stands, between the bot's range and the
+    /// opponent's, if the bot can solve it.
+    pub fn river_spot(&self, view: &PlayerView) -> Option<RiverSpot> {
+        if view.phase != GamePhase::River
+            || view.opponents_in_hand != 1
+            || view.variant.limit() != BettingLimit::NoLimit
+            || view.variant.is_omaha()
+        {
+            return None;
+        }
+        let opponent = (0..view.seats.len())
+            .find(|&seat| seat != view.seat && view.seats[seat].is_active())?;
+
+        let mut own = modelled_range(view, Some(view.seat));
+        if !own
+            .iter()
+            .any(|(cards, _)| same_hand(cards, &view.hole_cards))
+        {
+            own.push((view.hole_cards.clone(), OWN_HAND_WEIGHT));
+        }
+        let theirs = modelled_range(view, Some(opponent));
+        let (me, ranges) = if view.in_position {
+            (1, [theirs, own])
+        } else {
+            (0, [own, theirs])
+        };
+        let seats = if me == 0 {
+            [view.seat, opponent]
+        } else {
+            [opponent, view.seat]
+        };
+
+        let street_bets: u32 = view.seats.iter().map(|seat| seat.bet).sum();
+        let aggressive = view
+            .street_actions()
+            .iter()
+            .filter(|record| record.action.is_aggressive())
+            .count() as u32;
+        let start = Start {
+            pot: view.pot - street_bets,
+            bets: seats.map(|seat| view.seats[seat].bet),
+            stacks: seats.map(|seat| view.seats[seat].stack),
+            to_act: me,
+            acted: seats.map(|seat| view.seats[seat].acted),
+            last_raise: view
+                .available
+                .min_raise
+                .map_or(view.big_blind, |min| min.saturating_sub(view.max_bet())),
+            raises: aggressive.saturating_sub(1),
+            big_blind: view.big_blind,
+        };
+        RiverSpot::new(
+            &view.board,
+            view.variant.ranking(),
+            ranges.map(|range| {
+                range
+                    .into_iter()
+                    .map(|(cards, weight)| ([cards[0], cards[1]], weight))
+                    .collect()
+            }),
+            start,
+            BetSizes::default(),
+        )
+        .ok()
+    }
+
+    /// Solve the river and sample an action for the bot's hand.
+    fn river(&self, view: &PlayerView) -> Option<Action> {
+        let spot = self.river_spot(view)?;
+        let me = spot.start.to_act;
+        let hand = spot.hand(me, &view.hole_cards)?;
+        let solution = spot.solve(ITERATIONS);
+        let actions = solution.actions(0, hand);
+        let weights: Vec<f64> = actions.iter().map(|&(_, weight)| weight).collect();
+        let (action, _) = actions[self.bot.pick(&weights)];
+        Some(self.at_table(view, action))
+    }
+
+    /// The solver's action as a legal one at the table. The solver only
+    /// knows the effective stacks, so an all-in is for everything the bot
+    /// has.
+    fn at_table(&self, view: &PlayerView, action: Action) -> Action {
+        let all_in = view.bet() + view.stack();
+        match action {
+            Action::Fold if view.to_call == 0 => Action::Check,
+            Action::Fold | Action::Check => action,
+            Action::Call(_) if view.to_call >= view.stack() => Action::AllIn(all_in),
+            Action::Call(_) => Action::Call(view.to_call),
+            Action::AllIn(_) => Action::AllIn(all_in),
+            Action::Bet(to) | Action::Raise(to) => {
+                let min = match action {
+                    Action::Bet(_) => view.available.min_bet,
+                    _ => view.available.min_raise,
+                };
+                let to = to.max(min.unwrap_or(to)).min(view.available.max_raise);
+                if to >= all_in {
+                    Action::AllIn(all_in)
+                } else if matches!(action, Action::Bet(_)) {
+                    Action::Bet(to)
+                } else {
+                    Action::Raise(to)
+                }
+            }
+        }
+    }
+}
+
+impl Strategy for SolverBot {
+    fn aggression(&self) -> f64 {
+        self.bot.aggression
+    }
+
+    fn decide(&self, view: &PlayerView) -> Action {
+        if view.phase == GamePhase::Preflop {
+            if let Some(action) = push_fold(view) {
+                return action;
+            }
+        }
+        self.river(view).unwrap_or_else(|| self.bot.decide(view))
+    }
+}
+
+fn same_hand(a: &[Card], b: &[Card]) -> bool {
+    a.len() == b.len() && a.iter().all(|card| b.contains(card))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::deck::parse_cards;
+    use crate::game::state::{GameState, HUMAN};
+
+    const BOT: usize = 1;
+
+    /// A heads-up river where the player has bet `player_bet` into `pot`
+    /// and the bot, out of position, has to answer.
+    fn facing_river_bet(bot_cards: &str, board: &str, pot: u32, player_bet: u32) -> GameState {
+        let mut state = GameState::new(100);
+        state.phase = GamePhase::River;
+        state.seats[BOT].cards = parse_cards(bot_cards).unwrap();
+        state.board = parse_cards(board).unwrap();
+        state.pot = pot + player_bet;
+        state.seats[HUMAN].bet = player_bet;
+        state.seats[HUMAN].acted = true;
+        state.seats[BOT].bet = 0;
+        state.seats[BOT].acted = false;
+        state.to_act = BOT;
+        state.button = HUMAN;
+        state.seats[BOT].stack = 150;
+        state.seats[HUMAN].stack = 150 - player_bet;
+        state.last_aggressor = Some(HUMAN);
+        state.last_raise_size = player_bet;
+        state
+    }
+
+    fn decisions(state: &GameState, runs: u64) -> Vec<Action> {
+        (0..runs)
+            .map(|seed| SolverBot::with_seed(0.5, seed).decide(&PlayerView::new(state, BOT)))
+            .collect()
+    }
+
+    #[test]
+    fn test_river_spot_starts_where_the_hand_is() {
+        let state = facing_river_bet("AhKh", "QhJhTh2c3d", 60, 40);
+        let view = PlayerView::new(&state, BOT);
+        let spot = SolverBot::with_seed(0.5, 1).river_spot(&view).unwrap();
+        assert_eq!(spot.start.pot, 60);
+        assert_eq!(spot.start.bets, [0, 40]);
+        assert_eq!(spot.start.to_act, 0);
+        assert!(spot.hand(0, &state.seats[BOT].cards).is_some());
+    }
+
+    #[test]
+    fn test_nuts_never_fold_and_air_never_calls() {
+        let nuts = facing_river_bet("AhKh", "QhJhTh2c3d", 60, 60);
+        assert!(decisions(&nuts, 2).iter().all(|action| matches!(
+            action,
+            Action::Raise(_) | Action::AllIn(_) | Action::Call(_)
+        )));
+
+        let air = facing_river_bet("7s2h", "KdQc4s9hAc", 60, 60);
+        assert!(decisions(&air, 2)
+            .iter()
+            .all(|action| !matches!(action, Action::Call(_))));
+    }
+}
@@ -0,0 +1,137 @@
+//! Optional user config file with session defaults. Command-line flags
+//! override anything set here.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::state::Stakes;
+
+const APP_NAME: &str = "terminal-poker";
+const CONFIG_FILE: &str = "config.json";
+
+/// Contents of `config.json`, e.g.
+///
+/// ```json
+/// { "small_blind": 1, "big_blind": 3, "ante": 3, "big_blind_ante": true }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    pub small_blind: Option<u32>,
+    pub big_blind: Option<u32>,
+    pub ante: Option<u32>,
+    pub big_blind_ante: Option<bool>,
+}
+
+impl ConfigFile {
+    pub fn path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(APP_NAME)
+            .join(CONFIG_FILE)
+    }
+
+    /// Load the config file, falling back to defaults if it is missing or
+    /// can't be read.
+    pub fn load_or_default() -> Self {
+        let path = Self::path();
+        if !path.exists() {
+            return Self::default();
+        }
+        match Self::load(&path) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Warning: Could not load config file, using defaults: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        serde_json::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Combine the file with command-line overrides. If only the big blind
+    /// is given, the small blind is half of it.
+    pub fn stakes(&self, overrides: &ConfigFile) -> Result<Stakes, String> {
+        let defaults = Stakes::default();
+        let big_blind = overrides
+            .big_blind
+            .or(self.big_blind)
+            .unwrap_or(defaults.big_blind);
+        let stakes = Stakes {
+            small_blind: overrides
+                .small_blind
+                .or(self.small_blind)
+                .unwrap_or((big_blind / 2).max(1)),
+            big_blind,
+            ante: overrides.ante.or(self.ante).unwrap_or(defaults.ante),
+            big_blind_ante: overrides
+                .big_blind_ante
+                .or(self.big_blind_ante)
+                .unwrap_or(defaults.big_blind_ante),
+        };
+        stakes.validate()?;
+        Ok(stakes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_config_gives_default_stakes() {
+        let config: ConfigFile = serde_json::from_str("{}").unwrap();
+        assert_eq!(
+            config.stakes(&ConfigFile::default()).unwrap(),
+            Stakes::default()
+        );
+    }
+
+    #[test]
+    fn test_command_line_overrides_file() {
+        let file: ConfigFile =
+            serde_json::from_str(r#"{ "small_blind": 1, "big_blind": 3, "ante": 1 }"#).unwrap();
+        let cli = ConfigFile {
+            ante: Some(3),
+            big_blind_ante: Some(true),
+            ..ConfigFile::default()
+        };
+        let stakes = file.stakes(&cli).unwrap();
+        assert_eq!(
+            stakes,
+            Stakes {
+                small_blind: 1,
+                big_blind: 3,
+                ante: 3,
+                big_blind_ante: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_small_blind_defaults_to_half_the_big_blind() {
+        let cli = ConfigFile {
+            big_blind: Some(10),
+            ..ConfigFile::default()
+        };
+        let stakes = ConfigFile::default().stakes(&cli).unwrap();
+        assert_eq!((stakes.small_blind, stakes.big_blind), (5, 10));
+    }
+
+    #[test]
+    fn test_invalid_stakes_are_rejected() {
+        let cli = ConfigFile {
+            small_blind: Some(5),
+            big_blind: Some(2),
+            ..ConfigFile::default()
+        };
+        assert!(ConfigFile::default().stakes(&cli).is_err());
+        assert!(serde_json::from_str::<ConfigFile>(r#"{ "big_blnd": 2 }"#).is_err());
+    }
+}
@@ -1,8 +1,8 @@
-use super::state::BIG_BLIND;
 use serde::{Deserialize, Serialize};
 
-fn format_bb(chips: u32) -> String {
-    let bb = chips as f64 / BIG_BLIND as f64;
+/// Chip amount in big blinds, e.g. `3BB` or `2.5BB`.
+pub fn format_bb(chips: u32, big_blind: u32) -> String {
+    let bb = chips as f64 / big_blind as f64;
     if bb == bb.floor() {
         format!("{}BB", bb as u32)
     } else {
@@ -46,7 +46,7 @@ impl Action {
 
     /// Actor-aware description with BB-formatted amounts.
     /// "You" gets base-form verbs ("call"), "Bot" gets third-person ("calls").
-    pub fn description_for(&self, actor: &str) -> String {
+    pub fn description_for(&self, actor: &str, big_blind: u32) -> String {
         let is_you = actor == "You";
         match self {
             Action::Fold => {
@@ -66,28 +66,24 @@ impl Action {
             Action::Call(amt) => format!(
                 "{} {}",
                 if is_you { "call" } else { "calls" },
-                format_bb(*amt)
+                format_bb(*amt, big_blind)
             ),
             Action::Bet(amt) => format!(
                 "{} {}",
                 if is_you { "bet" } else { "bets" },
-                format_bb(*amt)
+                format_bb(*amt, big_blind)
             ),
             Action::Raise(amt) => format!(
                 "{} to {}",
                 if is_you { "raise" } else { "raises" },
-                format_bb(*amt)
-            ),
-            Action::AllIn(amt) => format!(
-                "{} for {}",
-                if is_you { "all-in" } else { "all-in" },
-                format_bb(*amt)
+                format_bb(*amt, big_blind)
             ),
+            Action::AllIn(amt) => format!("all-in for {}", format_bb(*amt, big_blind)),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[allow(dead_code)]
 pub struct AvailableActions {
     pub can_fold: bool,
@@ -95,11 +91,19 @@ pub struct AvailableActions {
     pub can_call: Option<u32>,
     pub min_bet: Option<u32>,
     pub min_raise: Option<u32>,
+    /// Largest total a bet or raise may go to: the whole stack in
+    /// no-limit, the size of the pot in pot-limit, the fixed size in limit.
     pub max_raise: u32,
 }
 
 impl AvailableActions {
-    pub fn new(to_call: u32, min_raise_to: u32, player_stack: u32, big_blind: u32) -> Self {
+    pub fn new(
+        to_call: u32,
+        min_raise_to: u32,
+        max_raise_to: u32,
+        player_stack: u32,
+        min_bet_size: u32,
+    ) -> Self {
         let can_check = to_call == 0;
         let can_call = if to_call > 0 && to_call < player_stack {
             Some(to_call)
@@ -108,7 +112,7 @@ impl AvailableActions {
         };
 
         let min_bet = if can_check && player_stack > 0 {
-            Some(big_blind.min(player_stack))
+            Some(min_bet_size.min(player_stack))
         } else {
             None
         };
@@ -125,7 +129,7 @@ impl AvailableActions {
             can_call,
             min_bet,
             min_raise,
-            max_raise: player_stack,
+            max_raise: max_raise_to,
         }
     }
 }
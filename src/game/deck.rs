@@ -1,7 +1,8 @@
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Suit {
@@ -24,6 +25,51 @@ impl Suit {
     pub fn is_red(&self) -> bool {
         matches!(self, Suit::Hearts | Suit::Diamonds)
     }
+
+    /// ASCII letter: `s`, `h`, `d` or `c`.
+    pub fn to_char(self) -> char {
+        match self {
+            Suit::Spades => 's',
+            Suit::Hearts => 'h',
+            Suit::Diamonds => 'd',
+            Suit::Clubs => 'c',
+        }
+    }
+
+    pub const ALL: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// Accepts the letter in either case, or the suit symbol.
+impl TryFrom<char> for Suit {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            's' | 'S' | '♠' => Ok(Suit::Spades),
+            'h' | 'H' | '♥' => Ok(Suit::Hearts),
+            'd' | 'D' | '♦' => Ok(Suit::Diamonds),
+            'c' | 'C' | '♣' => Ok(Suit::Clubs),
+            _ => Err(format!("'{}' is not a suit (use s, h, d or c)", c)),
+        }
+    }
+}
+
+impl FromStr for Suit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Suit::try_from(c),
+            _ => Err(format!("'{}' is not a suit (use s, h, d or c)", s)),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -77,6 +123,46 @@ impl Rank {
         Rank::King,
         Rank::Ace,
     ];
+
+    /// ASCII character, with `T` for ten.
+    pub fn to_char(self) -> char {
+        match self {
+            Rank::Ten => 'T',
+            other => other.symbol().chars().next().unwrap_or('?'),
+        }
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// Accepts `2`-`9`, `T`, `J`, `Q`, `K` and `A`, in either case.
+impl TryFrom<char> for Rank {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Rank::ALL
+            .into_iter()
+            .find(|rank| rank.to_char() == c.to_ascii_uppercase())
+            .ok_or_else(|| format!("'{}' is not a rank (use 2-9, T, J, Q, K or A)", c))
+    }
+}
+
+/// Also accepts `10` for ten.
+impl FromStr for Rank {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Rank::try_from(c),
+            _ if s == "10" => Ok(Rank::Ten),
+            _ => Err(format!("'{}' is not a rank (use 2-9, T, J, Q, K or A)", s)),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -91,13 +177,64 @@ impl Card {
     }
 }
 
+/// ASCII form such as `As` or `Td`, which `FromStr` reads back.
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.rank.symbol(), self.suit.symbol())
+        write!(f, "{}{}", self.rank, self.suit)
     }
 }
 
-#[derive(Debug, Clone)]
+/// A rank then a suit, e.g. `As`, `td`, `10h` or `K♠`.
+impl FromStr for Card {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let Some(suit) = s.chars().last() else {
+            return Err("empty card".to_string());
+        };
+        let rank = &s[..s.len() - suit.len_utf8()];
+        if rank.is_empty() {
+            return Err(format!("'{}' is not a card like As or Td", s));
+        }
+        let rank: Rank = rank.parse().map_err(|e| format!("'{}': {}", s, e))?;
+        let suit = Suit::try_from(suit).map_err(|e| format!("'{}': {}", s, e))?;
+        Ok(Card::new(rank, suit))
+    }
+}
+
+impl TryFrom<&str> for Card {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Parse several cards written together or apart, e.g. `AsKd`,
+/// `Ah 7c 2d` or `Ah,7c,2d`. The same card may not appear twice.
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, String> {
+    let mut cards: Vec<Card> = Vec::new();
+    let mut pending = String::new();
+    for c in s.chars().filter(|c| !c.is_whitespace() && *c != ',') {
+        pending.push(c);
+        // A card ends with its suit; "10" is the only two-character rank
+        if Suit::try_from(c).is_ok() && pending != "1" {
+            let card: Card = pending.parse()?;
+            if cards.contains(&card) {
+                return Err(format!("{} appears more than once", card));
+            }
+            cards.push(card);
+            pending.clear();
+        }
+    }
+    if !pending.is_empty() {
+        return Err(format!("'{}' is missing a suit", pending));
+    }
+    Ok(cards)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Deck {
     cards: Vec<Card>,
     index: usize,
@@ -106,7 +243,7 @@ pub struct Deck {
 impl Deck {
     pub fn new() -> Self {
         let mut cards = Vec::with_capacity(52);
-        for suit in [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs] {
+        for suit in Suit::ALL {
             for rank in Rank::ALL {
                 cards.push(Card::new(rank, suit));
             }
@@ -114,9 +251,23 @@ impl Deck {
         Self { cards, index: 0 }
     }
 
-    pub fn shuffle(&mut self) {
-        let mut rng = thread_rng();
-        self.cards.shuffle(&mut rng);
+    /// The 36-card short deck (sixes through aces) used by six-plus Hold'em.
+    pub fn short() -> Self {
+        let mut deck = Self::new();
+        deck.cards.retain(|card| card.rank >= Rank::Six);
+        deck
+    }
+
+    /// A deck that deals exactly `cards`, in order. Useful for setting up
+    /// specific runouts.
+    pub fn stacked(cards: Vec<Card>) -> Self {
+        Self { cards, index: 0 }
+    }
+
+    /// Shuffle the full deck with the given RNG and reset the deal position.
+    /// Passing a seeded RNG makes the resulting card order reproducible.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
         self.index = 0;
     }
 
@@ -144,6 +295,8 @@ impl Default for Deck {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn test_deck_has_52_cards() {
@@ -153,13 +306,67 @@ mod tests {
         assert!(deck.deal().is_none());
     }
 
+    #[test]
+    fn test_short_deck_has_no_low_cards() {
+        let cards = Deck::short().deal_n(52);
+        assert_eq!(cards.len(), 36);
+        assert!(cards.iter().all(|card| card.rank >= Rank::Six));
+    }
+
+    #[test]
+    fn test_cards_round_trip_through_text() {
+        for card in Deck::new().deal_n(52) {
+            let text = card.to_string();
+            assert!(text.is_ascii() && text.len() == 2, "{text}");
+            assert_eq!(text.parse::<Card>(), Ok(card));
+        }
+        assert_eq!(Card::new(Rank::Ten, Suit::Diamonds).to_string(), "Td");
+        assert_eq!("10d".parse(), Ok(Card::new(Rank::Ten, Suit::Diamonds)));
+        assert_eq!(Card::try_from("k♠"), Ok(Card::new(Rank::King, Suit::Spades)));
+        assert_eq!("Q".parse::<Rank>(), Ok(Rank::Queen));
+        assert_eq!("C".parse::<Suit>(), Ok(Suit::Clubs));
+    }
+
+    #[test]
+    fn test_parse_several_cards() {
+        let ace_king = vec![
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        assert_eq!(parse_cards("AsKd"), Ok(ace_king.clone()));
+        assert_eq!(parse_cards(" As, Kd "), Ok(ace_king));
+        assert_eq!(parse_cards("Ah 7c 2d").unwrap().len(), 3);
+        assert_eq!(parse_cards("10h9h").unwrap()[0].rank, Rank::Ten);
+        assert_eq!(parse_cards(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_bad_cards_are_rejected() {
+        for bad in ["", "A", "s", "Ax", "1s", "AsK", "Xs"] {
+            assert!(bad.parse::<Card>().is_err(), "{bad}");
+        }
+        assert!(parse_cards("AsKdQ").is_err());
+        assert!(parse_cards("AsAs").is_err());
+        assert!(parse_cards("Ah 1c").is_err());
+        assert!(parse_cards("Zh").is_err());
+    }
+
     #[test]
     fn test_shuffle_resets_index() {
         let mut deck = Deck::new();
         deck.deal();
         deck.deal();
-        deck.shuffle();
+        deck.shuffle(&mut StdRng::seed_from_u64(1));
         let cards: Vec<_> = (0..52).filter_map(|_| deck.deal()).collect();
         assert_eq!(cards.len(), 52);
     }
+
+    #[test]
+    fn test_same_seed_same_order() {
+        let mut a = Deck::new();
+        let mut b = Deck::new();
+        a.shuffle(&mut StdRng::seed_from_u64(42));
+        b.shuffle(&mut StdRng::seed_from_u64(42));
+        assert_eq!(a.deal_n(52), b.deal_n(52));
+    }
 }
@@ -0,0 +1,581 @@
+//! Win probability for hands on any board. When there are few enough
+//! possible runouts every one of them is dealt out; otherwise runouts are
+//! sampled at random.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use super::deck::Card;
+use super::evaluator::{evaluate, HandValue};
+use super::hand::Ranking;
+use super::variant::GameVariant;
+
+/// Runouts sampled when there are too many to enumerate.
+pub const DEFAULT_ITERATIONS: u32 = 10_000;
+
+/// One hand's results over all the runouts considered.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Equity {
+    /// Fraction of runouts won outright.
+    pub win: f64,
+    /// Fraction of runouts split with other hands.
+    pub tie: f64,
+    /// Expected share of the pot: wins plus an equal part of each split.
+    pub equity: f64,
+}
+
+pub struct EquityCalculator {
+    variant: GameVariant,
+    /// Runouts to sample. If there are no more possible runouts than this,
+    /// all of them are enumerated instead and the result is exact.
+    pub iterations: u32,
+    rng: StdRng,
+}
+
+impl EquityCalculator {
+    pub fn new(variant: GameVariant, iterations: u32) -> Self {
+        Self::with_rng(variant, iterations, StdRng::from_entropy())
+    }
+
+    /// A calculator whose sampled results are fully determined by `seed`.
+    pub fn with_seed(variant: GameVariant, iterations: u32, seed: u64) -> Self {
+        Self::with_rng(variant, iterations, StdRng::seed_from_u64(seed))
+    }
+
+    pub fn with_rng(variant: GameVariant, iterations: u32, rng: StdRng) -> Self {
+        Self {
+            variant,
+            iterations: iterations.max(1),
+            rng,
+        }
+    }
+
+    /// Equity of each hand against all the others, with the board run out
+    /// to five cards.
+    pub fn hands(&mut self, hands: &[Vec<Card>], board: &[Card]) -> Result<Vec<Equity>, String> {
+        if hands.len() < 2 {
+            return Err("need at least two hands".to_string());
+        }
+        let hands: Vec<&[Card]> = hands.iter().map(Vec::as_slice).collect();
+        let known = self.known_cards(&hands, board)?;
+        let ranking = self.variant.ranking();
+        let missing = 5 - board.len();
+        let mut stub = self.stub(&known);
+        let mut tally = Tally::new(hands.len());
+
+        if combination_count(stub.len(), missing) <= self.iterations as u64 {
+            for_each_combination(&stub, missing, |runout| {
+                showdown(ranking, &hands, board, runout, 1.0, &mut tally);
+            });
+        } else {
+            for _ in 0..self.iterations {
+                let (runout, _) = stub.partial_shuffle(&mut self.rng, missing);
+                showdown(ranking, &hands, board, runout, 1.0, &mut tally);
+            }
+        }
+        Ok(tally.finish())
+    }
+
+    /// Equity of `hand` against an opponent holding one of the `range`
+    /// combos, each with a relative weight. Combos that share a card with
+    /// the hand or the board are left out.
+    pub fn hand_vs_range<C: AsRef<[Card]>>(
+        &mut self,
+        hand: &[Card],
+        range: &[(C, f64)],
+        board: &[Card],
+    ) -> Result<Equity, String> {
+        let known = self.known_cards(&[hand], board)?;
+        let combos: Vec<(&[Card], f64)> = range
+            .iter()
+            .map(|(combo, weight)| (combo.as_ref(), *weight))
+            .filter(|(combo, weight)| {
+                *weight > 0.0
+                    && combo.len() == self.variant.hole_cards()
+                    && !combo.iter().any(|card| known.contains(card))
+            })
+            .collect();
+        if combos.is_empty() {
+            return Err("no hand in the range is possible with these cards".to_string());
+        }
+
+        let ranking = self.variant.ranking();
+        let missing = 5 - board.len();
+        let stub_len = self.stub(&known).len() - self.variant.hole_cards();
+        let per_combo = combination_count(stub_len, missing);
+        let mut tally = Tally::new(2);
+
+        if per_combo.saturating_mul(combos.len() as u64) <= self.iterations as u64 {
+            for &(combo, weight) in &combos {
+                let stub = self.stub(&[known.as_slice(), combo].concat());
+                let weight = weight / per_combo as f64;
+                for_each_combination(&stub, missing, |runout| {
+                    showdown(ranking, &[hand, combo], board, runout, weight, &mut tally);
+                });
+            }
+        } else {
+            for _ in 0..self.iterations {
+                let &(combo, _) = combos
+                    .choose_weighted(&mut self.rng, |(_, weight)| *weight)
+                    .map_err(|e| e.to_string())?;
+                let mut stub = self.stub(&[known.as_slice(), combo].concat());
+                let (runout, _) = stub.partial_shuffle(&mut self.rng, missing);
+                showdown(ranking, &[hand, combo], board, runout, 1.0, &mut tally);
+            }
+        }
+        Ok(tally.finish()[0])
+    }
+
+    /// Equity of two ranges against each other. Every `hero` combo is run
+    /// against the `villain` combos it doesn't block, and counts for as
+    /// many matchups as that leaves. Returns the hero's equity, then the
+    /// villain's.
+    pub fn range_vs_range<C: AsRef<[Card]>>(
+        &mut self,
+        hero: &[(C, f64)],
+        villain: &[(C, f64)],
+        board: &[Card],
+    ) -> Result<[Equity; 2], String> {
+        self.known_cards(&[], board)?;
+        let mut sum = Equity::default();
+        let mut total = 0.0;
+        for (combo, weight) in hero {
+            let combo = combo.as_ref();
+            if *weight <= 0.0 || combo.iter().any(|card| board.contains(card)) {
+                continue;
+            }
+            let matchups: f64 = villain
+                .iter()
+                .filter(|(other, _)| {
+                    !other
+                        .as_ref()
+                        .iter()
+                        .any(|card| combo.contains(card) || board.contains(card))
+                })
+                .map(|(_, weight)| weight.max(0.0))
+                .sum();
+            if matchups <= 0.0 {
+                continue;
+            }
+            let equity = self.hand_vs_range(combo, villain, board)?;
+            let weight = weight * matchups;
+            sum.win += equity.win * weight;
+            sum.tie += equity.tie * weight;
+            sum.equity += equity.equity * weight;
+            total += weight;
+        }
+        if total == 0.0 {
+            return Err("no hands in the two ranges can be dealt together".to_string());
+        }
+
+        let hero = Equity {
+            win: sum.win / total,
+            tie: sum.tie / total,
+            equity: sum.equity / total,
+        };
+        let villain = Equity {
+            win: (1.0 - hero.win - hero.tie).max(0.0),
+            tie: hero.tie,
+            equity: 1.0 - hero.equity,
+        };
+        Ok([hero, villain])
+    }
+
+    /// All the hands and board cards, checked for size and duplicates.
+    fn known_cards(&self, hands: &[&[Card]], board: &[Card]) -> Result<Vec<Card>, String> {
+        let hole_cards = self.variant.hole_cards();
+        if let Some(hand) = hands.iter().find(|hand| hand.len() != hole_cards) {
+            return Err(format!(
+                "{} needs {} hole cards per hand, got {}",
+                self.variant.name(),
+                hole_cards,
+                hand.len()
+            ));
+        }
+        if board.len() > 5 {
+            return Err(format!(
+                "board has {} cards, at most 5 allowed",
+                board.len()
+            ));
+        }
+
+        let deck = self.variant.deck().deal_n(52);
+        let mut known: Vec<Card> = Vec::new();
+        for &card in hands.iter().copied().flatten().chain(board) {
+            if known.contains(&card) {
+                return Err(format!("{} appears more than once", card));
+            }
+            if !deck.contains(&card) {
+                return Err(format!(
+                    "{} is not in the {} deck",
+                    card,
+                    self.variant.name()
+                ));
+            }
+            known.push(card);
+        }
+        Ok(known)
+    }
+
+    /// Cards left in the deck once the known cards are out.
+    fn stub(&self, known: &[Card]) -> Vec<Card> {
+        self.variant
+            .deck()
+            .deal_n(52)
+            .into_iter()
+            .filter(|card| !known.contains(card))
+            .collect()
+    }
+}
+
+/// Weighted results per hand, summed over runouts.
+struct Tally {
+    win: Vec<f64>,
+    tie: Vec<f64>,
+    share: Vec<f64>,
+    total: f64,
+    /// Scratch space for the hand values of the current runout.
+    values: Vec<HandValue>,
+}
+
+impl Tally {
+    fn new(hands: usize) -> Self {
+        Self {
+            win: vec![0.0; hands],
+            tie: vec![0.0; hands],
+            share: vec![0.0; hands],
+            total: 0.0,
+            values: Vec::with_capacity(hands),
+        }
+    }
+
+    /// Credit the best of `values` with a win, or a share of a tie.
+    fn add(&mut self, weight: f64) {
+        let best = self.values.iter().copied().max().unwrap_or_default();
+        let winners = self.values.iter().filter(|&&v| v == best).count();
+        for (i, &value) in self.values.iter().enumerate() {
+            if value != best {
+                continue;
+            }
+            if winners > 1 {
+                self.tie[i] += weight;
+            } else {
+                self.win[i] += weight;
+            }
+            self.share[i] += weight / winners as f64;
+        }
+        self.total += weight;
+    }
+
+    fn finish(self) -> Vec<Equity> {
+        (0..self.win.len())
+            .map(|i| Equity {
+                win: self.win[i] / self.total,
+                tie: self.tie[i] / self.total,
+                equity: self.share[i] / self.total,
+            })
+            .collect()
+    }
+}
+
+fn showdown(
+    ranking: Ranking,
+    hands: &[&[Card]],
+    board: &[Card],
+    runout: &[Card],
+    weight: f64,
+    tally: &mut Tally,
+) {
+    // Every hand has a card, so the first one can fill the unused slots
+    let mut full_board = [hands[0][0]; 5];
+    let len = board.len() + runout.len();
+    full_board[..board.len()].copy_from_slice(board);
+    full_board[board.len()..len].copy_from_slice(runout);
+
+    tally.values.clear();
+    tally.values.extend(
+        hands
+            .iter()
+            .map(|hand| evaluate(hand, &full_board[..len], ranking)),
+    );
+    tally.add(weight);
+}
+
+/// Number of ways to choose `k` of `n` cards.
+fn combination_count(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    (0..k as u64).fold(1, |count, i| count * (n as u64 - i) / (i + 1))
+}
+
+/// Call `f` with every `k`-card combination of `cards`, in order.
+fn for_each_combination(cards: &[Card], k: usize, mut f: impl FnMut(&[Card])) {
+    if k > cards.len() {
+        return;
+    }
+    let mut indices: Vec<usize> = (0..k).collect();
+    let mut combo: Vec<Card> = indices.iter().map(|&i| cards[i]).collect();
+    loop {
+        f(&combo);
+        // Advance the rightmost index that can still move
+        let Some(i) = (0..k).rev().find(|&i| indices[i] < cards.len() - k + i) else {
+            return;
+        };
+        indices[i] += 1;
+        for j in i + 1..k {
+            indices[j] = indices[j - 1] + 1;
+        }
+        for j in i..k {
+            combo[j] = cards[indices[j]];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::deck::{Deck, Rank, Suit};
+    use crate::game::range::Range;
+
+    fn card(rank: Rank, suit: Suit) -> Card {
+        Card::new(rank, suit)
+    }
+
+    fn holdem(seed: u64) -> EquityCalculator {
+        EquityCalculator::with_seed(GameVariant::NoLimitHoldem, 4_000, seed)
+    }
+
+    #[test]
+    fn test_combination_count() {
+        assert_eq!(combination_count(48, 5), 1_712_304);
+        assert_eq!(combination_count(45, 2), 990);
+        assert_eq!(combination_count(44, 0), 1);
+        assert_eq!(combination_count(3, 4), 0);
+
+        let cards = Deck::new().deal_n(52);
+        let mut seen = 0;
+        for_each_combination(&cards[..7], 3, |combo| {
+            assert_eq!(combo.len(), 3);
+            seen += 1;
+        });
+        assert_eq!(seen, 35);
+    }
+
+    #[test]
+    fn test_river_is_exact() {
+        let aces = vec![card(Rank::Ace, Suit::Spades), card(Rank::Ace, Suit::Hearts)];
+        let kings = vec![
+            card(Rank::King, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+        ];
+        let board = [
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Seven, Suit::Diamonds),
+            card(Rank::Nine, Suit::Clubs),
+            card(Rank::Jack, Suit::Hearts),
+            card(Rank::Three, Suit::Spades),
+        ];
+        let equities = holdem(1).hands(&[aces, kings], &board).unwrap();
+        assert_eq!(equities[0].win, 1.0);
+        assert_eq!(equities[1].equity, 0.0);
+    }
+
+    #[test]
+    fn test_turn_counts_outs_exactly() {
+        // Nut flush draw against top pair: 9 hearts and 3 aces out of 44 cards
+        let draw = vec![
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Five, Suit::Hearts),
+        ];
+        let pair = vec![
+            card(Rank::King, Suit::Spades),
+            card(Rank::Queen, Suit::Clubs),
+        ];
+        let board = [
+            card(Rank::King, Suit::Hearts),
+            card(Rank::Eight, Suit::Hearts),
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Jack, Suit::Diamonds),
+        ];
+        let equities = holdem(1).hands(&[draw, pair], &board).unwrap();
+        assert!((equities[0].equity - 12.0 / 44.0).abs() < 1e-9);
+        assert!((equities[0].equity + equities[1].equity - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_identical_hands_split() {
+        let a = vec![
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+        ];
+        let b = vec![
+            card(Rank::Ace, Suit::Diamonds),
+            card(Rank::King, Suit::Clubs),
+        ];
+        let board = [
+            card(Rank::Two, Suit::Spades),
+            card(Rank::Seven, Suit::Hearts),
+            card(Rank::Nine, Suit::Diamonds),
+            card(Rank::Jack, Suit::Clubs),
+        ];
+        let equities = holdem(1).hands(&[a, b], &board).unwrap();
+        assert_eq!(equities[0].tie, 1.0);
+        assert_eq!(equities[0].equity, 0.5);
+    }
+
+    #[test]
+    fn test_preflop_is_sampled() {
+        let aces = vec![card(Rank::Ace, Suit::Spades), card(Rank::Ace, Suit::Hearts)];
+        let kings = vec![
+            card(Rank::King, Suit::Diamonds),
+            card(Rank::King, Suit::Clubs),
+        ];
+        let equities = holdem(7).hands(&[aces, kings], &[]).unwrap();
+        // AA is about 82% against KK
+        assert!((equities[0].equity - 0.82).abs() < 0.03, "{equities:?}");
+    }
+
+    #[test]
+    fn test_hand_vs_range_skips_blocked_combos() {
+        let aces = [card(Rank::Ace, Suit::Spades), card(Rank::Ace, Suit::Hearts)];
+        let board = [
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Seven, Suit::Diamonds),
+            card(Rank::Nine, Suit::Clubs),
+            card(Rank::Jack, Suit::Hearts),
+            card(Rank::Three, Suit::Spades),
+        ];
+        let range = [
+            // Blocked by the hero's ace of spades
+            (
+                [card(Rank::Ace, Suit::Spades), card(Rank::Ace, Suit::Clubs)],
+                1.0,
+            ),
+            // Loses
+            (
+                [
+                    card(Rank::King, Suit::Spades),
+                    card(Rank::King, Suit::Hearts),
+                ],
+                1.0,
+            ),
+            // Wins with a set of nines, weighted three times as likely
+            (
+                [
+                    card(Rank::Nine, Suit::Spades),
+                    card(Rank::Nine, Suit::Hearts),
+                ],
+                3.0,
+            ),
+        ];
+        let equity = holdem(1).hand_vs_range(&aces, &range, &board).unwrap();
+        assert!((equity.equity - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sampled_range_matches_enumerated() {
+        let hand = [
+            card(Rank::Queen, Suit::Spades),
+            card(Rank::Queen, Suit::Hearts),
+        ];
+        let board = [
+            card(Rank::Ace, Suit::Clubs),
+            card(Rank::Seven, Suit::Diamonds),
+            card(Rank::Two, Suit::Clubs),
+        ];
+        let range = [
+            (
+                [
+                    card(Rank::Ace, Suit::Spades),
+                    card(Rank::King, Suit::Spades),
+                ],
+                1.0,
+            ),
+            (
+                [card(Rank::King, Suit::Clubs), card(Rank::Jack, Suit::Clubs)],
+                1.0,
+            ),
+        ];
+        let exact = EquityCalculator::with_seed(GameVariant::NoLimitHoldem, 5_000, 1)
+            .hand_vs_range(&hand, &range, &board)
+            .unwrap();
+        let sampled = EquityCalculator::with_seed(GameVariant::NoLimitHoldem, 1_500, 1)
+            .hand_vs_range(&hand, &range, &board)
+            .unwrap();
+        assert!(
+            (exact.equity - sampled.equity).abs() < 0.04,
+            "{exact:?} {sampled:?}"
+        );
+    }
+
+    #[test]
+    fn test_short_deck_deck_and_ranking() {
+        let mut calc = EquityCalculator::with_seed(GameVariant::ShortDeck, 1_000, 1);
+        let low = vec![
+            card(Rank::Five, Suit::Spades),
+            card(Rank::Six, Suit::Hearts),
+        ];
+        let high = vec![
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+        ];
+        assert!(calc.hands(&[low, high], &[]).is_err());
+
+        // The flush beats the full house
+        let flush = vec![
+            card(Rank::Ace, Suit::Hearts),
+            card(Rank::Seven, Suit::Hearts),
+        ];
+        let full_house = vec![
+            card(Rank::King, Suit::Diamonds),
+            card(Rank::Nine, Suit::Clubs),
+        ];
+        let board = [
+            card(Rank::King, Suit::Hearts),
+            card(Rank::King, Suit::Spades),
+            card(Rank::Nine, Suit::Hearts),
+            card(Rank::Eight, Suit::Hearts),
+            card(Rank::Six, Suit::Clubs),
+        ];
+        let equities = calc.hands(&[flush, full_house], &board).unwrap();
+        assert_eq!(equities[0].win, 1.0);
+    }
+
+    #[test]
+    fn test_range_vs_range_weights_matchups() {
+        let aces = Range::parse("AA").unwrap().combos();
+        let villain = Range::parse("KK, AA").unwrap().combos();
+        let board = [
+            card(Rank::Two, Suit::Clubs),
+            card(Rank::Seven, Suit::Diamonds),
+            card(Rank::Nine, Suit::Clubs),
+            card(Rank::Jack, Suit::Hearts),
+            card(Rank::Three, Suit::Spades),
+        ];
+        // Every hero AA leaves one AA combo, a split, and all six KK
+        let [hero, villain] = holdem(1).range_vs_range(&aces, &villain, &board).unwrap();
+        assert!((hero.win - 6.0 / 7.0).abs() < 1e-9, "{hero:?}");
+        assert!((hero.tie - 1.0 / 7.0).abs() < 1e-9);
+        assert!((villain.equity - 0.5 / 7.0).abs() < 1e-9);
+        assert!(villain.win.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bad_input_is_rejected() {
+        let a = vec![
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::King, Suit::Hearts),
+        ];
+        let b = vec![
+            card(Rank::Ace, Suit::Spades),
+            card(Rank::Queen, Suit::Clubs),
+        ];
+        let mut calc = holdem(1);
+        assert!(calc.hands(&[a.clone(), b], &[]).is_err());
+        assert!(calc.hands(std::slice::from_ref(&a), &[]).is_err());
+        let short = vec![card(Rank::Two, Suit::Clubs)];
+        assert!(calc.hands(&[a, short], &[]).is_err());
+    }
+}
@@ -0,0 +1,321 @@
+//! Fast hand evaluation for equity and solver work. Hands are reduced to a
+//! single comparable `HandValue` using per-suit rank bitmasks and a
+//! precomputed straight table, with no allocation. A full
+//! `HandEvaluation`, with its description, is only built on request.
+
+use super::deck::{Card, Rank};
+use super::hand::{rank_name, HandEvaluation, HandRank, Ranking};
+
+/// Bitmask of A-6-7-8-9, the lowest short-deck straight.
+const SHORT_DECK_WHEEL: u16 = rank_bit(14) | rank_bit(6) | rank_bit(7) | rank_bit(8) | rank_bit(9);
+
+/// High card of the best straight in every 13-bit rank mask, 0 if none.
+static STRAIGHTS: [u8; 8192] = straight_table();
+
+const fn rank_bit(rank: u8) -> u16 {
+    1 << (rank - 2)
+}
+
+const fn straight_table() -> [u8; 8192] {
+    let mut table = [0u8; 8192];
+    let mut mask = 0;
+    while mask < 8192 {
+        let mut high = 14;
+        while high >= 6 {
+            let run = 0b11111 << (high - 6);
+            if mask & run == run {
+                table[mask] = high as u8;
+                break;
+            }
+            high -= 1;
+        }
+        // A-2-3-4-5
+        let wheel = rank_bit(14) | rank_bit(2) | rank_bit(3) | rank_bit(4) | rank_bit(5);
+        if table[mask] == 0 && mask as u16 & wheel == wheel {
+            table[mask] = 5;
+        }
+        mask += 1;
+    }
+    table
+}
+
+const HAND_RANKS: [HandRank; 9] = [
+    HandRank::HighCard,
+    HandRank::Pair,
+    HandRank::TwoPair,
+    HandRank::ThreeOfAKind,
+    HandRank::Straight,
+    HandRank::Flush,
+    HandRank::FullHouse,
+    HandRank::FourOfAKind,
+    HandRank::StraightFlush,
+];
+
+/// Strength of a made hand: higher beats lower, equal values split.
+///
+/// Packed as the category's place in the ranking, the category itself and
+/// up to five kicker ranks, four bits each, most significant first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandValue(u32);
+
+impl HandValue {
+    fn new(rank: HandRank, kickers: &[u8], ranking: Ranking) -> Self {
+        let mut value = (ranking.order(rank) as u32) << 24 | (rank as u32) << 20;
+        for (i, &kicker) in kickers.iter().enumerate() {
+            value |= (kicker as u32) << (16 - 4 * i);
+        }
+        Self(value)
+    }
+
+    pub fn value(self) -> u32 {
+        self.0
+    }
+
+    pub fn rank(self) -> HandRank {
+        HAND_RANKS[(self.0 >> 20 & 0xF) as usize]
+    }
+
+    /// Ranks that decide ties, most important first.
+    pub fn kickers(self) -> Vec<Rank> {
+        (0..5)
+            .map(|i| (self.0 >> (16 - 4 * i) & 0xF) as u8)
+            .take_while(|&rank| rank != 0)
+            .map(rank_from_value)
+            .collect()
+    }
+
+    /// The full evaluation, with a description for display.
+    pub fn evaluation(self) -> HandEvaluation {
+        let rank = self.rank();
+        let kickers = self.kickers();
+        let name = |i: usize| kickers.get(i).map_or("", |&r| rank_name(r));
+        let description = match rank {
+            HandRank::HighCard => format!("{} high", name(0)),
+            HandRank::Pair => format!("Pair of {}", name(0)),
+            HandRank::TwoPair => format!("Two pair, {} and {}", name(0), name(1)),
+            HandRank::ThreeOfAKind => format!("Three of a kind, {}", name(0)),
+            HandRank::Straight => format!("{} high straight", name(0)),
+            HandRank::Flush => format!("{} high flush", name(0)),
+            HandRank::FullHouse => format!("Full house, {} full of {}", name(0), name(1)),
+            HandRank::FourOfAKind => format!("Four of a kind, {}", name(0)),
+            HandRank::StraightFlush => format!("{} high straight flush", name(0)),
+        };
+        HandEvaluation {
+            rank,
+            kickers,
+            description,
+        }
+    }
+}
+
+/// Best hand from the hole cards and board. With four hole cards (Omaha)
+/// the hand must use exactly two of them and three from the board; before
+/// the flop the best two-card start is rated.
+pub fn evaluate(hole_cards: &[Card], board: &[Card], ranking: Ranking) -> HandValue {
+    if hole_cards.len() != 4 {
+        return evaluate_cards(hole_cards, board, ranking);
+    }
+
+    let mut best = HandValue::default();
+    for i in 0..4 {
+        for j in i + 1..4 {
+            let hole = [hole_cards[i], hole_cards[j]];
+            if board.len() < 3 {
+                best = best.max(evaluate_cards(&hole, board, ranking));
+                continue;
+            }
+            for a in 0..board.len() {
+                for b in a + 1..board.len() {
+                    for c in b + 1..board.len() {
+                        let flop = [board[a], board[b], board[c]];
+                        best = best.max(evaluate_cards(&hole, &flop, ranking));
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Best hand that can be made from any of the given cards.
+fn evaluate_cards(hole_cards: &[Card], board: &[Card], ranking: Ranking) -> HandValue {
+    let mut suits = [0u16; 4];
+    let mut counts = [0u8; 15];
+    for card in hole_cards.iter().chain(board) {
+        suits[card.suit as usize] |= rank_bit(card.rank as u8);
+        counts[card.rank as usize] += 1;
+    }
+    let all = suits[0] | suits[1] | suits[2] | suits[3];
+
+    let flush = suits.iter().copied().find(|mask| mask.count_ones() >= 5);
+    if let Some(high) = flush.and_then(|mask| straight_high(mask, ranking)) {
+        return HandValue::new(HandRank::StraightFlush, &[high], ranking);
+    }
+
+    let (mut quads, mut trips, mut pairs) = (0u16, 0u16, 0u16);
+    for rank in 2..=14u8 {
+        match counts[rank as usize] {
+            4 => quads |= rank_bit(rank),
+            3 => trips |= rank_bit(rank),
+            2 => pairs |= rank_bit(rank),
+            _ => {}
+        }
+    }
+
+    if quads != 0 {
+        let quad = highest(quads);
+        let kicker = highest(all & !rank_bit(quad));
+        return HandValue::new(HandRank::FourOfAKind, &[quad, kicker], ranking);
+    }
+    if trips != 0 {
+        let trip = highest(trips);
+        // A second set of trips counts as the pair
+        let pair = highest((trips & !rank_bit(trip)) | pairs);
+        if pair != 0 {
+            return HandValue::new(HandRank::FullHouse, &[trip, pair], ranking);
+        }
+    }
+    if let Some(mask) = flush {
+        return HandValue::new(HandRank::Flush, &top(mask, 5), ranking);
+    }
+    if let Some(high) = straight_high(all, ranking) {
+        return HandValue::new(HandRank::Straight, &[high], ranking);
+    }
+    if trips != 0 {
+        let trip = highest(trips);
+        let rest = top(all & !rank_bit(trip), 2);
+        return HandValue::new(HandRank::ThreeOfAKind, &[trip, rest[0], rest[1]], ranking);
+    }
+    if pairs.count_ones() >= 2 {
+        let [high, low, ..] = top(pairs, 2);
+        let kicker = highest(all & !rank_bit(high) & !rank_bit(low));
+        return HandValue::new(HandRank::TwoPair, &[high, low, kicker], ranking);
+    }
+    if pairs != 0 {
+        let pair = highest(pairs);
+        let rest = top(all & !rank_bit(pair), 3);
+        return HandValue::new(HandRank::Pair, &[pair, rest[0], rest[1], rest[2]], ranking);
+    }
+    HandValue::new(HandRank::HighCard, &top(all, 5), ranking)
+}
+
+fn straight_high(mask: u16, ranking: Ranking) -> Option<u8> {
+    match STRAIGHTS[mask as usize] {
+        0 if ranking == Ranking::ShortDeck && mask & SHORT_DECK_WHEEL == SHORT_DECK_WHEEL => {
+            Some(9)
+        }
+        0 => None,
+        high => Some(high),
+    }
+}
+
+/// Highest rank in the mask, 0 if it is empty.
+fn highest(mask: u16) -> u8 {
+    if mask == 0 {
+        0
+    } else {
+        (15 - mask.leading_zeros()) as u8 + 2
+    }
+}
+
+/// The `n` highest ranks in the mask, padded with 0.
+fn top(mut mask: u16, n: usize) -> [u8; 5] {
+    let mut ranks = [0u8; 5];
+    for rank in ranks.iter_mut().take(n) {
+        *rank = highest(mask);
+        if *rank == 0 {
+            break;
+        }
+        mask &= !rank_bit(*rank);
+    }
+    ranks
+}
+
+fn rank_from_value(value: u8) -> Rank {
+    Rank::ALL[value as usize - 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::deck::parse_cards;
+
+    fn value(cards: &str) -> HandValue {
+        evaluate(&parse_cards(cards).unwrap(), &[], Ranking::Standard)
+    }
+
+    #[test]
+    fn test_categories() {
+        let cases = [
+            ("AsKd9h7c3s2d4h", HandRank::HighCard),
+            ("AsAd9h7c3s", HandRank::Pair),
+            ("AsAd9h9c3s", HandRank::TwoPair),
+            ("AsAdAh9c3s", HandRank::ThreeOfAKind),
+            ("As2d3h4c5s", HandRank::Straight),
+            ("As9s7s4s2s", HandRank::Flush),
+            ("AsAdAh9c9s", HandRank::FullHouse),
+            ("AsAdAhAc9s", HandRank::FourOfAKind),
+            ("9s8s7s6s5s", HandRank::StraightFlush),
+        ];
+        for (cards, rank) in cases {
+            assert_eq!(value(cards).rank(), rank, "{cards}");
+        }
+        let values: Vec<HandValue> = cases.iter().map(|(cards, _)| value(cards)).collect();
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_kickers_break_ties() {
+        assert!(value("AsAdKh7c3s") > value("AhAcQh7d3c"));
+        assert!(value("AsAdKh9c3s") > value("AhAcKd8c3c"));
+        // Only five cards play: the sixth and seventh don't count
+        assert_eq!(value("AsAdKhQcJs3d2h"), value("AhAcKdQhJc4c2s"));
+        // Two pair plays the best two pairs
+        assert_eq!(
+            value("AsAdKhKc9s9d2h").kickers(),
+            vec![Rank::Ace, Rank::King, Rank::Nine]
+        );
+        // Two sets make a full house
+        assert_eq!(value("AsAdAhKcKsKd2h").rank(), HandRank::FullHouse);
+    }
+
+    #[test]
+    fn test_straights() {
+        assert_eq!(value("As2d3h4c5s").kickers(), vec![Rank::Five]);
+        assert!(value("2d3h4c5s6s") > value("As2d3h4c5s"));
+        assert_eq!(value("AsKdQhJcTs9d").kickers(), vec![Rank::Ace]);
+        let short = evaluate(&parse_cards("As6d7h8c9s").unwrap(), &[], Ranking::ShortDeck);
+        assert_eq!(short.rank(), HandRank::Straight);
+        assert_eq!(short.kickers(), vec![Rank::Nine]);
+    }
+
+    #[test]
+    fn test_short_deck_flush_beats_full_house() {
+        let flush = evaluate(&parse_cards("As9s7s8s6s").unwrap(), &[], Ranking::ShortDeck);
+        let boat = evaluate(&parse_cards("AsAdAh9c9s").unwrap(), &[], Ranking::ShortDeck);
+        assert!(flush > boat);
+        assert!(value("As9s7s8s6s") < value("AsAdAh9c9s"));
+    }
+
+    #[test]
+    fn test_omaha_uses_two_hole_cards() {
+        let hole = parse_cards("AsKsQdJd").unwrap();
+        let board = parse_cards("2s3s4s9h9c").unwrap();
+        // Only two spades from the hand: a flush, not a straight flush or nothing
+        assert_eq!(
+            evaluate(&hole, &board, Ranking::Standard).rank(),
+            HandRank::Flush
+        );
+    }
+
+    #[test]
+    fn test_evaluation_description() {
+        let eval = value("AsAdAh9c9s").evaluation();
+        assert_eq!(eval.rank, HandRank::FullHouse);
+        assert_eq!(eval.description, "Full house, aces full of nines");
+        assert_eq!(
+            value("KsKd9h7c3s").evaluation().description,
+            "Pair of kings"
+        );
+    }
+}
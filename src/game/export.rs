@@ -0,0 +1,535 @@
+//! Export recorded hands as PokerStars-style text hand histories, the format
+//! most trackers, equity tools and coaching sites can import.
+
+use super::actions::Action;
+use super::deck::Card;
+use super::history::{HandOutcome, HandRecord};
+use super::state::{Street, HUMAN};
+use super::variant::BettingLimit;
+
+const TABLE_NAME: &str = "terminal-poker";
+
+/// Render every completed hand in PokerStars format. Hands still in
+/// progress are skipped.
+pub fn to_pokerstars(hands: &[HandRecord]) -> String {
+    hands
+        .iter()
+        .filter(|hand| hand.result.is_some())
+        .map(pokerstars_hand)
+        .collect::<Vec<_>>()
+        .join("\n\n\n")
+}
+
+fn pokerstars_hand(hand: &HandRecord) -> String {
+    let mut lines = Vec::new();
+    let result = match hand.result {
+        Some(ref result) => result,
+        None => return String::new(),
+    };
+    let table_size = result.stacks.len();
+
+    // Hand ids must be unique across sessions for trackers to import them
+    let hand_id = hand.started_at * 10_000 + (hand.hand_number % 10_000) as u64;
+    // Limit games are named by their small and big bet rather than the blinds
+    let stakes = match hand.variant.limit() {
+        BettingLimit::FixedLimit => (hand.big_blind, hand.big_blind * 2),
+        _ => (hand.small_blind, hand.big_blind),
+    };
+    lines.push(format!(
+        "PokerStars Hand #{}:  {} ({}/{}) - {} UTC",
+        hand_id,
+        hand.variant.pokerstars_name(),
+        stakes.0,
+        stakes.1,
+        format_timestamp(hand.started_at)
+    ));
+    lines.push(format!(
+        "Table '{}' {}-max Seat #{} is the button",
+        TABLE_NAME,
+        table_size,
+        hand.button + 1
+    ));
+    for record in &hand.seats {
+        lines.push(format!(
+            "Seat {}: {} ({} in chips)",
+            record.seat + 1,
+            name(record.seat, table_size),
+            record.stack
+        ));
+    }
+
+    // Antes come off the stack before the blinds, except a big-blind ante,
+    // which the big blind only pays out of what's left after the blind
+    let ante_paid = |seat: usize| {
+        let stack = starting_stack(hand, seat);
+        if !hand.big_blind_ante {
+            hand.ante.min(stack)
+        } else if seat == hand.big_blind_seat {
+            hand.ante.min(stack - hand.big_blind.min(stack))
+        } else {
+            0
+        }
+    };
+    let mut antes = 0;
+    for record in &hand.seats {
+        let paid = ante_paid(record.seat);
+        if paid > 0 {
+            antes += paid;
+            lines.push(format!(
+                "{}: posts the ante {}",
+                name(record.seat, table_size),
+                paid
+            ));
+        }
+    }
+    let behind = |seat: usize| {
+        let stack = starting_stack(hand, seat);
+        if hand.big_blind_ante {
+            stack
+        } else {
+            stack - ante_paid(seat)
+        }
+    };
+    let sb = hand.small_blind.min(behind(hand.small_blind_seat));
+    let bb = hand.big_blind.min(behind(hand.big_blind_seat));
+    lines.push(format!(
+        "{}: posts small blind {}",
+        name(hand.small_blind_seat, table_size),
+        sb
+    ));
+    lines.push(format!(
+        "{}: posts big blind {}",
+        name(hand.big_blind_seat, table_size),
+        bb
+    ));
+    lines.push("*** HOLE CARDS ***".to_string());
+    if let Some(hero) = hand.seat(HUMAN) {
+        lines.push(format!(
+            "Dealt to {} [{}]",
+            name(HUMAN, table_size),
+            cards_text(&hero.cards)
+        ));
+    }
+
+    let mut contributed = antes;
+    let mut uncalled_total = 0;
+    let mut uncalled_to: Option<(usize, u32)> = None;
+    let mut folded: Vec<Option<Street>> = vec![None; table_size];
+
+    for street in &hand.streets {
+        let mut bets = vec![0u32; table_size];
+        match street.street {
+            Street::Preflop => {
+                bets[hand.small_blind_seat] = sb;
+                bets[hand.big_blind_seat] = bb;
+            }
+            Street::Flop => lines.push(format!("*** FLOP *** [{}]", cards_text(&street.board))),
+            Street::Turn | Street::River => {
+                let label = if street.street == Street::Turn {
+                    "TURN"
+                } else {
+                    "RIVER"
+                };
+                let (previous, new) = street.board.split_at(street.board.len() - 1);
+                lines.push(format!(
+                    "*** {} *** [{}] [{}]",
+                    label,
+                    cards_text(previous),
+                    cards_text(new)
+                ));
+            }
+        }
+
+        for record in &street.actions {
+            let p = record.seat;
+            let max = bets.iter().copied().max().unwrap_or(0);
+            let who = name(p, table_size);
+            let text = match record.action {
+                Action::Fold => {
+                    folded[p] = Some(street.street);
+                    format!("{}: folds", who)
+                }
+                Action::Check => format!("{}: checks", who),
+                Action::Call(amount) => {
+                    bets[p] += amount;
+                    format!("{}: calls {}", who, amount)
+                }
+                Action::Bet(total) | Action::Raise(total) => {
+                    bets[p] = total;
+                    if max == 0 {
+                        format!("{}: bets {}", who, total)
+                    } else {
+                        format!("{}: raises {} to {}", who, total - max, total)
+                    }
+                }
+                Action::AllIn(total) => {
+                    let added = total - bets[p];
+                    bets[p] = total;
+                    if total <= max {
+                        format!("{}: calls {} and is all-in", who, added)
+                    } else if max == 0 {
+                        format!("{}: bets {} and is all-in", who, total)
+                    } else {
+                        format!("{}: raises {} to {} and is all-in", who, total - max, total)
+                    }
+                }
+            };
+            lines.push(text);
+        }
+
+        contributed += bets.iter().sum::<u32>();
+        // Whatever the biggest bet put in beyond the next biggest goes back
+        let mut order: Vec<usize> = (0..table_size).collect();
+        order.sort_by(|a, b| bets[*b].cmp(&bets[*a]));
+        let (top, second) = (order[0], order.get(1).map(|&s| bets[s]).unwrap_or(0));
+        if bets[top] > second {
+            let uncalled = bets[top] - second;
+            uncalled_total += uncalled;
+            uncalled_to = Some((top, uncalled));
+            lines.push(format!(
+                "Uncalled bet ({}) returned to {}",
+                uncalled,
+                name(top, table_size)
+            ));
+        }
+    }
+
+    let pot = contributed - uncalled_total;
+
+    // Amount each seat takes from the pot. Without a showdown the engine
+    // hands the uncalled bet back as part of the pot, so take it off again.
+    let mut won: Vec<u32> = (0..table_size).map(|seat| result.winnings(seat)).collect();
+    if !result.showdown {
+        if let Some((seat, uncalled)) = uncalled_to {
+            won[seat] = won[seat].saturating_sub(uncalled);
+        }
+    }
+
+    if result.showdown {
+        lines.push("*** SHOW DOWN ***".to_string());
+        for seat in showdown_order(hand, result, table_size) {
+            lines.push(format!(
+                "{}: shows [{}] ({})",
+                name(seat, table_size),
+                cards_text(hole_cards(hand, seat)),
+                result.shown_hand(seat).unwrap_or("")
+            ));
+        }
+    }
+    let side_pots = result.showdown && result.pots.len() > 1;
+    if side_pots {
+        // Side pots are listed before the main pot, as PokerStars does
+        for (index, award) in result.pots.iter().enumerate().rev() {
+            for &seat in &award.winners {
+                lines.push(format!(
+                    "{} collected {} from {}",
+                    name(seat, table_size),
+                    award.share(seat),
+                    pot_name(index)
+                ));
+            }
+        }
+    } else {
+        for record in &hand.seats {
+            if won[record.seat] > 0 {
+                lines.push(format!(
+                    "{} collected {} from pot",
+                    name(record.seat, table_size),
+                    won[record.seat]
+                ));
+            }
+        }
+    }
+
+    lines.push("*** SUMMARY ***".to_string());
+    if side_pots {
+        let breakdown: Vec<String> = result
+            .pots
+            .iter()
+            .enumerate()
+            .map(|(index, award)| format!("{} {}.", capitalize(&pot_name(index)), award.amount))
+            .collect();
+        lines.push(format!("Total pot {} {} | Rake 0", pot, breakdown.join(" ")));
+    } else {
+        lines.push(format!("Total pot {} | Rake 0", pot));
+    }
+    let board = hand.final_board();
+    if !board.is_empty() {
+        lines.push(format!("Board [{}]", cards_text(board)));
+    }
+    for record in &hand.seats {
+        let seat = record.seat;
+        let mut position = String::new();
+        if seat == hand.button {
+            position.push_str(" (button)");
+        }
+        if seat == hand.small_blind_seat {
+            position.push_str(" (small blind)");
+        } else if seat == hand.big_blind_seat {
+            position.push_str(" (big blind)");
+        }
+        let outcome = match folded[seat] {
+            Some(Street::Preflop) => "folded before Flop".to_string(),
+            Some(Street::Flop) => "folded on the Flop".to_string(),
+            Some(Street::Turn) => "folded on the Turn".to_string(),
+            Some(Street::River) => "folded on the River".to_string(),
+            None if result.showdown => {
+                let cards = cards_text(hole_cards(hand, seat));
+                let made = result.shown_hand(seat).unwrap_or("");
+                if won[seat] > 0 {
+                    format!("showed [{}] and won ({}) with {}", cards, won[seat], made)
+                } else {
+                    format!("showed [{}] and lost with {}", cards, made)
+                }
+            }
+            None => format!("collected ({})", won[seat]),
+        };
+        lines.push(format!(
+            "Seat {}: {}{} {}",
+            seat + 1,
+            name(seat, table_size),
+            position,
+            outcome
+        ));
+    }
+
+    lines.join("\n")
+}
+
+// ── Helpers ─────────────────────────────────────────────────
+
+fn name(seat: usize, table_size: usize) -> String {
+    if seat == HUMAN {
+        "Hero".to_string()
+    } else if table_size == 2 {
+        "Bot".to_string()
+    } else {
+        format!("Bot {}", seat)
+    }
+}
+
+fn pot_name(index: usize) -> String {
+    match index {
+        0 => "main pot".to_string(),
+        1 => "side pot".to_string(),
+        n => format!("side pot-{}", n - 1),
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn starting_stack(hand: &HandRecord, seat: usize) -> u32 {
+    hand.seat(seat).map(|s| s.stack).unwrap_or(0)
+}
+
+fn hole_cards(hand: &HandRecord, seat: usize) -> &[Card] {
+    hand.seat(seat).map(|s| s.cards.as_slice()).unwrap_or(&[])
+}
+
+/// Shown hands in table order, starting left of the button.
+fn showdown_order(hand: &HandRecord, result: &HandOutcome, table_size: usize) -> Vec<usize> {
+    let mut seats: Vec<usize> = result.shown.iter().map(|shown| shown.seat).collect();
+    seats.sort_by_key(|&seat| (seat + table_size - hand.button - 1) % table_size);
+    seats
+}
+
+/// PokerStars card notation, e.g. `Ah`, `Td`: the cards' ASCII form.
+fn card_text(card: &Card) -> String {
+    card.to_string()
+}
+
+fn cards_text(cards: &[Card]) -> String {
+    cards.iter().map(card_text).collect::<Vec<_>>().join(" ")
+}
+
+/// Format a Unix timestamp as `YYYY/MM/DD HH:MM:SS` (UTC).
+fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let time = secs % 86_400;
+
+    // Civil-from-days (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}/{:02}/{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::deck::{Rank, Suit};
+    use crate::game::state::{GameConfig, GamePhase, GameState, Stakes};
+    use crate::game::variant::GameVariant;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_timestamp_format() {
+        assert_eq!(format_timestamp(0), "1970/01/01 00:00:00");
+        assert_eq!(format_timestamp(951_782_400), "2000/02/29 00:00:00");
+        assert_eq!(format_timestamp(1_700_000_000), "2023/11/14 22:13:20");
+    }
+
+    #[test]
+    fn test_raise_fold_returns_uncalled_bet() {
+        let mut state = GameState::with_seed(100, 21);
+        let opener = state.to_act;
+        state.apply_action(opener, Action::Raise(6));
+        state.apply_action(1 - opener, Action::Fold);
+
+        let text = to_pokerstars(&state.take_finished_hands());
+        let opener = name(opener, 2);
+        assert!(text.starts_with("PokerStars Hand #"));
+        assert!(text.contains("Hold'em No Limit (1/2)"));
+        assert!(text.contains(&format!("{}: posts small blind 1", opener)));
+        assert!(text.contains(&format!("{}: raises 4 to 6", opener)));
+        assert!(text.contains(&format!("Uncalled bet (4) returned to {}", opener)));
+        assert!(text.contains(&format!("{} collected 4 from pot", opener)));
+        assert!(text.contains("Total pot 4 | Rake 0"));
+        assert!(text.contains("folded before Flop"));
+        assert!(!text.contains("*** FLOP ***"));
+    }
+
+    #[test]
+    fn test_showdown_hand_lists_every_street() {
+        let mut state = GameState::with_seed(100, 4);
+        while !matches!(state.phase, GamePhase::Showdown | GamePhase::HandComplete) {
+            let to_call = state.amount_to_call(state.to_act);
+            let action = if to_call > 0 {
+                Action::Call(to_call)
+            } else {
+                Action::Check
+            };
+            state.apply_action(state.to_act, action);
+        }
+
+        let hands = state.take_finished_hands();
+        let text = to_pokerstars(&hands);
+        let board = cards_text(&state.board);
+        assert!(text.contains(&format!(
+            "Dealt to Hero [{}]",
+            cards_text(&state.seats[HUMAN].cards)
+        )));
+        assert!(text.contains(&format!("*** FLOP *** [{}]", cards_text(&state.board[..3]))));
+        assert!(text.contains(&format!(
+            "*** RIVER *** [{}] [{}]",
+            cards_text(&state.board[..4]),
+            card_text(&state.board[4])
+        )));
+        assert!(text.contains("*** SHOW DOWN ***"));
+        assert!(text.contains(&format!("Board [{}]", board)));
+        assert!(text.contains(&format!(
+            "Total pot {} | Rake 0",
+            hands[0].result.as_ref().unwrap().pot
+        )));
+    }
+
+    #[test]
+    fn test_side_pots_are_split_out() {
+        let config = GameConfig {
+            seats: 3,
+            starting_stack_bb: 100,
+            variant: GameVariant::default(),
+            stakes: Stakes::default(),
+            tournament: None,
+        };
+        let mut state = GameState::with_config(config, StdRng::seed_from_u64(8));
+        state.seats[1].stack = 50;
+        state.seats[2].stack = 100;
+        state.button = 2;
+        state.start_new_hand();
+        // Button (seat 0) shoves, both blinds call off their stacks
+        state.apply_action(0, Action::AllIn(200));
+        state.apply_action(1, Action::AllIn(50));
+        state.apply_action(2, Action::AllIn(100));
+        while state.phase != GamePhase::Showdown {
+            state.advance_phase();
+        }
+
+        let text = to_pokerstars(&state.take_finished_hands());
+        assert!(text.contains("Table 'terminal-poker' 3-max Seat #1 is the button"));
+        assert!(text.contains("Bot 1: posts small blind 1"));
+        assert!(text.contains("Uncalled bet (100) returned to Hero"));
+        assert!(text.contains("Total pot 250 Main pot 150. Side pot 100. | Rake 0"));
+        assert!(text.contains("from main pot"));
+        assert!(text.contains("from side pot"));
+    }
+
+    #[test]
+    fn test_antes_are_posted_and_counted() {
+        let config = GameConfig {
+            seats: 3,
+            starting_stack_bb: 100,
+            variant: GameVariant::default(),
+            stakes: Stakes {
+                small_blind: 10,
+                big_blind: 20,
+                ante: 5,
+                big_blind_ante: false,
+            },
+            tournament: None,
+        };
+        let mut state = GameState::with_config(config, StdRng::seed_from_u64(2));
+        state.apply_action(0, Action::Fold);
+        state.apply_action(1, Action::Fold);
+
+        let text = to_pokerstars(&state.take_finished_hands());
+        assert!(text.contains("Hold'em No Limit (10/20)"));
+        assert!(text.contains("Hero: posts the ante 5"));
+        assert!(text.contains("Bot 2: posts the ante 5"));
+        assert!(text.contains("Uncalled bet (10) returned to Bot 2"));
+        assert!(text.contains("Bot 2 collected 35 from pot"));
+        assert!(text.contains("Total pot 35 | Rake 0"));
+    }
+
+    #[test]
+    fn test_big_blind_ante_is_posted_once() {
+        let config = GameConfig {
+            seats: 3,
+            starting_stack_bb: 100,
+            variant: GameVariant::default(),
+            stakes: Stakes {
+                small_blind: 10,
+                big_blind: 20,
+                ante: 20,
+                big_blind_ante: true,
+            },
+            tournament: None,
+        };
+        let mut state = GameState::with_config(config, StdRng::seed_from_u64(2));
+        state.apply_action(0, Action::Fold);
+        state.apply_action(1, Action::Fold);
+
+        let text = to_pokerstars(&state.take_finished_hands());
+        assert_eq!(text.matches("posts the ante").count(), 1);
+        assert!(text.contains("Bot 2: posts the ante 20"));
+        assert!(text.contains("Bot 2 collected 40 from pot"));
+    }
+
+    #[test]
+    fn test_card_text_uses_t_for_ten() {
+        assert_eq!(card_text(&Card::new(Rank::Ten, Suit::Diamonds)), "Td");
+        assert_eq!(card_text(&Card::new(Rank::Ace, Suit::Spades)), "As");
+    }
+}
@@ -1,7 +1,9 @@
 use super::deck::{Card, Rank};
+use super::evaluator;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum HandRank {
     HighCard = 0,
     Pair = 1,
@@ -14,7 +16,28 @@ pub enum HandRank {
     StraightFlush = 8,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Hand ranking rules. Short-deck (6+) Hold'em has no 2-5, which makes
+/// flushes rarer than full houses, so a flush ranks higher; A-6-7-8-9 is
+/// its lowest straight.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Ranking {
+    #[default]
+    Standard,
+    ShortDeck,
+}
+
+impl Ranking {
+    /// Position of a category in this ranking, lowest first.
+    pub(crate) fn order(self, rank: HandRank) -> u8 {
+        match (self, rank) {
+            (Ranking::ShortDeck, HandRank::Flush) => HandRank::FullHouse as u8,
+            (Ranking::ShortDeck, HandRank::FullHouse) => HandRank::Flush as u8,
+            _ => rank as u8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HandEvaluation {
     pub rank: HandRank,
     pub kickers: Vec<Rank>,
@@ -24,7 +47,11 @@ pub struct HandEvaluation {
 impl HandEvaluation {
     /// Returns a normalized strength value between 0.0 and 1.0
     pub fn strength(&self) -> f64 {
-        let base = self.rank as u32 as f64 / 8.0;
+        self.strength_with(Ranking::Standard)
+    }
+
+    pub fn strength_with(&self, ranking: Ranking) -> f64 {
+        let base = ranking.order(self.rank) as f64 / 8.0;
         let kicker_bonus = if !self.kickers.is_empty() {
             (self.kickers[0] as u32 as f64 - 2.0) / 12.0 * 0.1
         } else {
@@ -32,27 +59,52 @@ impl HandEvaluation {
         };
         (base + kicker_bonus).min(1.0)
     }
+
+    /// Showdown ordering: category first, then kickers.
+    pub fn compare(&self, other: &HandEvaluation) -> std::cmp::Ordering {
+        self.compare_with(other, Ranking::Standard)
+    }
+
+    pub fn compare_with(&self, other: &HandEvaluation, ranking: Ranking) -> std::cmp::Ordering {
+        ranking
+            .order(self.rank)
+            .cmp(&ranking.order(other.rank))
+            .then_with(|| self.kickers.cmp(&other.kickers))
+    }
 }
 
+/// Best hand from the hole cards and board. With four hole cards (Omaha)
+/// the hand must use exactly two of them and three from the board.
 pub fn evaluate_hand(hole_cards: &[Card], board: &[Card]) -> HandEvaluation {
-    let mut all_cards: Vec<Card> = hole_cards.to_vec();
-    all_cards.extend(board);
+    evaluate_hand_with(hole_cards, board, Ranking::Standard)
+}
 
-    if all_cards.len() < 5 {
-        return evaluate_partial(&all_cards);
+pub fn evaluate_hand_with(hole_cards: &[Card], board: &[Card], ranking: Ranking) -> HandEvaluation {
+    if hole_cards.len() == 4 && board.len() < 3 {
+        return evaluate_omaha_start(hole_cards, board);
     }
+    if hole_cards.len() + board.len() < 5 {
+        let mut cards = hole_cards.to_vec();
+        cards.extend(board);
+        return evaluate_partial(&cards);
+    }
+    evaluator::evaluate(hole_cards, board, ranking).evaluation()
+}
 
-    // Generate all 5-card combinations and find the best
-    let combos = combinations(&all_cards, 5);
-    combos
-        .into_iter()
-        .map(|combo| evaluate_five(&combo))
-        .max_by(|a, b| a.rank.cmp(&b.rank).then_with(|| a.kickers.cmp(&b.kickers)))
-        .unwrap_or_else(|| HandEvaluation {
-            rank: HandRank::HighCard,
-            kickers: vec![],
-            description: "Unknown".to_string(),
-        })
+/// No five-card Omaha hand yet: rate the best two-card start.
+fn evaluate_omaha_start(hole_cards: &[Card], board: &[Card]) -> HandEvaluation {
+    let mut best: Option<HandEvaluation> = None;
+    for i in 0..hole_cards.len() {
+        for j in i + 1..hole_cards.len() {
+            let mut cards = vec![hole_cards[i], hole_cards[j]];
+            cards.extend(board);
+            let eval = evaluate_partial(&cards);
+            if best.as_ref().is_none_or(|b| eval.compare(b).is_gt()) {
+                best = Some(eval);
+            }
+        }
+    }
+    best.unwrap_or_else(|| evaluate_partial(&[]))
 }
 
 fn evaluate_partial(cards: &[Card]) -> HandEvaluation {
@@ -136,175 +188,7 @@ fn evaluate_partial(cards: &[Card]) -> HandEvaluation {
     }
 }
 
-fn evaluate_five(cards: &[Card]) -> HandEvaluation {
-    let mut rank_counts: HashMap<Rank, u8> = HashMap::new();
-    let mut suit_counts: HashMap<super::deck::Suit, u8> = HashMap::new();
-
-    for card in cards {
-        *rank_counts.entry(card.rank).or_insert(0) += 1;
-        *suit_counts.entry(card.suit).or_insert(0) += 1;
-    }
-
-    let is_flush = suit_counts.values().any(|&c| c >= 5);
-
-    let mut ranks: Vec<Rank> = cards.iter().map(|c| c.rank).collect();
-    ranks.sort_by(|a, b| b.cmp(a));
-    ranks.dedup();
-
-    let straight_high = check_straight(&ranks);
-
-    // Check for straight flush
-    if is_flush {
-        if let Some(high) = straight_high {
-            return HandEvaluation {
-                rank: HandRank::StraightFlush,
-                kickers: vec![high],
-                description: format!("{} high straight flush", rank_name(high)),
-            };
-        }
-    }
-
-    // Four of a kind
-    if let Some((&rank, _)) = rank_counts.iter().find(|(_, &c)| c == 4) {
-        return HandEvaluation {
-            rank: HandRank::FourOfAKind,
-            kickers: vec![rank],
-            description: format!("Four of a kind, {}", rank_name(rank)),
-        };
-    }
-
-    // Full house
-    let trips = rank_counts.iter().find(|(_, &c)| c == 3).map(|(&r, _)| r);
-    let pair = rank_counts.iter().find(|(_, &c)| c == 2).map(|(&r, _)| r);
-
-    if trips.is_some() && pair.is_some() {
-        return HandEvaluation {
-            rank: HandRank::FullHouse,
-            kickers: vec![trips.unwrap(), pair.unwrap()],
-            description: format!(
-                "Full house, {} full of {}",
-                rank_name(trips.unwrap()),
-                rank_name(pair.unwrap())
-            ),
-        };
-    }
-
-    if is_flush {
-        return HandEvaluation {
-            rank: HandRank::Flush,
-            kickers: ranks.clone(),
-            description: format!("{} high flush", rank_name(ranks[0])),
-        };
-    }
-
-    if let Some(high) = straight_high {
-        return HandEvaluation {
-            rank: HandRank::Straight,
-            kickers: vec![high],
-            description: format!("{} high straight", rank_name(high)),
-        };
-    }
-
-    if let Some(trip_rank) = trips {
-        return HandEvaluation {
-            rank: HandRank::ThreeOfAKind,
-            kickers: vec![trip_rank],
-            description: format!("Three of a kind, {}", rank_name(trip_rank)),
-        };
-    }
-
-    // Two pair
-    let pairs: Vec<Rank> = rank_counts
-        .iter()
-        .filter(|(_, &c)| c == 2)
-        .map(|(&r, _)| r)
-        .collect();
-
-    if pairs.len() >= 2 {
-        let mut sorted_pairs = pairs.clone();
-        sorted_pairs.sort_by(|a, b| b.cmp(a));
-        let high_pair = sorted_pairs[0];
-        let low_pair = sorted_pairs[1];
-        return HandEvaluation {
-            rank: HandRank::TwoPair,
-            kickers: sorted_pairs,
-            description: format!(
-                "Two pair, {} and {}",
-                rank_name(high_pair),
-                rank_name(low_pair)
-            ),
-        };
-    }
-
-    // One pair
-    if pairs.len() == 1 {
-        return HandEvaluation {
-            rank: HandRank::Pair,
-            kickers: vec![pairs[0]],
-            description: format!("Pair of {}", rank_name(pairs[0])),
-        };
-    }
-
-    // High card
-    HandEvaluation {
-        rank: HandRank::HighCard,
-        kickers: ranks,
-        description: format!(
-            "{} high",
-            rank_name(cards.iter().map(|c| c.rank).max().unwrap())
-        ),
-    }
-}
-
-/// Returns the high card of the straight if one exists, None otherwise.
-/// For a wheel (A-2-3-4-5), returns Five (not Ace) since it's the lowest straight.
-fn check_straight(sorted_ranks: &[Rank]) -> Option<Rank> {
-    if sorted_ranks.len() < 5 {
-        return None;
-    }
-
-    // Check for wheel (A-2-3-4-5) - returns Five as high card
-    let values: Vec<u8> = sorted_ranks.iter().map(|r| *r as u8).collect();
-    if values.contains(&14)
-        && values.contains(&2)
-        && values.contains(&3)
-        && values.contains(&4)
-        && values.contains(&5)
-    {
-        return Some(Rank::Five);
-    }
-
-    // Check for regular straight
-    for window in sorted_ranks.windows(5) {
-        let vals: Vec<u8> = window.iter().map(|r| *r as u8).collect();
-        if vals[0] as i8 - vals[4] as i8 == 4 {
-            return Some(window[0]); // Highest card in the window
-        }
-    }
-
-    None
-}
-
-fn combinations(cards: &[Card], k: usize) -> Vec<Vec<Card>> {
-    if k == 0 {
-        return vec![vec![]];
-    }
-    if cards.len() < k {
-        return vec![];
-    }
-
-    let mut result = Vec::new();
-    for (i, &card) in cards.iter().enumerate() {
-        let rest = &cards[i + 1..];
-        for mut combo in combinations(rest, k - 1) {
-            combo.insert(0, card);
-            result.push(combo);
-        }
-    }
-    result
-}
-
-fn rank_name(rank: Rank) -> &'static str {
+pub(super) fn rank_name(rank: Rank) -> &'static str {
     match rank {
         Rank::Two => "twos",
         Rank::Three => "threes",
@@ -326,6 +210,11 @@ fn rank_name(rank: Rank) -> &'static str {
 mod tests {
     use super::*;
     use crate::game::deck::Suit;
+    use std::cmp::Ordering;
+
+    fn evaluate_five(cards: &[Card], ranking: Ranking) -> HandEvaluation {
+        evaluate_hand_with(cards, &[], ranking)
+    }
 
     #[test]
     fn test_pair() {
@@ -336,7 +225,7 @@ mod tests {
             Card::new(Rank::Queen, Suit::Clubs),
             Card::new(Rank::Jack, Suit::Spades),
         ];
-        let eval = evaluate_five(&cards);
+        let eval = evaluate_five(&cards, Ranking::Standard);
         assert_eq!(eval.rank, HandRank::Pair);
     }
 
@@ -349,7 +238,7 @@ mod tests {
             Card::new(Rank::Jack, Suit::Spades),
             Card::new(Rank::Nine, Suit::Spades),
         ];
-        let eval = evaluate_five(&cards);
+        let eval = evaluate_five(&cards, Ranking::Standard);
         assert_eq!(eval.rank, HandRank::Flush);
     }
 
@@ -362,7 +251,7 @@ mod tests {
             Card::new(Rank::Jack, Suit::Clubs),
             Card::new(Rank::Ten, Suit::Spades),
         ];
-        let eval = evaluate_five(&cards);
+        let eval = evaluate_five(&cards, Ranking::Standard);
         assert_eq!(eval.rank, HandRank::Straight);
     }
 
@@ -376,7 +265,7 @@ mod tests {
             Card::new(Rank::Four, Suit::Clubs),
             Card::new(Rank::Five, Suit::Spades),
         ];
-        let wheel_eval = evaluate_five(&wheel);
+        let wheel_eval = evaluate_five(&wheel, Ranking::Standard);
         assert_eq!(wheel_eval.rank, HandRank::Straight);
         assert_eq!(wheel_eval.kickers[0], Rank::Five);
 
@@ -388,7 +277,7 @@ mod tests {
             Card::new(Rank::Five, Suit::Clubs),
             Card::new(Rank::Six, Suit::Spades),
         ];
-        let six_eval = evaluate_five(&six_high);
+        let six_eval = evaluate_five(&six_high, Ranking::Standard);
         assert_eq!(six_eval.rank, HandRank::Straight);
         assert_eq!(six_eval.kickers[0], Rank::Six);
 
@@ -396,6 +285,61 @@ mod tests {
         assert!(six_eval.kickers > wheel_eval.kickers);
     }
 
+    #[test]
+    fn test_short_deck_wheel_is_nine_high() {
+        let wheel = vec![
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Spades),
+        ];
+        let eval = evaluate_five(&wheel, Ranking::ShortDeck);
+        assert_eq!(eval.rank, HandRank::Straight);
+        assert_eq!(eval.kickers[0], Rank::Nine);
+        // Not a straight with a full deck
+        assert_eq!(evaluate_five(&wheel, Ranking::Standard).rank, HandRank::HighCard);
+    }
+
+    #[test]
+    fn test_short_deck_flush_beats_full_house() {
+        let flush = evaluate_hand_with(
+            &[
+                Card::new(Rank::Ace, Suit::Hearts),
+                Card::new(Rank::Seven, Suit::Hearts),
+            ],
+            &[
+                Card::new(Rank::King, Suit::Hearts),
+                Card::new(Rank::King, Suit::Spades),
+                Card::new(Rank::Nine, Suit::Hearts),
+                Card::new(Rank::Eight, Suit::Hearts),
+                Card::new(Rank::Six, Suit::Clubs),
+            ],
+            Ranking::ShortDeck,
+        );
+        let full_house = evaluate_hand_with(
+            &[
+                Card::new(Rank::King, Suit::Diamonds),
+                Card::new(Rank::Nine, Suit::Clubs),
+            ],
+            &[
+                Card::new(Rank::King, Suit::Hearts),
+                Card::new(Rank::King, Suit::Spades),
+                Card::new(Rank::Nine, Suit::Hearts),
+                Card::new(Rank::Eight, Suit::Hearts),
+                Card::new(Rank::Six, Suit::Clubs),
+            ],
+            Ranking::ShortDeck,
+        );
+        assert_eq!(flush.rank, HandRank::Flush);
+        assert_eq!(full_house.rank, HandRank::FullHouse);
+        assert_eq!(flush.compare_with(&full_house, Ranking::ShortDeck), Ordering::Greater);
+        assert_eq!(flush.compare(&full_house), Ordering::Less);
+        assert!(
+            flush.strength_with(Ranking::ShortDeck) > full_house.strength_with(Ranking::ShortDeck)
+        );
+    }
+
     #[test]
     fn test_straight_flush() {
         // Royal flush: A♠ K♠ Q♠ J♠ T♠
@@ -406,7 +350,7 @@ mod tests {
             Card::new(Rank::Jack, Suit::Spades),
             Card::new(Rank::Ten, Suit::Spades),
         ];
-        let eval = evaluate_five(&royal);
+        let eval = evaluate_five(&royal, Ranking::Standard);
         assert_eq!(eval.rank, HandRank::StraightFlush);
         assert_eq!(eval.kickers[0], Rank::Ace);
     }
@@ -421,7 +365,7 @@ mod tests {
             Card::new(Rank::Jack, Suit::Spades),
             Card::new(Rank::Ten, Suit::Hearts), // Different suit
         ];
-        let eval = evaluate_five(&cards);
+        let eval = evaluate_five(&cards, Ranking::Standard);
         // This is just a straight, not a flush (only 4 spades)
         assert_eq!(eval.rank, HandRank::Straight);
     }
@@ -436,7 +380,65 @@ mod tests {
             Card::new(Rank::Jack, Suit::Spades),
             Card::new(Rank::Nine, Suit::Spades),
         ];
-        let eval = evaluate_five(&cards);
+        let eval = evaluate_five(&cards, Ranking::Standard);
+        assert_eq!(eval.rank, HandRank::Flush);
+    }
+
+    #[test]
+    fn test_omaha_needs_two_suited_hole_cards_for_a_flush() {
+        // Four hearts on board but only one heart in hand
+        let hole = vec![
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Diamonds),
+        ];
+        let board = vec![
+            Card::new(Rank::Queen, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Clubs),
+        ];
+        let eval = evaluate_hand(&hole, &board);
+        assert_eq!(eval.rank, HandRank::HighCard);
+
+        // Hold'em with the same ace plays the board flush
+        let eval = evaluate_hand(&hole[..2], &board);
         assert_eq!(eval.rank, HandRank::Flush);
     }
+
+    #[test]
+    fn test_omaha_uses_exactly_three_board_cards() {
+        // Quads on board, but only three of them can play
+        let hole = vec![
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Diamonds),
+        ];
+        let board = vec![
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Diamonds),
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+        let eval = evaluate_hand(&hole, &board);
+        assert_eq!(eval.rank, HandRank::FullHouse);
+        assert_eq!(eval.kickers, vec![Rank::Nine, Rank::King]);
+    }
+
+    #[test]
+    fn test_omaha_preflop_rates_best_two_cards() {
+        let hole = vec![
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        let eval = evaluate_hand(&hole, &[]);
+        assert_eq!(eval.rank, HandRank::Pair);
+        assert_eq!(eval.kickers, vec![Rank::Ace]);
+    }
 }
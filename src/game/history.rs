@@ -0,0 +1,287 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::actions::Action;
+use super::deck::Card;
+use super::state::{PotAward, Street};
+use super::variant::GameVariant;
+
+/// A single action as it happened, with the chip counts right after it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionRecord {
+    pub seat: usize,
+    pub action: Action,
+    /// Pot after the action was applied.
+    pub pot: u32,
+    /// Actor's remaining stack after the action was applied.
+    pub stack: u32,
+}
+
+/// Everything that happened on one betting street.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreetRecord {
+    pub street: Street,
+    /// Full board as of this street (empty preflop).
+    pub board: Vec<Card>,
+    /// Pot at the start of the street (includes the blinds preflop).
+    pub pot: u32,
+    pub actions: Vec<ActionRecord>,
+}
+
+/// A hand revealed at showdown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShownHand {
+    pub seat: usize,
+    /// Made-hand description, e.g. "Two pair, Kings and Sevens".
+    pub description: String,
+}
+
+/// How a hand ended.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandOutcome {
+    pub pot: u32,
+    /// True if the hand reached showdown, false if everyone else folded.
+    pub showdown: bool,
+    /// Main pot first, then any side pots, with who won each.
+    pub pots: Vec<PotAward>,
+    /// Hands shown down, empty if the hand ended with a fold.
+    pub shown: Vec<ShownHand>,
+    /// Stacks of every seat after the pot was awarded.
+    pub stacks: Vec<u32>,
+}
+
+impl HandOutcome {
+    /// Total chips awarded to `seat`.
+    pub fn winnings(&self, seat: usize) -> u32 {
+        self.pots
+            .iter()
+            .map(|pot| pot.share(seat))
+            .sum()
+    }
+
+    pub fn shown_hand(&self, seat: usize) -> Option<&str> {
+        self.shown
+            .iter()
+            .find(|shown| shown.seat == seat)
+            .map(|shown| shown.description.as_str())
+    }
+}
+
+/// A seat dealt into the hand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeatRecord {
+    pub seat: usize,
+    /// Stack before the blinds were posted.
+    pub stack: u32,
+    pub cards: Vec<Card>,
+}
+
+/// Structured record of one complete hand, suitable for review and replay.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandRecord {
+    pub hand_number: u32,
+    /// Unix timestamp (seconds) of when the hand was dealt.
+    pub started_at: u64,
+    #[serde(default)]
+    pub variant: GameVariant,
+    pub button: usize,
+    pub small_blind_seat: usize,
+    pub big_blind_seat: usize,
+    pub small_blind: u32,
+    pub big_blind: u32,
+    #[serde(default)]
+    pub ante: u32,
+    /// Only the big blind posted `ante`, for the whole table.
+    #[serde(default)]
+    pub big_blind_ante: bool,
+    /// Seats dealt in, in seat order. Seat 0 is the human.
+    pub seats: Vec<SeatRecord>,
+    pub streets: Vec<StreetRecord>,
+    /// `None` while the hand is still in progress.
+    pub result: Option<HandOutcome>,
+}
+
+impl HandRecord {
+    pub fn new(
+        hand_number: u32,
+        button: usize,
+        small_blind_seat: usize,
+        big_blind_seat: usize,
+        small_blind: u32,
+        big_blind: u32,
+        ante: u32,
+    ) -> Self {
+        let started_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self {
+            hand_number,
+            started_at,
+            variant: GameVariant::default(),
+            button,
+            small_blind_seat,
+            big_blind_seat,
+            small_blind,
+            big_blind,
+            ante,
+            big_blind_ante: false,
+            seats: Vec::new(),
+            streets: Vec::new(),
+            result: None,
+        }
+    }
+
+    pub fn add_seat(&mut self, seat: usize, stack: u32, cards: &[Card]) {
+        self.seats.push(SeatRecord {
+            seat,
+            stack,
+            cards: cards.to_vec(),
+        });
+    }
+
+    pub fn seat(&self, seat: usize) -> Option<&SeatRecord> {
+        self.seats.iter().find(|s| s.seat == seat)
+    }
+
+    pub fn start_street(&mut self, street: Street, board: &[Card], pot: u32) {
+        self.streets.push(StreetRecord {
+            street,
+            board: board.to_vec(),
+            pot,
+            actions: Vec::new(),
+        });
+    }
+
+    pub fn record_action(&mut self, seat: usize, action: Action, pot: u32, stack: u32) {
+        if let Some(street) = self.streets.last_mut() {
+            street.actions.push(ActionRecord {
+                seat,
+                action,
+                pot,
+                stack,
+            });
+        }
+    }
+
+    /// The board as it stood when the hand ended.
+    pub fn final_board(&self) -> &[Card] {
+        self.streets
+            .last()
+            .map(|s| s.board.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::{GameConfig, GamePhase, GameState, Stakes, HUMAN};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn play_passively(state: &mut GameState) {
+        while !matches!(state.phase, GamePhase::Showdown | GamePhase::HandComplete) {
+            let to_call = state.amount_to_call(state.to_act);
+            let action = if to_call > 0 {
+                Action::Call(to_call)
+            } else {
+                Action::Check
+            };
+            state.apply_action(state.to_act, action);
+        }
+    }
+
+    #[test]
+    fn test_showdown_hand_is_recorded() {
+        let mut state = GameState::with_seed(100, 11);
+        play_passively(&mut state);
+
+        let hands = state.take_finished_hands();
+        assert_eq!(hands.len(), 1);
+        let record = &hands[0];
+        assert_eq!(record.hand_number, 1);
+        assert_eq!(record.seats.len(), 2);
+        assert_eq!(record.seats[0].cards, state.seats[0].cards);
+        assert_eq!(record.seats[1].cards, state.seats[1].cards);
+
+        let streets: Vec<Street> = record.streets.iter().map(|s| s.street).collect();
+        assert_eq!(
+            streets,
+            vec![Street::Preflop, Street::Flop, Street::Turn, Street::River]
+        );
+        assert_eq!(record.streets[1].board.len(), 3);
+        assert_eq!(record.final_board(), state.board.as_slice());
+
+        let result = record.result.as_ref().unwrap();
+        assert!(result.showdown);
+        assert!(result.shown_hand(HUMAN).is_some());
+        assert_eq!(result.stacks.iter().sum::<u32>(), 400);
+
+        // Drained: nothing left to take
+        assert!(state.take_finished_hands().is_empty());
+    }
+
+    #[test]
+    fn test_fold_hand_is_recorded() {
+        let mut state = GameState::with_seed(100, 5);
+        let folder = state.to_act;
+        state.apply_action(folder, Action::Fold);
+
+        let hands = state.take_finished_hands();
+        assert_eq!(hands.len(), 1);
+        let record = &hands[0];
+        assert_eq!(record.streets.len(), 1);
+        assert_eq!(
+            record.streets[0].actions,
+            vec![ActionRecord {
+                seat: folder,
+                action: Action::Fold,
+                pot: 3,
+                stack: 199,
+            }]
+        );
+
+        let result = record.result.as_ref().unwrap();
+        assert!(!result.showdown);
+        assert_eq!(result.pots.len(), 1);
+        assert_eq!(result.pots[0].winners, vec![1 - folder]);
+        assert_eq!(result.pot, 3);
+    }
+
+    #[test]
+    fn test_record_round_trips_through_json() {
+        let mut state = GameState::with_seed(100, 9);
+        play_passively(&mut state);
+        let record = state.take_finished_hands().remove(0);
+
+        let json = serde_json::to_string(&record).unwrap();
+        let parsed: HandRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn test_multiway_record_lists_every_seat() {
+        let config = GameConfig {
+            seats: 4,
+            starting_stack_bb: 100,
+            variant: GameVariant::default(),
+            stakes: Stakes::default(),
+            tournament: None,
+        };
+        let mut state = GameState::with_config(config, StdRng::seed_from_u64(3));
+        play_passively(&mut state);
+
+        let record = state.take_finished_hands().remove(0);
+        assert_eq!(record.seats.len(), 4);
+        assert_eq!(record.button, 0);
+        assert_eq!(record.small_blind_seat, 1);
+        assert_eq!(record.big_blind_seat, 2);
+        // Under the gun opens preflop
+        assert_eq!(record.streets[0].actions[0].seat, 3);
+        let result = record.result.as_ref().unwrap();
+        assert_eq!(result.shown.len(), 4);
+        assert_eq!(result.stacks.iter().sum::<u32>(), 800);
+    }
+}
@@ -1,4 +1,13 @@
 pub mod actions;
 pub mod deck;
+pub mod equity;
+pub mod evaluator;
+pub mod export;
 pub mod hand;
+pub mod history;
+pub mod pushfold;
+pub mod range;
 pub mod state;
+pub mod tournament;
+pub mod variant;
+pub mod view;
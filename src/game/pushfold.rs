@@ -0,0 +1,616 @@
+//! Heads-up push/fold play for short stacks. With 20 big blinds or less
+//! the small blind either moves all in or folds, and the big blind calls
+//! or folds. `solve` finds the equilibrium of that game, where neither
+//! player can gain by changing their range, and the charts below hold its
+//! result for every effective stack from 1 to 20 big blinds.
+//!
+//! The charts give, for each of the 169 starting hands, the largest stack
+//! at which the hand is in the range: it is played at that stack and every
+//! stack below it.
+
+use std::cmp::Ordering;
+use std::io::{self, BufRead, Write};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use super::deck::{Card, Deck, Rank, Suit};
+use super::evaluator::evaluate;
+use super::hand::Ranking;
+
+/// Deepest effective stack, in big blinds, the charts cover.
+pub const MAX_STACK_BB: u32 = 20;
+
+/// Number of starting hand classes: 13 pairs, 78 suited and 78 offsuit.
+pub const HANDS: usize = 169;
+
+/// Largest stack at which the small blind moves all in with each hand,
+/// laid out as `hand_index` (suited hands above the diagonal); 0 if never.
+#[rustfmt::skip]
+const JAM_CHART: [u8; HANDS] = [
+    20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+    20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 19, 19,
+    20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 16, 13, 12,
+    20, 20, 20, 20, 20, 20, 20, 20, 18, 17, 13, 10,  8,
+    20, 20, 20, 20, 20, 20, 20, 20, 20, 11, 10,  7,  6,
+    20, 20, 20, 20, 20, 20, 20, 20, 20, 13,  5,  4,  3,
+    20, 18, 13, 13, 16, 20, 20, 20, 20, 18,  7,  2,  2,
+    20, 16, 10,  8, 10, 10, 14, 20, 20, 20, 11,  2,  2,
+    20, 15,  9,  6,  5,  5,  7, 10, 20, 20, 16,  7,  2,
+    20, 14,  9,  6,  4,  3,  2,  2,  2, 20, 20, 12,  2,
+    20, 13,  7,  5,  3,  2,  2,  2,  2,  2, 20, 10,  1,
+    20, 12,  7,  5,  3,  2,  1,  1,  1,  1,  1, 20,  1,
+    20, 11,  6,  4,  3,  2,  1,  1,  1,  1,  1,  1, 20,
+];
+
+/// Largest stack at which the big blind calls an all-in with each hand.
+#[rustfmt::skip]
+const CALL_CHART: [u8; HANDS] = [
+    20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20,
+    20, 20, 20, 20, 20, 20, 17, 15, 14, 13, 12, 11, 10,
+    20, 20, 20, 20, 20, 15, 13, 10,  9,  9,  8,  7,  7,
+    20, 20, 19, 20, 17, 13, 10,  8,  7,  6,  6,  5,  5,
+    20, 20, 15, 12, 20, 11,  9,  7,  6,  5,  5,  4,  4,
+    20, 16, 11,  9,  8, 20,  8,  6,  5,  5,  4,  4,  3,
+    20, 13,  9,  7,  6,  6, 20,  6,  5,  4,  4,  3,  3,
+    20, 12,  8,  6,  5,  5,  4, 20,  5,  4,  4,  3,  3,
+    20, 11,  7,  5,  4,  4,  4,  4, 20,  4,  4,  3,  3,
+    20, 10,  6,  5,  4,  3,  3,  3,  3, 20,  4,  4,  3,
+    18,  9,  6,  4,  3,  3,  3,  3,  3,  3, 20,  3,  3,
+    16,  8,  5,  4,  3,  3,  2,  2,  3,  3,  3, 20,  3,
+    15,  8,  5,  4,  3,  3,  2,  2,  2,  2,  2,  2, 14,
+];
+
+/// Position in a heads-up hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    /// On the button, first to act preflop: jam or fold.
+    SmallBlind,
+    /// Facing the small blind's all-in: call or fold.
+    BigBlind,
+}
+
+impl Position {
+    pub fn name(self) -> &'static str {
+        match self {
+            Position::SmallBlind => "small blind",
+            Position::BigBlind => "big blind",
+        }
+    }
+}
+
+/// Whether the chart plays `hand` (all in as the small blind, calling as
+/// the big blind) at an effective stack of `stack_bb` big blinds.
+pub fn plays(position: Position, hand: &[Card], stack_bb: f64) -> bool {
+    chart_stack(position, hand_index(hand[0], hand[1])) as f64 >= stack_bb.round().max(1.0)
+}
+
+/// Largest stack at which the chart plays the hand with this index.
+pub fn chart_stack(position: Position, index: usize) -> u32 {
+    let chart = match position {
+        Position::SmallBlind => &JAM_CHART,
+        Position::BigBlind => &CALL_CHART,
+    };
+    chart[index] as u32
+}
+
+/// Share of all starting hands the chart plays at a stack of `stack_bb`.
+pub fn range_share(position: Position, stack_bb: u32) -> f64 {
+    let combos: usize = (0..HANDS)
+        .filter(|&index| chart_stack(position, index) >= stack_bb)
+        .map(|index| hand_combos(index).len())
+        .sum();
+    combos as f64 / 1326.0
+}
+
+/// Index of a starting hand in a 13 by 13 grid, aces first: pairs on the
+/// diagonal, suited hands above it and offsuit hands below.
+pub fn hand_index(a: Card, b: Card) -> usize {
+    let (high, low) = if a.rank >= b.rank { (a, b) } else { (b, a) };
+    let row = 14 - high.rank as usize;
+    let col = 14 - low.rank as usize;
+    if high.suit == low.suit {
+        row * 13 + col
+    } else {
+        col * 13 + row
+    }
+}
+
+/// Name of the starting hand with this index, e.g. `AKs`.
+pub fn hand_name(index: usize) -> String {
+    let (row, col) = (index / 13, index % 13);
+    let rank = |i: usize| Rank::ALL[12 - i].to_char();
+    match row.cmp(&col) {
+        Ordering::Equal => format!("{}{}", rank(row), rank(col)),
+        Ordering::Less => format!("{}{}s", rank(row), rank(col)),
+        Ordering::Greater => format!("{}{}o", rank(col), rank(row)),
+    }
+}
+
+/// Every two-card combo of the starting hand with this index.
+fn hand_combos(index: usize) -> Vec<[Card; 2]> {
+    let (row, col) = (index / 13, index % 13);
+    let high = Rank::ALL[12 - row.min(col)];
+    let low = Rank::ALL[12 - row.max(col)];
+    let mut combos = Vec::new();
+    for (i, &s1) in Suit::ALL.iter().enumerate() {
+        for (j, &s2) in Suit::ALL.iter().enumerate() {
+            let keep = match row.cmp(&col) {
+                Ordering::Equal => i < j,
+                Ordering::Less => i == j,
+                Ordering::Greater => i != j,
+            };
+            if keep {
+                combos.push([Card::new(high, s1), Card::new(low, s2)]);
+            }
+        }
+    }
+    combos
+}
+
+/// Every way to deal one combo from each list without sharing a card.
+fn matchups(first: &[[Card; 2]], second: &[[Card; 2]]) -> Vec<([Card; 2], [Card; 2])> {
+    first
+        .iter()
+        .flat_map(|&x| second.iter().map(move |&y| (x, y)))
+        .filter(|(x, y)| !x.iter().any(|card| y.contains(card)))
+        .collect()
+}
+
+/// All-in equity of every starting hand against every other, with how
+/// many combo pairs each matchup has once shared cards are ruled out.
+pub struct Equities {
+    /// `equity[a * HANDS + b]`: hand `a`'s share of the pot against `b`.
+    equity: Vec<f64>,
+    /// `combos[a * HANDS + b]`: ways `a` and `b` can be dealt together.
+    combos: Vec<f64>,
+}
+
+impl Equities {
+    /// Estimate every matchup from `samples` random deals.
+    pub fn compute(samples: u32, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut equity = vec![0.0; HANDS * HANDS];
+        let mut combos = vec![0.0; HANDS * HANDS];
+        let deck = Deck::new().deal_n(52);
+        let all_combos: Vec<Vec<[Card; 2]>> = (0..HANDS).map(hand_combos).collect();
+
+        for a in 0..HANDS {
+            for b in a..HANDS {
+                let pairs = matchups(&all_combos[a], &all_combos[b]);
+                let mut share = 0.0;
+                for _ in 0..samples {
+                    let (x, y) = pairs[rng.gen_range(0..pairs.len())];
+                    let mut stub: Vec<Card> = deck
+                        .iter()
+                        .copied()
+                        .filter(|card| !x.contains(card) && !y.contains(card))
+                        .collect();
+                    let (board, _) = stub.partial_shuffle(&mut rng, 5);
+                    let mine = evaluate(&x, board, Ranking::Standard);
+                    let theirs = evaluate(&y, board, Ranking::Standard);
+                    share += match mine.cmp(&theirs) {
+                        Ordering::Greater => 1.0,
+                        Ordering::Equal => 0.5,
+                        Ordering::Less => 0.0,
+                    };
+                }
+                // A hand against itself splits evenly
+                let share = if a == b { 0.5 } else { share / samples as f64 };
+                equity[a * HANDS + b] = share;
+                equity[b * HANDS + a] = 1.0 - share;
+                combos[a * HANDS + b] = pairs.len() as f64;
+                combos[b * HANDS + a] = pairs.len() as f64;
+            }
+        }
+        Self { equity, combos }
+    }
+
+    /// Build from a function giving hand `a`'s equity against hand `b`.
+    pub fn from_fn(equity: impl Fn(usize, usize) -> f64) -> Self {
+        let mut table = Self {
+            equity: vec![0.0; HANDS * HANDS],
+            combos: vec![0.0; HANDS * HANDS],
+        };
+        let all_combos: Vec<Vec<[Card; 2]>> = (0..HANDS).map(hand_combos).collect();
+        for a in 0..HANDS {
+            for b in 0..HANDS {
+                table.equity[a * HANDS + b] = equity(a, b);
+                table.combos[a * HANDS + b] = matchups(&all_combos[a], &all_combos[b]).len() as f64;
+            }
+        }
+        table
+    }
+
+    pub fn equity(&self, a: usize, b: usize) -> f64 {
+        self.equity[a * HANDS + b]
+    }
+
+    /// Ways `a` and `b` can be dealt together.
+    pub fn combos(&self, a: usize, b: usize) -> f64 {
+        self.combos[a * HANDS + b]
+    }
+}
+
+/// How often each starting hand moves all in as the small blind and calls
+/// as the big blind, indexed by `hand_index`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub jam: Vec<f64>,
+    pub call: Vec<f64>,
+}
+
+/// Solve the push/fold game at an effective stack of `stack_bb` big
+/// blinds by fictitious play: each player repeatedly best-responds to the
+/// other's average strategy, and the averages converge to the equilibrium.
+pub fn solve(stack_bb: f64, equities: &Equities, iterations: u32) -> Solution {
+    let mut jam = vec![1.0; HANDS];
+    let mut call = vec![1.0; HANDS];
+    for t in 1..=iterations {
+        let step = 1.0 / (t + 1) as f64;
+        let jams = best_jams(stack_bb, equities, &call);
+        let calls = best_calls(stack_bb, equities, &jam);
+        for hand in 0..HANDS {
+            jam[hand] += (jams[hand] - jam[hand]) * step;
+            call[hand] += (calls[hand] - call[hand]) * step;
+        }
+    }
+    Solution { jam, call }
+}
+
+/// The small blind's best response: jam each hand that does better than
+/// the half blind it gives up by folding.
+fn best_jams(stack_bb: f64, equities: &Equities, call: &[f64]) -> Vec<f64> {
+    (0..HANDS)
+        .map(|a| {
+            let (mut total, mut weight) = (0.0, 0.0);
+            for (b, &calls) in call.iter().enumerate() {
+                let combos = equities.combos(a, b);
+                // The big blind folds and the small blind wins it, or
+                // calls and the stacks go in
+                let called = stack_bb * (2.0 * equities.equity(a, b) - 1.0);
+                total += combos * ((1.0 - calls) + calls * called);
+                weight += combos;
+            }
+            if total / weight > -0.5 {
+                1.0
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// The big blind's best response: call with each hand that loses less than
+/// the big blind it gives up by folding.
+fn best_calls(stack_bb: f64, equities: &Equities, jam: &[f64]) -> Vec<f64> {
+    (0..HANDS)
+        .map(|b| {
+            let gain: f64 = jam
+                .iter()
+                .enumerate()
+                .map(|(a, &jams)| {
+                    let called = stack_bb * (2.0 * equities.equity(b, a) - 1.0);
+                    equities.combos(a, b) * jams * (called + 1.0)
+                })
+                .sum();
+            if gain > 0.0 {
+                1.0
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Charts of the largest stack each hand is jammed and called at, solving
+/// every stack from 1 to `MAX_STACK_BB`.
+pub fn compute_charts(equities: &Equities, iterations: u32) -> ([u8; HANDS], [u8; HANDS]) {
+    let mut jam = [0u8; HANDS];
+    let mut call = [0u8; HANDS];
+    for stack in 1..=MAX_STACK_BB {
+        let solution = solve(stack as f64, equities, iterations);
+        for hand in 0..HANDS {
+            if solution.jam[hand] >= 0.5 {
+                jam[hand] = stack as u8;
+            }
+            if solution.call[hand] >= 0.5 {
+                call[hand] = stack as u8;
+            }
+        }
+    }
+    (jam, call)
+}
+
+/// A push/fold question for the trainer: what does the chart do with this
+/// hand, in this seat, at this stack?
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Question {
+    pub position: Position,
+    pub stack_bb: u32,
+    pub hand: [Card; 2],
+}
+
+impl Question {
+    /// A random hand and seat, at `stack_bb` or a random stack up to
+    /// `MAX_STACK_BB`.
+    pub fn random<R: Rng>(rng: &mut R, stack_bb: Option<u32>) -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle(rng);
+        let cards = deck.deal_n(2);
+        Self {
+            position: if rng.gen_bool(0.5) {
+                Position::SmallBlind
+            } else {
+                Position::BigBlind
+            },
+            stack_bb: stack_bb.unwrap_or_else(|| rng.gen_range(1..=MAX_STACK_BB)),
+            hand: [cards[0], cards[1]],
+        }
+    }
+
+    /// True if the chart plays the hand: jams or calls rather than folds.
+    pub fn answer(&self) -> bool {
+        plays(self.position, &self.hand, self.stack_bb as f64)
+    }
+
+    /// Why the answer is what it is, e.g. "the chart jams K9o up to 14BB".
+    pub fn explanation(&self) -> String {
+        let index = hand_index(self.hand[0], self.hand[1]);
+        let verb = match self.position {
+            Position::SmallBlind => "jams",
+            Position::BigBlind => "calls with",
+        };
+        match chart_stack(self.position, index) {
+            0 => format!("the chart never {} {}", verb, hand_name(index)),
+            MAX_STACK_BB => format!(
+                "the chart {} {} at every stack up to {}BB",
+                verb,
+                hand_name(index),
+                MAX_STACK_BB
+            ),
+            stack => format!("the chart {} {} up to {}BB", verb, hand_name(index), stack),
+        }
+    }
+}
+
+/// Both charts as 13 by 13 grids, aces first, suited hands above the
+/// diagonal. Each cell is the largest stack the hand is played at, or with
+/// a `stack` the hand if it is played there.
+pub fn write_charts(out: &mut impl Write, stack: Option<u32>) -> io::Result<()> {
+    let charts = [
+        (Position::SmallBlind, "Small blind: jam"),
+        (Position::BigBlind, "Big blind: call a jam"),
+    ];
+    for (i, (position, title)) in charts.into_iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        match stack {
+            Some(stack) => writeln!(
+                out,
+                "{} at {}BB ({:.1}% of hands)",
+                title,
+                stack,
+                range_share(position, stack) * 100.0
+            )?,
+            None => writeln!(out, "{} (largest stack in BB)", title)?,
+        }
+        for row in 0..13 {
+            let cells: Vec<String> = (0..13)
+                .map(|col| {
+                    let index = row * 13 + col;
+                    let played = chart_stack(position, index);
+                    match stack {
+                        Some(stack) if played >= stack => format!("{:<4}", hand_name(index)),
+                        Some(_) => format!("{:<4}", "."),
+                        None if played == MAX_STACK_BB => format!("{:>3}+", played),
+                        None if played == 0 => format!("{:>3} ", "-"),
+                        None => format!("{:>3} ", played),
+                    }
+                })
+                .collect();
+            writeln!(out, "{}", cells.join(" ").trim_end())?;
+        }
+    }
+    Ok(())
+}
+
+/// Ask for the chart play with random hands, reading answers from `input`,
+/// and keep score. Stops early on `q` or at the end of input.
+pub fn run_quiz<R: Rng>(
+    input: impl BufRead,
+    out: &mut impl Write,
+    rng: &mut R,
+    stack: Option<u32>,
+    questions: u32,
+) -> io::Result<()> {
+    let mut lines = input.lines();
+    let (mut asked, mut correct) = (0, 0);
+
+    'quiz: for number in 1..=questions {
+        let question = Question::random(rng, stack);
+        let play = match question.position {
+            Position::SmallBlind => "jam",
+            Position::BigBlind => "call",
+        };
+        let answer = loop {
+            write!(
+                out,
+                "{}/{}  {}BB in the {}, holding {} {}: ({}){} or (f)old? ",
+                number,
+                questions,
+                question.stack_bb,
+                question.position.name(),
+                question.hand[0],
+                question.hand[1],
+                &play[..1],
+                &play[1..]
+            )?;
+            out.flush()?;
+            let Some(line) = lines.next().transpose()? else {
+                writeln!(out)?;
+                break 'quiz;
+            };
+            match line.trim().to_lowercase().chars().next() {
+                Some('f') => break false,
+                Some('q') => break 'quiz,
+                Some(c) if c == play.chars().next().unwrap_or('j') => break true,
+                _ => writeln!(out, "Answer {} or f (q to stop)", &play[..1])?,
+            }
+        };
+        asked += 1;
+        if answer == question.answer() {
+            correct += 1;
+            writeln!(out, "Correct: {}", question.explanation())?;
+        } else {
+            writeln!(out, "Wrong: {}", question.explanation())?;
+        }
+    }
+
+    if asked > 0 {
+        writeln!(
+            out,
+            "Score: {}/{} ({:.0}%)",
+            correct,
+            asked,
+            correct as f64 / asked as f64 * 100.0
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::deck::parse_cards;
+
+    fn hand(cards: &str) -> Vec<Card> {
+        parse_cards(cards).unwrap()
+    }
+
+    #[test]
+    fn test_hand_index_and_name() {
+        let index = |cards: &str| {
+            let cards = hand(cards);
+            hand_index(cards[0], cards[1])
+        };
+        assert_eq!(index("AsAh"), 0);
+        assert_eq!(index("AsKs"), 1);
+        assert_eq!(index("KsAs"), 1);
+        assert_eq!(index("AsKh"), 13);
+        assert_eq!(index("2s2h"), HANDS - 1);
+        assert_eq!(hand_name(index("AsKs")), "AKs");
+        assert_eq!(hand_name(index("Kh9c")), "K9o");
+        assert_eq!(hand_name(index("7d7c")), "77");
+        let total: usize = (0..HANDS).map(|i| hand_combos(i).len()).sum();
+        assert_eq!(total, 1326);
+    }
+
+    #[test]
+    fn test_solver_on_ranked_hands() {
+        // Hands ranked by index, each a small favourite over worse ones
+        let equities = Equities::from_fn(|a, b| 0.5 + (b as f64 - a as f64) / 1000.0);
+        let deep = solve(20.0, &equities, 200);
+        let short = solve(2.0, &equities, 200);
+        let jams = |s: &Solution| s.jam.iter().filter(|&&f| f >= 0.5).count();
+        // Shorter stacks jam more hands, and the best hands always jam
+        assert!(jams(&short) > jams(&deep));
+        assert!(deep.jam[0] >= 0.5 && deep.call[0] >= 0.5);
+        // The best hands are jammed before worse ones
+        let first_fold = deep.jam.iter().position(|&f| f < 0.5).unwrap();
+        assert!(deep.jam[first_fold..].iter().all(|&f| f < 0.5));
+    }
+
+    #[test]
+    fn test_charts() {
+        // Aces always; seven-deuce offsuit only when very short
+        assert!(plays(Position::SmallBlind, &hand("AsAh"), 20.0));
+        assert!(plays(Position::BigBlind, &hand("AsAh"), 20.0));
+        assert!(!plays(Position::SmallBlind, &hand("7s2h"), 10.0));
+        assert!(!plays(Position::BigBlind, &hand("7s2h"), 10.0));
+        assert!(plays(Position::SmallBlind, &hand("7s2h"), 1.0));
+        // Stacks are rounded to the nearest chart
+        assert_eq!(
+            plays(Position::SmallBlind, &hand("Kh9c"), 9.6),
+            plays(Position::SmallBlind, &hand("Kh9c"), 10.0)
+        );
+        // The big blind calls tighter than the small blind jams, and
+        // everything is played at 1BB
+        assert!(range_share(Position::SmallBlind, 10) > range_share(Position::BigBlind, 10));
+        assert_eq!(range_share(Position::SmallBlind, 1), 1.0);
+        assert_eq!(range_share(Position::BigBlind, 1), 1.0);
+    }
+
+    #[test]
+    fn test_question() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let question = Question::random(&mut rng, Some(8));
+        assert_eq!(question.stack_bb, 8);
+        assert_ne!(question.hand[0], question.hand[1]);
+        let aces = Question {
+            position: Position::SmallBlind,
+            stack_bb: 15,
+            hand: [hand("As")[0], hand("Ah")[0]],
+        };
+        assert!(aces.answer());
+        assert_eq!(
+            aces.explanation(),
+            "the chart jams AA at every stack up to 20BB"
+        );
+    }
+
+    #[test]
+    fn test_write_charts() {
+        let text = |stack| {
+            let mut out = Vec::new();
+            write_charts(&mut out, stack).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        let largest = text(None);
+        let lines: Vec<&str> = largest.lines().collect();
+        assert_eq!(lines.len(), 2 * 14 + 1);
+        assert_eq!(lines[0], "Small blind: jam (largest stack in BB)");
+        assert!(lines[1].starts_with(" 20+"));
+        assert_eq!(lines[15], "Big blind: call a jam (largest stack in BB)");
+
+        let at_ten = text(Some(10));
+        assert!(at_ten.starts_with("Small blind: jam at 10BB ("));
+        let first_row = at_ten.lines().nth(1).unwrap();
+        assert!(first_row.starts_with("AA   AKs"));
+        // Seven-deuce offsuit, bottom row, isn't jammed at 10BB
+        let seventh_row = at_ten.lines().nth(13).unwrap();
+        assert_eq!(seventh_row.split_whitespace().nth(7), Some("."));
+    }
+
+    #[test]
+    fn test_quiz_keeps_score() {
+        // Answer the first two questions right and the third wrong
+        let mut rng = StdRng::seed_from_u64(4);
+        let answers: Vec<String> = (0..3)
+            .map(|i| {
+                let question = Question::random(&mut rng, Some(10));
+                let play = match question.position {
+                    Position::SmallBlind => "j",
+                    Position::BigBlind => "c",
+                };
+                if question.answer() == (i < 2) {
+                    play.to_string()
+                } else {
+                    "f".to_string()
+                }
+            })
+            .collect();
+        let input = format!("{}\nq\n", answers.join("\n"));
+
+        let mut out = Vec::new();
+        let mut rng = StdRng::seed_from_u64(4);
+        run_quiz(input.as_bytes(), &mut out, &mut rng, Some(10), 5).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.matches("Correct: ").count(), 2);
+        assert_eq!(text.matches("Wrong: ").count(), 1);
+        assert!(text.contains("4/5  10BB in the "));
+        assert!(text.ends_with("Score: 2/3 (67%)\n"));
+    }
+}
@@ -0,0 +1,328 @@
+//! Hold'em hand ranges in the usual notation, e.g.
+//! `AA-TT, AKs, KQo+, A5s-A2s, 76s:0.5`.
+//!
+//! - `AA`, `AKs`, `AKo` and `AK` (suited and offsuit) are single hands.
+//! - `TT+` is tens or better; `KTo+` is KTo, KJo and KQo.
+//! - `AA-TT` and `A5s-A2s` run between two hands.
+//! - `:0.5` after an entry plays it half the time.
+
+use std::str::FromStr;
+
+use super::deck::{Card, Rank, Suit};
+
+/// Number of distinct two-card starting hands.
+pub const COMBOS: usize = 1326;
+
+/// A weighted set of two-card starting hands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    /// Weight of every combo, indexed by `combo_index`; 0.0 if not in the range.
+    weights: Vec<f64>,
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self {
+            weights: vec![0.0; COMBOS],
+        }
+    }
+}
+
+impl Range {
+    pub fn parse(notation: &str) -> Result<Self, String> {
+        let mut range = Self::default();
+        for entry in notation.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (hands, weight) = parse_entry(entry).map_err(|e| format!("'{}': {}", entry, e))?;
+            for hand in hands {
+                for [a, b] in hand.combos() {
+                    range.weights[combo_index(a, b)] = weight;
+                }
+            }
+        }
+        Ok(range)
+    }
+
+    /// Weight of the hand `a b` (in either order), 0.0 if it isn't in the range.
+    pub fn weight(&self, a: Card, b: Card) -> f64 {
+        if a == b {
+            0.0
+        } else {
+            self.weights[combo_index(a, b)]
+        }
+    }
+
+    pub fn set_weight(&mut self, a: Card, b: Card, weight: f64) {
+        if a != b {
+            self.weights[combo_index(a, b)] = weight;
+        }
+    }
+
+    /// Take out every combo holding one of the `dead` cards, e.g. the hero's
+    /// hand and the board.
+    pub fn remove_blocked(&mut self, dead: &[Card]) {
+        for &card in dead {
+            for other in all_cards() {
+                self.set_weight(card, other, 0.0);
+            }
+        }
+    }
+
+    /// Every combo in the range with its weight.
+    pub fn combos(&self) -> Vec<([Card; 2], f64)> {
+        let cards = all_cards();
+        let mut combos = Vec::new();
+        for (i, &high) in cards.iter().enumerate() {
+            for &low in &cards[..i] {
+                let weight = self.weights[combo_index(high, low)];
+                if weight > 0.0 {
+                    combos.push(([high, low], weight));
+                }
+            }
+        }
+        combos
+    }
+
+    /// Number of combos in the range, ignoring weights.
+    pub fn len(&self) -> usize {
+        self.weights.iter().filter(|&&w| w > 0.0).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Combos counted by weight, so `AA:0.5` counts as 3.
+    pub fn weighted_len(&self) -> f64 {
+        self.weights.iter().sum()
+    }
+}
+
+impl FromStr for Range {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// A starting hand class such as AA, AKs or AKo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HandClass {
+    high: Rank,
+    low: Rank,
+    /// None for pairs and for hands given without `s` or `o`.
+    suited: Option<bool>,
+}
+
+impl HandClass {
+    fn is_pair(self) -> bool {
+        self.high == self.low
+    }
+
+    fn combos(self) -> Vec<[Card; 2]> {
+        let mut combos = Vec::new();
+        for (i, &s1) in Suit::ALL.iter().enumerate() {
+            for (j, &s2) in Suit::ALL.iter().enumerate() {
+                let keep = if self.is_pair() {
+                    i < j
+                } else {
+                    match self.suited {
+                        Some(true) => i == j,
+                        Some(false) => i != j,
+                        None => true,
+                    }
+                };
+                if keep {
+                    combos.push([Card::new(self.high, s1), Card::new(self.low, s2)]);
+                }
+            }
+        }
+        combos
+    }
+}
+
+fn parse_entry(entry: &str) -> Result<(Vec<HandClass>, f64), String> {
+    let (hands, weight) = match entry.split_once(':') {
+        Some((hands, weight)) => {
+            let weight: f64 = weight
+                .trim()
+                .parse()
+                .map_err(|_| format!("'{}' is not a valid weight", weight.trim()))?;
+            if !(0.0..=1.0).contains(&weight) {
+                return Err(format!("weight {} is not in range 0.0 to 1.0", weight));
+            }
+            (hands.trim(), weight)
+        }
+        None => (entry, 1.0),
+    };
+
+    let hands = if let Some(first) = hands.strip_suffix('+') {
+        let first = parse_hand(first)?;
+        let top = if first.is_pair() {
+            Rank::Ace
+        } else {
+            rank_below(first.high)
+        };
+        hands_between(first, top)
+    } else if let Some((from, to)) = hands.split_once('-') {
+        let (from, to) = (parse_hand(from)?, parse_hand(to)?);
+        if from.is_pair() != to.is_pair()
+            || (!from.is_pair() && (from.high != to.high || from.suited != to.suited))
+        {
+            return Err(
+                "ends of a span must be pairs, or share the first card and suitedness".to_string(),
+            );
+        }
+        let (bottom, top) = if from.low <= to.low {
+            (from, to)
+        } else {
+            (to, from)
+        };
+        hands_between(bottom, top.low)
+    } else {
+        vec![parse_hand(hands)?]
+    };
+    Ok((hands, weight))
+}
+
+/// `first` and every hand above it up to `top`: higher pairs, or higher
+/// second cards with the same first card.
+fn hands_between(first: HandClass, top: Rank) -> Vec<HandClass> {
+    Rank::ALL
+        .iter()
+        .filter(|&&rank| rank >= first.low && rank <= top)
+        .map(|&rank| {
+            if first.is_pair() {
+                HandClass {
+                    high: rank,
+                    low: rank,
+                    suited: None,
+                }
+            } else {
+                HandClass { low: rank, ..first }
+            }
+        })
+        .collect()
+}
+
+fn parse_hand(text: &str) -> Result<HandClass, String> {
+    let chars: Vec<char> = text.trim().chars().collect();
+    let (ranks, suited) = match chars.as_slice() {
+        [r1, r2] => ([*r1, *r2], None),
+        [r1, r2, 's' | 'S'] => ([*r1, *r2], Some(true)),
+        [r1, r2, 'o' | 'O'] => ([*r1, *r2], Some(false)),
+        _ => {
+            return Err(format!(
+                "'{}' is not a hand like AA, AKs or AKo",
+                text.trim()
+            ))
+        }
+    };
+    let r1 = Rank::try_from(ranks[0])?;
+    let r2 = Rank::try_from(ranks[1])?;
+    if r1 == r2 && suited.is_some() {
+        return Err("a pair can't be suited or offsuit".to_string());
+    }
+    Ok(HandClass {
+        high: r1.max(r2),
+        low: r1.min(r2),
+        suited,
+    })
+}
+
+fn rank_below(rank: Rank) -> Rank {
+    Rank::ALL
+        .iter()
+        .copied()
+        .rev()
+        .find(|&r| r < rank)
+        .unwrap_or(rank)
+}
+
+fn all_cards() -> Vec<Card> {
+    Rank::ALL
+        .iter()
+        .flat_map(|&rank| Suit::ALL.iter().map(move |&suit| Card::new(rank, suit)))
+        .collect()
+}
+
+pub(crate) fn card_index(card: Card) -> usize {
+    (card.rank as usize - 2) * 4 + card.suit as usize
+}
+
+/// Index of a two-card hand among the 1326, whatever the card order.
+fn combo_index(a: Card, b: Card) -> usize {
+    let (a, b) = (card_index(a), card_index(b));
+    let (high, low) = if a > b { (a, b) } else { (b, a) };
+    high * (high - 1) / 2 + low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(notation: &str) -> usize {
+        Range::parse(notation).unwrap().len()
+    }
+
+    #[test]
+    fn test_single_hands() {
+        assert_eq!(count("AA"), 6);
+        assert_eq!(count("AKs"), 4);
+        assert_eq!(count("AKo"), 12);
+        assert_eq!(count("AK"), 16);
+        assert_eq!(count("ka"), 16);
+    }
+
+    #[test]
+    fn test_plus_and_spans() {
+        assert_eq!(count("TT+"), 30);
+        assert_eq!(count("AA-TT"), 30);
+        assert_eq!(count("TT-AA"), 30);
+        assert_eq!(count("KTo+"), 36);
+        assert_eq!(count("KQo+"), 12);
+        assert_eq!(count("A5s-A2s"), 16);
+        assert_eq!(count("22+"), 78);
+    }
+
+    #[test]
+    fn test_weights() {
+        let range = Range::parse("AA-TT, AKs, KQo+, A5s-A2s, 76s:0.5").unwrap();
+        assert_eq!(range.len(), 66);
+        assert_eq!(range.weighted_len(), 64.0);
+        let seven = Card::new(Rank::Seven, Suit::Hearts);
+        let six = Card::new(Rank::Six, Suit::Hearts);
+        assert_eq!(range.weight(six, seven), 0.5);
+        assert_eq!(range.weight(seven, Card::new(Rank::Six, Suit::Clubs)), 0.0);
+    }
+
+    #[test]
+    fn test_all_combos_are_distinct() {
+        let range =
+            Range::parse("22+, A2+, K2+, Q2+, J2+, T2+, 92+, 82+, 72+, 62+, 52+, 42+, 32").unwrap();
+        assert_eq!(range.len(), COMBOS);
+        assert_eq!(range.combos().len(), COMBOS);
+    }
+
+    #[test]
+    fn test_blocked_combos_are_removed() {
+        let mut range = Range::parse("AA, AKs").unwrap();
+        range.remove_blocked(&[Card::new(Rank::Ace, Suit::Spades)]);
+        // Three aces left make three pairs, and three suited AK
+        assert_eq!(range.len(), 6);
+        assert!(range
+            .combos()
+            .iter()
+            .all(|(cards, _)| !cards.contains(&Card::new(Rank::Ace, Suit::Spades))));
+    }
+
+    #[test]
+    fn test_bad_notation_is_rejected() {
+        for bad in [
+            "AAs", "AX", "AKQ", "AK:2", "AK:x", "A5s-K2s", "AA-KQ", "A5s-A2o",
+        ] {
+            assert!(Range::parse(bad).is_err(), "{bad}");
+        }
+        assert!(Range::parse("").unwrap().is_empty());
+    }
+}
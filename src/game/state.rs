@@ -1,25 +1,24 @@
 use super::actions::{Action, AvailableActions};
 use super::deck::{Card, Deck};
-use super::hand::{evaluate_hand, HandEvaluation};
+use super::hand::{evaluate_hand_with, HandEvaluation};
+use super::history::{HandOutcome, HandRecord, ShownHand};
+use super::tournament::{BlindSchedule, Tournament};
+use super::variant::{BettingLimit, GameVariant};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::time::Instant;
 
-pub const BIG_BLIND: u32 = 2;
-pub const SMALL_BLIND: u32 = 1;
+/// Seat index of the human player. Every other seat is a bot.
+pub const HUMAN: usize = 0;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum Player {
-    Human,
-    Bot,
-}
+/// Largest supported table (full ring).
+pub const MAX_SEATS: usize = 9;
 
-impl Player {
-    pub fn opponent(&self) -> Self {
-        match self {
-            Player::Human => Player::Bot,
-            Player::Bot => Player::Human,
-        }
-    }
-}
+/// Fixed-limit cap: a bet and three raises per betting round. Preflop the
+/// big blind counts as the bet.
+pub const LIMIT_BETS_PER_ROUND: u32 = 4;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GamePhase {
@@ -33,7 +32,7 @@ pub enum GamePhase {
     Summary,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Street {
     Preflop,
     Flop,
@@ -53,61 +52,270 @@ impl From<GamePhase> for Option<Street> {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Forced bets for a cash game, in chips.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stakes {
+    pub small_blind: u32,
+    pub big_blind: u32,
+    #[serde(default)]
+    pub ante: u32,
+    /// The big blind pays a single ante for the whole table instead of
+    /// every player posting one.
+    #[serde(default)]
+    pub big_blind_ante: bool,
+}
+
+impl Default for Stakes {
+    fn default() -> Self {
+        Self {
+            small_blind: 1,
+            big_blind: 2,
+            ante: 0,
+            big_blind_ante: false,
+        }
+    }
+}
+
+impl Stakes {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.big_blind == 0 {
+            return Err("big blind must be positive".to_string());
+        }
+        if self.small_blind > self.big_blind {
+            return Err("small blind is bigger than big blind".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Table setup for a session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameConfig {
+    /// Number of seats, 2 (heads-up) to `MAX_SEATS`.
+    pub seats: usize,
+    /// Starting stack, in big blinds of the first level.
+    pub starting_stack_bb: u32,
+    pub variant: GameVariant,
+    /// Blinds and ante. In a tournament the amounts come from the blind
+    /// schedule, but the ante format still applies.
+    pub stakes: Stakes,
+    /// Play a sit-and-go with this blind structure instead of a cash game.
+    pub tournament: Option<BlindSchedule>,
+}
+
+impl GameConfig {
+    pub fn heads_up(starting_stack_bb: u32) -> Self {
+        Self {
+            seats: 2,
+            starting_stack_bb,
+            variant: GameVariant::default(),
+            stakes: Stakes::default(),
+            tournament: None,
+        }
+    }
+}
+
+/// Per-seat state. A seat with no chips at the start of a hand sits out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Seat {
+    pub stack: u32,
+    /// Chips put in on the current street.
+    pub bet: u32,
+    /// Chips put in over the whole hand, used to build side pots.
+    pub committed: u32,
+    pub cards: Vec<Card>,
+    /// Dealt into the current hand.
+    pub in_hand: bool,
+    pub folded: bool,
+    /// Has acted since the last bet or raise on this street.
+    pub acted: bool,
+}
+
+impl Seat {
+    fn new(stack: u32) -> Self {
+        Self {
+            stack,
+            bet: 0,
+            committed: 0,
+            cards: Vec::new(),
+            in_hand: false,
+            folded: false,
+            acted: false,
+        }
+    }
+
+    /// Still contesting the pot.
+    pub fn is_active(&self) -> bool {
+        self.in_hand && !self.folded
+    }
+
+    /// Still contesting the pot and has chips left to bet.
+    pub fn can_act(&self) -> bool {
+        self.is_active() && self.stack > 0
+    }
+}
+
+/// One pot (main or side) and who took it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PotAward {
+    pub amount: u32,
+    pub winners: Vec<usize>,
+}
+
+impl PotAward {
+    /// Chips of this pot going to `seat`. Odd chips go to the winners
+    /// listed first.
+    pub fn share(&self, seat: usize) -> u32 {
+        match self.winners.iter().position(|&w| w == seat) {
+            Some(position) => {
+                let count = self.winners.len() as u32;
+                self.amount / count + u32::from((position as u32) < self.amount % count)
+            }
+            None => 0,
+        }
+    }
+}
+
+/// How a seat fared at showdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatOutcome {
+    Won,
+    Split,
+    Lost,
+}
+
+/// Everything needed to pick a session back up except the RNG, which
+/// can't be serialized: a restored state draws from fresh entropy until
+/// `reseed` restarts it from a saved seed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
     pub phase: GamePhase,
+    pub variant: GameVariant,
     pub deck: Deck,
-    pub player_cards: Vec<Card>,
-    pub bot_cards: Vec<Card>,
+    pub seats: Vec<Seat>,
     pub board: Vec<Card>,
     pub pot: u32,
-    pub player_stack: u32,
-    pub bot_stack: u32,
-    pub player_bet: u32,
-    pub bot_bet: u32,
-    pub to_act: Player,
-    pub button: Player,
-    pub last_aggressor: Option<Player>,
-    pub preflop_aggressor: Option<Player>,
+    pub small_blind: u32,
+    pub big_blind: u32,
+    /// Paid by every player dealt in before the blinds, or by the big
+    /// blind alone in the big-blind ante format.
+    pub ante: u32,
+    pub big_blind_ante: bool,
+    /// Blind clock, in tournament mode.
+    pub tournament: Option<Tournament>,
+    pub to_act: usize,
+    pub button: usize,
+    pub small_blind_seat: usize,
+    pub big_blind_seat: usize,
+    pub last_aggressor: Option<usize>,
+    pub preflop_aggressor: Option<usize>,
     pub last_raise_size: u32,
+    /// Bets and raises made this round, for the fixed-limit cap.
+    pub bets_this_round: u32,
     pub hand_number: u32,
     pub starting_stack: u32,
     pub hands_played: u32,
     pub hands_won: u32,
     pub biggest_pot_won: u32,
     pub biggest_pot_lost: u32,
-    pub last_action: Option<(Player, Action)>,
+    pub last_action: Option<(usize, Action)>,
     pub showdown_result: Option<ShowdownResult>,
-    pub actions_this_street: u8,
+    /// Source of all card randomness. Seeded for reproducible sessions.
+    #[serde(skip, default = "StdRng::from_entropy")]
+    pub rng: StdRng,
+    /// Structured record of the hand in progress.
+    pub current_hand: HandRecord,
+    /// Completed hands not yet collected by `take_finished_hands`.
+    finished_hands: Vec<HandRecord>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShowdownResult {
-    pub winner: Option<Player>,
-    pub player_hand: HandEvaluation,
-    pub bot_hand: HandEvaluation,
+    /// Evaluated hand of every seat that reached showdown.
+    pub hands: Vec<(usize, HandEvaluation)>,
+    /// Main pot first, then side pots in the order they were built.
+    pub pots: Vec<PotAward>,
     pub pot_won: u32,
 }
 
+impl ShowdownResult {
+    pub fn hand(&self, seat: usize) -> Option<&HandEvaluation> {
+        self.hands.iter().find(|(s, _)| *s == seat).map(|(_, h)| h)
+    }
+
+    /// Chips awarded to `seat` across all pots.
+    pub fn winnings(&self, seat: usize) -> u32 {
+        self.pots
+            .iter()
+            .map(|pot| pot.share(seat))
+            .sum()
+    }
+
+    pub fn outcome(&self, seat: usize) -> SeatOutcome {
+        if self
+            .pots
+            .iter()
+            .any(|pot| pot.winners.len() == 1 && pot.winners[0] == seat)
+        {
+            SeatOutcome::Won
+        } else if self.pots.iter().any(|pot| pot.winners.contains(&seat)) {
+            SeatOutcome::Split
+        } else {
+            SeatOutcome::Lost
+        }
+    }
+}
+
 impl GameState {
     pub fn new(starting_stack_bb: u32) -> Self {
-        let starting_stack = starting_stack_bb * BIG_BLIND;
+        Self::with_rng(starting_stack_bb, StdRng::from_entropy())
+    }
+
+    /// Create a game whose deals are fully determined by `seed`.
+    pub fn with_seed(starting_stack_bb: u32, seed: u64) -> Self {
+        Self::with_rng(starting_stack_bb, StdRng::seed_from_u64(seed))
+    }
+
+    pub fn with_rng(starting_stack_bb: u32, rng: StdRng) -> Self {
+        Self::with_config(GameConfig::heads_up(starting_stack_bb), rng)
+    }
+
+    pub fn with_config(config: GameConfig, rng: StdRng) -> Self {
+        let seat_count = config.seats.clamp(2, MAX_SEATS);
+        let tournament = config.tournament.map(Tournament::new);
+        let (small_blind, big_blind, ante) = match tournament {
+            Some(ref t) => {
+                let level = t.current_level();
+                (level.small_blind, level.big_blind, level.ante)
+            }
+            None => (
+                config.stakes.small_blind,
+                config.stakes.big_blind,
+                config.stakes.ante,
+            ),
+        };
+        let starting_stack = config.starting_stack_bb * big_blind;
         let mut state = Self {
             phase: GamePhase::Preflop,
+            variant: config.variant,
             deck: Deck::new(),
-            player_cards: Vec::new(),
-            bot_cards: Vec::new(),
+            seats: vec![Seat::new(starting_stack); seat_count],
             board: Vec::new(),
             pot: 0,
-            player_stack: starting_stack,
-            bot_stack: starting_stack,
-            player_bet: 0,
-            bot_bet: 0,
-            to_act: Player::Human,
-            button: Player::Bot,
+            small_blind,
+            big_blind,
+            ante,
+            big_blind_ante: config.stakes.big_blind_ante,
+            tournament,
+            to_act: HUMAN,
+            // Moves onto seat 0 when the first hand is dealt
+            button: seat_count - 1,
+            small_blind_seat: 0,
+            big_blind_seat: 0,
             last_aggressor: None,
             preflop_aggressor: None,
-            last_raise_size: BIG_BLIND,
+            last_raise_size: big_blind,
+            bets_this_round: 0,
             hand_number: 0,
             starting_stack,
             hands_played: 0,
@@ -116,345 +324,602 @@ impl GameState {
             biggest_pot_lost: 0,
             last_action: None,
             showdown_result: None,
-            actions_this_street: 0,
+            rng,
+            current_hand: HandRecord::new(0, 0, 0, 0, small_blind, big_blind, ante),
+            finished_hands: Vec::new(),
         };
         state.start_new_hand();
         state
     }
 
+    /// Restart the card RNG from a seed drawn from it, and return the seed.
+    /// Saving the seed is how a saved session keeps its card stream.
+    pub fn reseed(&mut self) -> u64 {
+        let seed = self.rng.gen();
+        self.rng = StdRng::seed_from_u64(seed);
+        seed
+    }
+
+    pub fn num_seats(&self) -> usize {
+        self.seats.len()
+    }
+
+    pub fn is_heads_up(&self) -> bool {
+        self.seats.len() == 2
+    }
+
     pub fn start_new_hand(&mut self) {
         self.hand_number += 1;
-        self.button = self.button.opponent();
         self.phase = GamePhase::Preflop;
-        self.deck = Deck::new();
-        self.deck.shuffle();
-        self.player_cards = self.deck.deal_n(2);
-        self.bot_cards = self.deck.deal_n(2);
+        // Blinds only go up between hands
+        if let Some(ref mut tournament) = self.tournament {
+            tournament.update(self.hands_played, Instant::now());
+            let level = tournament.current_level();
+            self.small_blind = level.small_blind;
+            self.big_blind = level.big_blind;
+            self.ante = level.ante;
+        }
+        self.deck = self.variant.deck();
+        self.deck.shuffle(&mut self.rng);
         self.board.clear();
         self.pot = 0;
-        self.player_bet = 0;
-        self.bot_bet = 0;
         self.last_aggressor = None;
         self.preflop_aggressor = None;
-        self.last_raise_size = BIG_BLIND;
+        self.last_raise_size = self.big_blind;
+        // The big blind is the opening bet
+        self.bets_this_round = 1;
         self.last_action = None;
         self.showdown_result = None;
-        self.actions_this_street = 0;
-
-        // Post blinds - button posts SB, other player posts BB
-        // In heads-up, button acts first preflop
-        match self.button {
-            Player::Human => {
-                // Human is button (SB), Bot is BB
-                let sb = SMALL_BLIND.min(self.player_stack);
-                let bb = BIG_BLIND.min(self.bot_stack);
-                self.player_stack -= sb;
-                self.player_bet = sb;
-                self.bot_stack -= bb;
-                self.bot_bet = bb;
-                self.pot = sb + bb;
-                self.to_act = Player::Human; // Button acts first preflop
+
+        // Busted seats sit out; the button skips them
+        for seat in &mut self.seats {
+            seat.in_hand = seat.stack > 0;
+            seat.folded = false;
+            seat.acted = false;
+            seat.bet = 0;
+            seat.committed = 0;
+            seat.cards.clear();
+        }
+        self.button = self.next_seat(self.button, Seat::is_active);
+
+        // Heads-up the button posts the small blind and acts first preflop.
+        // Otherwise the blinds sit to the button's left and UTG opens.
+        if self.active_count() == 2 {
+            self.small_blind_seat = self.button;
+        } else {
+            self.small_blind_seat = self.next_seat(self.button, Seat::is_active);
+        }
+        self.big_blind_seat = self.next_seat(self.small_blind_seat, Seat::is_active);
+
+        for seat in self.seats.iter_mut().filter(|s| s.in_hand) {
+            seat.cards = self.deck.deal_n(self.variant.hole_cards());
+        }
+
+        self.current_hand = HandRecord::new(
+            self.hand_number,
+            self.button,
+            self.small_blind_seat,
+            self.big_blind_seat,
+            self.small_blind,
+            self.big_blind,
+            self.ante,
+        );
+        self.current_hand.variant = self.variant;
+        self.current_hand.big_blind_ante = self.big_blind_ante;
+        for (index, seat) in self.seats.iter().enumerate() {
+            if seat.in_hand {
+                self.current_hand.add_seat(index, seat.stack, &seat.cards);
             }
-            Player::Bot => {
-                // Bot is button (SB), Human is BB
-                let sb = SMALL_BLIND.min(self.bot_stack);
-                let bb = BIG_BLIND.min(self.player_stack);
-                self.bot_stack -= sb;
-                self.bot_bet = sb;
-                self.player_stack -= bb;
-                self.player_bet = bb;
-                self.pot = sb + bb;
-                self.to_act = Player::Bot; // Button acts first preflop
+        }
+
+        if self.big_blind_ante {
+            // The blind takes priority if the big blind can't cover both
+            self.add_chips(self.small_blind_seat, self.small_blind);
+            self.add_chips(self.big_blind_seat, self.big_blind);
+            self.post_ante(self.big_blind_seat, self.ante);
+        } else {
+            for index in 0..self.seats.len() {
+                if self.seats[index].in_hand {
+                    self.post_ante(index, self.ante);
+                }
             }
+            self.add_chips(self.small_blind_seat, self.small_blind);
+            self.add_chips(self.big_blind_seat, self.big_blind);
+        }
+        self.to_act = self.next_seat(self.big_blind_seat, Seat::can_act);
+
+        self.current_hand
+            .start_street(Street::Preflop, &self.board, self.pot);
+
+        // The blinds put everyone all-in: nothing to bet, just deal
+        if self.is_runout_ready() {
+            self.advance_phase();
         }
     }
 
-    pub fn apply_action(&mut self, player: Player, action: Action) {
-        self.last_action = Some((player, action));
-        self.actions_this_street += 1;
+    pub fn apply_action(&mut self, seat: usize, action: Action) {
+        let action = self.enforce_limit(seat, action);
+        self.last_action = Some((seat, action));
 
         match action {
             Action::Fold => {
-                self.handle_fold(player);
-                return;
+                self.seats[seat].folded = true;
             }
             Action::Check => {
                 // Nothing to do
             }
             Action::Call(amount) => {
-                self.add_chips(player, amount);
-            }
-            Action::Bet(amount) | Action::Raise(amount) => {
-                let current_bet = self.current_bet(player);
-                let to_add = amount - current_bet;
-                let old_max = self.max_bet(); // Capture before mutation
-                self.add_chips(player, to_add);
-                self.last_aggressor = Some(player);
-                self.last_raise_size = amount - old_max;
-                if self.phase == GamePhase::Preflop {
-                    self.preflop_aggressor = Some(player);
-                }
+                self.add_chips(seat, amount);
             }
-            Action::AllIn(amount) => {
-                let current_bet = self.current_bet(player);
-                let to_add = amount - current_bet;
+            Action::Bet(amount) | Action::Raise(amount) | Action::AllIn(amount) => {
+                let current_bet = self.seats[seat].bet;
+                let to_add = amount.saturating_sub(current_bet);
                 let old_max = self.max_bet(); // Capture before mutation
-                self.add_chips(player, to_add);
-                if amount > old_max {
-                    self.last_aggressor = Some(player);
+                self.add_chips(seat, to_add);
+                // An all-in for no more than the current bet is just a call.
+                // One for less than a full raise has to be called, but
+                // doesn't reopen the betting or change the minimum raise.
+                if amount > old_max && amount - old_max >= self.last_raise_size {
+                    self.last_aggressor = Some(seat);
                     self.last_raise_size = amount - old_max;
+                    self.bets_this_round += 1;
                     if self.phase == GamePhase::Preflop {
-                        self.preflop_aggressor = Some(player);
+                        self.preflop_aggressor = Some(seat);
+                    }
+                    // Everyone else must respond to the new bet
+                    for (index, other) in self.seats.iter_mut().enumerate() {
+                        if index != seat {
+                            other.acted = false;
+                        }
                     }
                 }
             }
         }
+        self.seats[seat].acted = true;
+        self.record_action(seat, action);
+
+        if self.active_count() == 1 {
+            self.award_uncontested();
+            return;
+        }
 
         // Check if betting round is complete
         if self.is_betting_round_complete() {
             self.advance_phase();
         } else {
-            self.to_act = player.opponent();
+            self.to_act = self.next_seat(seat, Seat::can_act);
         }
     }
 
-    fn add_chips(&mut self, player: Player, amount: u32) {
-        match player {
-            Player::Human => {
-                let actual = amount.min(self.player_stack);
-                self.player_stack -= actual;
-                self.player_bet += actual;
-                self.pot += actual;
-            }
-            Player::Bot => {
-                let actual = amount.min(self.bot_stack);
-                self.bot_stack -= actual;
-                self.bot_bet += actual;
-                self.pot += actual;
+    /// In fixed-limit every bet and raise is the fixed size, and once the
+    /// round is capped a raise becomes a call.
+    fn enforce_limit(&self, seat: usize, action: Action) -> Action {
+        if self.variant.limit() != BettingLimit::FixedLimit {
+            return action;
+        }
+        let amount = match action {
+            Action::Bet(amount) | Action::Raise(amount) | Action::AllIn(amount) => amount,
+            _ => return action,
+        };
+        let max_bet = self.max_bet();
+        if amount <= max_bet {
+            return action;
+        }
+
+        let to_call = self.amount_to_call(seat);
+        let all_in_to = self.seats[seat].bet + self.seats[seat].stack;
+        if self.is_raising_capped() {
+            return if to_call == 0 {
+                Action::Check
+            } else if to_call >= self.seats[seat].stack {
+                Action::AllIn(all_in_to)
+            } else {
+                Action::Call(to_call)
+            };
+        }
+
+        let fixed_to = max_bet + self.limit_bet_size();
+        if fixed_to >= all_in_to {
+            Action::AllIn(all_in_to)
+        } else {
+            match action {
+                Action::Bet(_) => Action::Bet(fixed_to),
+                _ if to_call == 0 => Action::Bet(fixed_to),
+                _ => Action::Raise(fixed_to),
             }
         }
     }
 
-    fn current_bet(&self, player: Player) -> u32 {
-        match player {
-            Player::Human => self.player_bet,
-            Player::Bot => self.bot_bet,
-        }
+    fn record_action(&mut self, seat: usize, action: Action) {
+        let stack = self.seats[seat].stack;
+        self.current_hand
+            .record_action(seat, action, self.pot, stack);
+    }
+
+    /// Close out the current hand record and queue it for collection.
+    fn finish_hand_record(&mut self, outcome: HandOutcome) {
+        self.current_hand.result = Some(outcome);
+        self.finished_hands.push(self.current_hand.clone());
+    }
+
+    /// Remove and return all hands completed since the last call.
+    pub fn take_finished_hands(&mut self) -> Vec<HandRecord> {
+        std::mem::take(&mut self.finished_hands)
+    }
+
+    fn add_chips(&mut self, seat: usize, amount: u32) {
+        let seat = &mut self.seats[seat];
+        let actual = amount.min(seat.stack);
+        seat.stack -= actual;
+        seat.bet += actual;
+        seat.committed += actual;
+        self.pot += actual;
+    }
+
+    /// Antes are dead money: they go in the pot but don't count toward
+    /// the bet a player has to match.
+    fn post_ante(&mut self, seat: usize, ante: u32) {
+        let seat = &mut self.seats[seat];
+        let actual = ante.min(seat.stack);
+        seat.stack -= actual;
+        seat.committed += actual;
+        self.pot += actual;
     }
 
     pub fn max_bet(&self) -> u32 {
-        self.player_bet.max(self.bot_bet)
+        self.seats.iter().map(|s| s.bet).max().unwrap_or(0)
     }
 
-    fn handle_fold(&mut self, folder: Player) {
-        let winner = folder.opponent();
-        let pot = self.pot;
+    /// Next seat clockwise from `from` (exclusive) matching `pred`, wrapping
+    /// around the table. Returns `from` if no other seat matches.
+    pub fn next_seat(&self, from: usize, pred: impl Fn(&Seat) -> bool) -> usize {
+        let n = self.seats.len();
+        (1..=n)
+            .map(|offset| (from + offset) % n)
+            .find(|&index| pred(&self.seats[index]))
+            .unwrap_or(from)
+    }
 
-        match winner {
-            Player::Human => {
-                self.player_stack += pot;
-                self.hands_won += 1;
-                if pot > self.biggest_pot_won {
-                    self.biggest_pot_won = pot;
-                }
-            }
-            Player::Bot => {
-                self.bot_stack += pot;
-                if pot > self.biggest_pot_lost {
-                    self.biggest_pot_lost = pot;
-                }
-            }
-        }
+    /// Seats still contesting the pot.
+    pub fn active_seats(&self) -> Vec<usize> {
+        (0..self.seats.len())
+            .filter(|&index| self.seats[index].is_active())
+            .collect()
+    }
+
+    fn active_count(&self) -> usize {
+        self.seats.iter().filter(|s| s.is_active()).count()
+    }
+
+    /// Opponents still contesting the pot, from `seat`'s point of view.
+    pub fn opponents_in_hand(&self, seat: usize) -> usize {
+        self.active_seats().iter().filter(|&&s| s != seat).count()
+    }
+
+    /// True if `seat` acts after every other active seat postflop.
+    pub fn is_in_position(&self, seat: usize) -> bool {
+        let n = self.seats.len();
+        let order = |s: usize| (s + n - self.button - 1) % n;
+        self.active_seats()
+            .iter()
+            .all(|&other| order(other) <= order(seat))
+    }
+
+    /// At most one player can still bet, so the remaining board is dealt
+    /// without further action.
+    pub fn is_all_in_runout(&self) -> bool {
+        self.active_count() >= 2 && self.seats.iter().filter(|s| s.can_act()).count() <= 1
+    }
+
+    /// The session is over once the human busts or no opponent has chips left.
+    pub fn is_session_over(&self) -> bool {
+        self.seats[HUMAN].stack == 0
+            || self
+                .seats
+                .iter()
+                .enumerate()
+                .all(|(index, seat)| index == HUMAN || seat.stack == 0)
+    }
+
+    /// Everyone else folded: the last active seat takes the whole pot.
+    fn award_uncontested(&mut self) {
+        let winner = self.next_seat(self.to_act, Seat::is_active);
+        let pot = self.pot;
+        self.seats[winner].stack += pot;
+        self.record_human_result(pot, winner == HUMAN);
+
+        self.finish_hand_record(HandOutcome {
+            pot,
+            showdown: false,
+            pots: vec![PotAward {
+                amount: pot,
+                winners: vec![winner],
+            }],
+            shown: Vec::new(),
+            stacks: self.seats.iter().map(|s| s.stack).collect(),
+        });
 
         self.pot = 0;
         self.hands_played += 1;
         self.phase = GamePhase::HandComplete;
     }
 
-    fn is_betting_round_complete(&self) -> bool {
-        // Both players all-in: always complete
-        if self.player_stack == 0 && self.bot_stack == 0 {
-            return true;
-        }
-
-        // One player all-in
-        if self.player_stack == 0 || self.bot_stack == 0 {
-            let allin_bet = if self.player_stack == 0 {
-                self.player_bet
-            } else {
-                self.bot_bet
-            };
-            let other_bet = if self.player_stack == 0 {
-                self.bot_bet
-            } else {
-                self.player_bet
-            };
-
-            // Short all-in (pushed for <= other's existing bet): complete immediately
-            if allin_bet <= other_bet {
-                return true;
+    fn record_human_result(&mut self, pot: u32, won: bool) {
+        if won {
+            self.hands_won += 1;
+            if pot > self.biggest_pot_won {
+                self.biggest_pot_won = pot;
             }
-
-            // All-in for more: complete when other player matches
-            return self.player_bet == self.bot_bet;
+        } else if self.seats[HUMAN].committed > 0 && pot > self.biggest_pot_lost {
+            self.biggest_pot_lost = pot;
         }
+    }
 
-        // Bets must be equal
-        if self.player_bet != self.bot_bet {
-            return false;
-        }
+    /// At most one player can still bet and nobody owes a call, so the
+    /// board is dealt out without asking anyone.
+    fn is_runout_ready(&self) -> bool {
+        self.is_all_in_runout() && self.is_betting_round_complete()
+    }
 
-        // Preflop special case: BB gets option if no raise
-        if self.phase == GamePhase::Preflop {
-            if self.last_aggressor.is_none() {
-                // No raise yet, BB gets option. Round complete only when BB has checked.
-                let bb_player = self.button.opponent();
-                return self
-                    .last_action
-                    .map(|(actor, action)| actor == bb_player && action == Action::Check)
-                    .unwrap_or(false);
-            }
+    fn is_betting_round_complete(&self) -> bool {
+        let max = self.max_bet();
+        let can_act: Vec<&Seat> = self.seats.iter().filter(|s| s.can_act()).collect();
+
+        // Everyone is all-in
+        if can_act.is_empty() {
+            return true;
         }
 
-        // Postflop: both players must have acted for round to complete
-        // (With no aggressor and equal bets, need at least 2 actions = both checked)
-        if self.last_aggressor.is_none() {
-            return self.actions_this_street >= 2;
+        // A lone player with chips has nobody left to bet against once
+        // they have matched the biggest all-in
+        if can_act.len() == 1 && can_act[0].bet >= max {
+            return true;
         }
 
-        // With an aggressor, if bets are equal, the other player called
-        true
+        // Everyone who can still bet has acted since the last raise and
+        // matched it. Preflop the big blind has not acted yet, which gives
+        // them the option after limps.
+        can_act.iter().all(|s| s.acted && s.bet == max)
     }
 
     pub fn advance_phase(&mut self) {
-        // Return excess chips for unequal all-in bets (heads-up: no side pots needed)
-        if self.player_bet != self.bot_bet {
-            let effective = self.player_bet.min(self.bot_bet);
-            if self.player_bet > effective {
-                let excess = self.player_bet - effective;
-                self.player_stack += excess;
-                self.pot -= excess;
-            } else {
-                let excess = self.bot_bet - effective;
-                self.bot_stack += excess;
-                self.pot -= excess;
-            }
-        }
+        self.return_uncalled_bet();
 
-        // Reset street bets and action counter
-        self.player_bet = 0;
-        self.bot_bet = 0;
+        // Reset street bets
+        for seat in &mut self.seats {
+            seat.bet = 0;
+            seat.acted = false;
+        }
         self.last_aggressor = None;
-        self.actions_this_street = 0;
+        self.last_raise_size = self.big_blind;
+        self.bets_this_round = 0;
 
-        match self.phase {
+        let street = match self.phase {
             GamePhase::Preflop => {
                 self.board.extend(self.deck.deal_n(3));
                 self.phase = GamePhase::Flop;
+                Street::Flop
             }
             GamePhase::Flop => {
                 self.board.extend(self.deck.deal_n(1));
                 self.phase = GamePhase::Turn;
+                Street::Turn
             }
             GamePhase::Turn => {
                 self.board.extend(self.deck.deal_n(1));
                 self.phase = GamePhase::River;
+                Street::River
             }
             GamePhase::River => {
                 self.resolve_showdown();
                 return;
             }
             _ => return,
+        };
+        self.current_hand.start_street(street, &self.board, self.pot);
+
+        // Nobody left to bet against: deal the rest of the board out
+        if self.is_runout_ready() {
+            self.advance_phase();
+            return;
         }
 
-        // Postflop: BB (non-button) acts first
-        self.to_act = self.button.opponent();
+        // Postflop: first active player left of the button acts first
+        self.to_act = self.next_seat(self.button, Seat::can_act);
     }
 
-    fn resolve_showdown(&mut self) {
-        let player_eval = evaluate_hand(&self.player_cards, &self.board);
-        let bot_eval = evaluate_hand(&self.bot_cards, &self.board);
-
-        let winner = match player_eval.rank.cmp(&bot_eval.rank) {
-            std::cmp::Ordering::Greater => Some(Player::Human),
-            std::cmp::Ordering::Less => Some(Player::Bot),
-            std::cmp::Ordering::Equal => {
-                match player_eval.kickers.cmp(&bot_eval.kickers) {
-                    std::cmp::Ordering::Greater => Some(Player::Human),
-                    std::cmp::Ordering::Less => Some(Player::Bot),
-                    std::cmp::Ordering::Equal => None, // Split pot
-                }
+    /// Give back the part of the biggest bet that nobody could match.
+    fn return_uncalled_bet(&mut self) {
+        let mut bets: Vec<u32> = self.seats.iter().map(|s| s.bet).collect();
+        bets.sort_unstable_by(|a, b| b.cmp(a));
+        let (top, second) = (bets[0], bets.get(1).copied().unwrap_or(0));
+        if top > second {
+            let excess = top - second;
+            if let Some(seat) = self.seats.iter_mut().find(|s| s.bet == top) {
+                seat.stack += excess;
+                seat.bet -= excess;
+                seat.committed -= excess;
+                self.pot -= excess;
             }
-        };
+        }
+    }
 
-        let pot = self.pot;
-        match winner {
-            Some(Player::Human) => {
-                self.player_stack += pot;
-                self.hands_won += 1;
-                if pot > self.biggest_pot_won {
-                    self.biggest_pot_won = pot;
-                }
-            }
-            Some(Player::Bot) => {
-                self.bot_stack += pot;
-                if pot > self.biggest_pot_lost {
-                    self.biggest_pot_lost = pot;
-                }
-            }
-            None => {
-                // Split pot - odd chip goes to out-of-position player (non-button)
-                let half = pot / 2;
-                let remainder = pot % 2;
-                if self.button == Player::Human {
-                    // Bot is out of position, gets odd chip
-                    self.player_stack += half;
-                    self.bot_stack += half + remainder;
-                } else {
-                    // Human is out of position, gets odd chip
-                    self.player_stack += half + remainder;
-                    self.bot_stack += half;
+    /// Split committed chips into a main pot and side pots. Each pot lists
+    /// the active seats eligible to win it.
+    fn build_pots(&self) -> Vec<(u32, Vec<usize>)> {
+        let active = self.active_seats();
+        let mut levels: Vec<u32> = active.iter().map(|&s| self.seats[s].committed).collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut pots: Vec<(u32, Vec<usize>)> = Vec::new();
+        let mut previous = 0;
+        for level in levels {
+            let amount: u32 = self
+                .seats
+                .iter()
+                .map(|s| s.committed.min(level) - s.committed.min(previous))
+                .sum();
+            let eligible: Vec<usize> = active
+                .iter()
+                .copied()
+                .filter(|&s| self.seats[s].committed >= level)
+                .collect();
+            if amount > 0 {
+                // Same contenders as the previous level: one pot, not two
+                match pots.last_mut() {
+                    Some((last_amount, last_eligible)) if *last_eligible == eligible => {
+                        *last_amount += amount;
+                    }
+                    _ => pots.push((amount, eligible)),
                 }
             }
+            previous = level;
         }
 
-        self.showdown_result = Some(ShowdownResult {
-            winner,
-            player_hand: player_eval,
-            bot_hand: bot_eval,
+        // Dead money above the last contender level (can't normally happen
+        // once uncalled bets are returned) stays in the last pot
+        let built: u32 = pots.iter().map(|(amount, _)| amount).sum();
+        if let Some((amount, _)) = pots.last_mut() {
+            *amount += self.pot.saturating_sub(built);
+        }
+        pots
+    }
+
+    fn resolve_showdown(&mut self) {
+        let ranking = self.variant.ranking();
+        let hands: Vec<(usize, HandEvaluation)> = self
+            .active_seats()
+            .into_iter()
+            .map(|s| {
+                let hand = evaluate_hand_with(&self.seats[s].cards, &self.board, ranking);
+                (s, hand)
+            })
+            .collect();
+
+        let n = self.seats.len();
+        // Odd chips go to the first winner left of the button (heads-up,
+        // that is the out-of-position player)
+        let order = |s: usize| (s + n - self.button - 1) % n;
+
+        let mut pots = Vec::new();
+        for (amount, eligible) in self.build_pots() {
+            let contenders: Vec<&(usize, HandEvaluation)> =
+                hands.iter().filter(|(s, _)| eligible.contains(s)).collect();
+            let best = contenders
+                .iter()
+                .map(|(_, h)| h)
+                .max_by(|a, b| a.compare_with(b, ranking))
+                .cloned();
+            let mut winners: Vec<usize> = match best {
+                Some(best) => contenders
+                    .iter()
+                    .filter(|(_, h)| h.compare_with(&best, ranking) == Ordering::Equal)
+                    .map(|(s, _)| *s)
+                    .collect(),
+                None => eligible.clone(),
+            };
+            winners.sort_by_key(|&s| order(s));
+            pots.push(PotAward { amount, winners });
+        }
+
+        let pot = self.pot;
+        let result = ShowdownResult {
+            hands,
+            pots,
             pot_won: pot,
+        };
+        for index in 0..n {
+            self.seats[index].stack += result.winnings(index);
+        }
+        if self.seats[HUMAN].in_hand {
+            match result.outcome(HUMAN) {
+                SeatOutcome::Won => self.record_human_result(pot, true),
+                SeatOutcome::Lost => self.record_human_result(pot, false),
+                SeatOutcome::Split => {}
+            }
+        }
+
+        self.finish_hand_record(HandOutcome {
+            pot,
+            showdown: true,
+            pots: result.pots.clone(),
+            shown: result
+                .hands
+                .iter()
+                .map(|(seat, hand)| ShownHand {
+                    seat: *seat,
+                    description: hand.description.clone(),
+                })
+                .collect(),
+            stacks: self.seats.iter().map(|s| s.stack).collect(),
         });
 
+        self.showdown_result = Some(result);
         self.pot = 0;
         self.hands_played += 1;
         self.phase = GamePhase::Showdown;
     }
 
-    pub fn amount_to_call(&self, player: Player) -> u32 {
-        let current = self.current_bet(player);
-        let max = self.max_bet();
-        if max > current {
-            max - current
-        } else {
-            0
-        }
+    pub fn amount_to_call(&self, seat: usize) -> u32 {
+        self.max_bet().saturating_sub(self.seats[seat].bet)
     }
 
     pub fn available_actions(&self) -> AvailableActions {
-        let stack = match self.to_act {
-            Player::Human => self.player_stack,
-            Player::Bot => self.bot_stack,
+        let stack = self.seats[self.to_act].stack;
+        let to_call = self.amount_to_call(self.to_act);
+        let min_raise_to = self.max_bet() + self.last_raise_size.max(self.big_blind);
+        let all_in_to = self.seats[self.to_act].bet + stack;
+
+        let mut actions = match self.variant.limit() {
+            BettingLimit::NoLimit => {
+                AvailableActions::new(to_call, min_raise_to, all_in_to, stack, self.big_blind)
+            }
+            BettingLimit::PotLimit => {
+                // Call first, then raise by the size of the pot
+                let pot_raise_to = (self.max_bet() + self.pot + to_call).min(all_in_to);
+                AvailableActions::new(to_call, min_raise_to, pot_raise_to, stack, self.big_blind)
+            }
+            BettingLimit::FixedLimit => {
+                let size = self.limit_bet_size();
+                let fixed_to = self.max_bet() + size;
+                let mut actions =
+                    AvailableActions::new(to_call, fixed_to, fixed_to.min(all_in_to), stack, size);
+                if self.is_raising_capped() {
+                    actions.min_raise = None;
+                    actions.min_bet = None;
+                    actions.max_raise = self.max_bet().min(all_in_to);
+                }
+                actions
+            }
         };
+        // Having acted since the last full raise, a player facing a short
+        // all-in can only call or fold
+        if self.seats[self.to_act].acted && to_call > 0 {
+            actions.min_raise = None;
+            actions.max_raise = self.max_bet().min(all_in_to);
+        }
+        actions
+    }
 
-        let to_call = self.amount_to_call(self.to_act);
-        let min_raise_to = self.max_bet() + self.last_raise_size.max(BIG_BLIND);
+    /// Fixed-limit bet size for the current street: the small bet (one big
+    /// blind) preflop and on the flop, the big bet (two) on the turn and river.
+    pub fn limit_bet_size(&self) -> u32 {
+        match self.phase {
+            GamePhase::Turn | GamePhase::River => self.big_blind * 2,
+            _ => self.big_blind,
+        }
+    }
 
-        AvailableActions::new(to_call, min_raise_to, stack, BIG_BLIND)
+    /// Fixed-limit only: the round has had its bet and three raises.
+    pub fn is_raising_capped(&self) -> bool {
+        self.variant.limit() == BettingLimit::FixedLimit
+            && self.bets_this_round >= LIMIT_BETS_PER_ROUND
     }
 
     #[allow(dead_code)]
     pub fn pot_odds(&self) -> Option<(f64, f64)> {
-        let to_call = self.amount_to_call(Player::Human);
+        let to_call = self.amount_to_call(HUMAN);
         if to_call == 0 {
             return None;
         }
@@ -467,7 +932,8 @@ impl GameState {
     }
 
     pub fn is_player_turn(&self) -> bool {
-        self.to_act == Player::Human
+        self.to_act == HUMAN
+            && self.seats[HUMAN].can_act()
             && !matches!(
                 self.phase,
                 GamePhase::Showdown
@@ -477,9 +943,30 @@ impl GameState {
             )
     }
 
+    /// Current blinds for display, e.g. "1/3", "50/100 ante 10" or
+    /// "50/100 BB ante 100".
+    pub fn stakes_label(&self) -> String {
+        let mut label = format!("{}/{}", self.small_blind, self.big_blind);
+        if self.ante > 0 {
+            let format = if self.big_blind_ante { " BB" } else { "" };
+            label.push_str(&format!("{} ante {}", format, self.ante));
+        }
+        label
+    }
+
     pub fn session_profit_bb(&self) -> f64 {
-        let current = self.player_stack as f64;
+        let current = self.seats[HUMAN].stack as f64;
         let starting = self.starting_stack as f64;
-        (current - starting) / BIG_BLIND as f64
+        (current - starting) / self.big_blind as f64
+    }
+
+    /// Where the human finished: 1st if they outlasted every opponent,
+    /// otherwise one behind everyone still holding chips.
+    pub fn finishing_position(&self) -> usize {
+        if self.seats[HUMAN].stack > 0 {
+            1
+        } else {
+            1 + self.seats.iter().filter(|s| s.stack > 0).count()
+        }
     }
 }
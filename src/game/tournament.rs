@@ -0,0 +1,299 @@
+//! Sit-and-go tournament structure: blind levels that rise by hand count or
+//! by time, and the clock that tracks the current level.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// One level of a blind structure. A level ends after `hands` hands or
+/// `minutes` minutes, whichever comes first. The last level never ends.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindLevel {
+    pub small_blind: u32,
+    pub big_blind: u32,
+    #[serde(default)]
+    pub ante: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hands: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minutes: Option<u32>,
+}
+
+impl BlindLevel {
+    fn by_hands(small_blind: u32, big_blind: u32, ante: u32, hands: u32) -> Self {
+        Self {
+            small_blind,
+            big_blind,
+            ante,
+            hands: Some(hands),
+            minutes: None,
+        }
+    }
+}
+
+/// A full blind structure, loadable from JSON:
+///
+/// ```json
+/// { "levels": [
+///     { "small_blind": 10, "big_blind": 20, "hands": 10 },
+///     { "small_blind": 25, "big_blind": 50, "ante": 5, "minutes": 5 }
+/// ] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindSchedule {
+    pub levels: Vec<BlindLevel>,
+}
+
+impl BlindSchedule {
+    /// Standard sit-and-go structure: blinds go up every 10 hands, antes
+    /// kick in from level 6.
+    pub fn sit_and_go() -> Self {
+        let levels = [
+            (10, 20, 0),
+            (15, 30, 0),
+            (25, 50, 0),
+            (50, 100, 0),
+            (75, 150, 0),
+            (100, 200, 25),
+            (150, 300, 25),
+            (200, 400, 50),
+            (300, 600, 75),
+            (400, 800, 100),
+            (600, 1200, 150),
+            (800, 1600, 200),
+            (1000, 2000, 250),
+        ];
+        Self {
+            levels: levels
+                .iter()
+                .map(|&(sb, bb, ante)| BlindLevel::by_hands(sb, bb, ante, 10))
+                .collect(),
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let schedule: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        schedule.validate()?;
+        Ok(schedule)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        Self::from_json(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.levels.is_empty() {
+            return Err("blind structure has no levels".to_string());
+        }
+        for (i, level) in self.levels.iter().enumerate() {
+            let n = i + 1;
+            if level.big_blind == 0 {
+                return Err(format!("level {}: big blind must be positive", n));
+            }
+            if level.small_blind > level.big_blind {
+                return Err(format!("level {}: small blind is bigger than big blind", n));
+            }
+            if level.hands == Some(0) || level.minutes == Some(0) {
+                return Err(format!("level {}: duration must be positive", n));
+            }
+            let last = n == self.levels.len();
+            if !last && level.hands.is_none() && level.minutes.is_none() {
+                return Err(format!("level {}: needs a `hands` or `minutes` duration", n));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Tournament clock: which level is running and when it started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tournament {
+    pub schedule: BlindSchedule,
+    /// Index into `schedule.levels`.
+    pub level: usize,
+    level_start_hand: u32,
+    #[serde(with = "elapsed")]
+    level_started_at: Instant,
+}
+
+/// Saves an `Instant` as the time elapsed since it. The clock stops while
+/// a session is saved, so time away from the table doesn't count.
+mod elapsed {
+    use std::time::{Duration, Instant};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(at: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        Instant::now()
+            .saturating_duration_since(*at)
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        let elapsed = Duration::deserialize(deserializer)?;
+        let now = Instant::now();
+        Ok(now.checked_sub(elapsed).unwrap_or(now))
+    }
+}
+
+impl Tournament {
+    pub fn new(schedule: BlindSchedule) -> Self {
+        Self {
+            schedule,
+            level: 0,
+            level_start_hand: 0,
+            level_started_at: Instant::now(),
+        }
+    }
+
+    pub fn current_level(&self) -> &BlindLevel {
+        &self.schedule.levels[self.level]
+    }
+
+    pub fn is_last_level(&self) -> bool {
+        self.level + 1 >= self.schedule.levels.len()
+    }
+
+    /// Move to the next level if the current one has run its course.
+    /// Called between hands; returns true if the blinds went up.
+    pub fn update(&mut self, hands_played: u32, now: Instant) -> bool {
+        if self.is_last_level() {
+            return false;
+        }
+        let hands_done = self
+            .hands_left(hands_played)
+            .is_some_and(|left| left == 0);
+        let time_done = self.time_left(now).is_some_and(|left| left.is_zero());
+        if hands_done || time_done {
+            self.level += 1;
+            self.level_start_hand = hands_played;
+            self.level_started_at = now;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Hands until the blinds go up, for hand-based levels.
+    pub fn hands_left(&self, hands_played: u32) -> Option<u32> {
+        if self.is_last_level() {
+            return None;
+        }
+        let played = hands_played.saturating_sub(self.level_start_hand);
+        self.current_level()
+            .hands
+            .map(|hands| hands.saturating_sub(played))
+    }
+
+    /// Time until the blinds go up, for timed levels.
+    pub fn time_left(&self, now: Instant) -> Option<Duration> {
+        if self.is_last_level() {
+            return None;
+        }
+        let elapsed = now.saturating_duration_since(self.level_started_at);
+        self.current_level()
+            .minutes
+            .map(|minutes| Duration::from_secs(minutes as u64 * 60).saturating_sub(elapsed))
+    }
+}
+
+/// "1st", "2nd", "3rd", "4th", ...
+pub fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schedule() {
+        let schedule = BlindSchedule::from_json(
+            r#"{ "levels": [
+                { "small_blind": 10, "big_blind": 20, "hands": 5 },
+                { "small_blind": 25, "big_blind": 50, "ante": 5, "minutes": 3 },
+                { "small_blind": 50, "big_blind": 100, "ante": 10 }
+            ] }"#,
+        )
+        .unwrap();
+        assert_eq!(schedule.levels.len(), 3);
+        assert_eq!(schedule.levels[0].ante, 0);
+        assert_eq!(schedule.levels[1].minutes, Some(3));
+        assert_eq!(schedule.levels[2].hands, None);
+    }
+
+    #[test]
+    fn test_invalid_schedules_are_rejected() {
+        assert!(BlindSchedule::from_json(r#"{ "levels": [] }"#).is_err());
+        assert!(BlindSchedule::from_json(
+            r#"{ "levels": [{ "small_blind": 30, "big_blind": 20, "hands": 5 }] }"#
+        )
+        .is_err());
+        // Only the last level may run forever
+        assert!(BlindSchedule::from_json(
+            r#"{ "levels": [
+                { "small_blind": 10, "big_blind": 20 },
+                { "small_blind": 20, "big_blind": 40 }
+            ] }"#
+        )
+        .is_err());
+        assert!(BlindSchedule::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_levels_advance_by_hands() {
+        let mut tournament = Tournament::new(BlindSchedule::sit_and_go());
+        let now = Instant::now();
+        assert_eq!(tournament.hands_left(0), Some(10));
+        assert!(!tournament.update(9, now));
+        assert_eq!(tournament.hands_left(9), Some(1));
+        assert!(tournament.update(10, now));
+        assert_eq!(tournament.current_level().big_blind, 30);
+        assert_eq!(tournament.hands_left(10), Some(10));
+    }
+
+    #[test]
+    fn test_levels_advance_by_time() {
+        let schedule = BlindSchedule::from_json(
+            r#"{ "levels": [
+                { "small_blind": 10, "big_blind": 20, "minutes": 2 },
+                { "small_blind": 20, "big_blind": 40 }
+            ] }"#,
+        )
+        .unwrap();
+        let mut tournament = Tournament::new(schedule);
+        let start = tournament.level_started_at;
+        assert_eq!(tournament.hands_left(50), None);
+        assert!(!tournament.update(3, start + Duration::from_secs(90)));
+        assert_eq!(
+            tournament.time_left(start + Duration::from_secs(90)),
+            Some(Duration::from_secs(30))
+        );
+        assert!(tournament.update(4, start + Duration::from_secs(120)));
+        assert!(tournament.is_last_level());
+        // The last level never ends
+        assert!(!tournament.update(1_000, start + Duration::from_secs(100_000)));
+        assert_eq!(tournament.time_left(start), None);
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(2), "2nd");
+        assert_eq!(ordinal(3), "3rd");
+        assert_eq!(ordinal(4), "4th");
+        assert_eq!(ordinal(11), "11th");
+        assert_eq!(ordinal(22), "22nd");
+    }
+}
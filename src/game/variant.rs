@@ -0,0 +1,91 @@
+//! Game variants: what gets dealt, how hands are ranked and how much can be
+//! bet.
+
+use serde::{Deserialize, Serialize};
+
+use super::deck::Deck;
+use super::hand::Ranking;
+
+/// Cap on the size of a single bet or raise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BettingLimit {
+    /// Anything up to the whole stack.
+    NoLimit,
+    /// Up to the size of the pot, counting the call.
+    PotLimit,
+    /// Fixed bet and raise sizes (the small bet preflop and on the flop,
+    /// double that on the turn and river) with a cap on raises per round.
+    FixedLimit,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameVariant {
+    #[default]
+    NoLimitHoldem,
+    /// Four hole cards, of which exactly two must be used.
+    PotLimitOmaha,
+    LimitHoldem,
+    /// Six-plus Hold'em: no 2-5 in the deck, and a flush beats a full house.
+    ShortDeck,
+}
+
+impl GameVariant {
+    pub fn hole_cards(self) -> usize {
+        match self {
+            GameVariant::NoLimitHoldem | GameVariant::LimitHoldem | GameVariant::ShortDeck => 2,
+            GameVariant::PotLimitOmaha => 4,
+        }
+    }
+
+    pub fn limit(self) -> BettingLimit {
+        match self {
+            GameVariant::NoLimitHoldem | GameVariant::ShortDeck => BettingLimit::NoLimit,
+            GameVariant::PotLimitOmaha => BettingLimit::PotLimit,
+            GameVariant::LimitHoldem => BettingLimit::FixedLimit,
+        }
+    }
+
+    pub fn is_omaha(self) -> bool {
+        self == GameVariant::PotLimitOmaha
+    }
+
+    pub fn is_short_deck(self) -> bool {
+        self == GameVariant::ShortDeck
+    }
+
+    pub fn ranking(self) -> Ranking {
+        if self.is_short_deck() {
+            Ranking::ShortDeck
+        } else {
+            Ranking::Standard
+        }
+    }
+
+    /// A fresh, unshuffled deck for this game.
+    pub fn deck(self) -> Deck {
+        if self.is_short_deck() {
+            Deck::short()
+        } else {
+            Deck::new()
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GameVariant::NoLimitHoldem => "No-Limit Hold'em",
+            GameVariant::PotLimitOmaha => "Pot-Limit Omaha",
+            GameVariant::LimitHoldem => "Limit Hold'em",
+            GameVariant::ShortDeck => "Short Deck Hold'em",
+        }
+    }
+
+    /// Game name as it appears in PokerStars hand histories.
+    pub fn pokerstars_name(self) -> &'static str {
+        match self {
+            GameVariant::NoLimitHoldem => "Hold'em No Limit",
+            GameVariant::PotLimitOmaha => "Omaha Pot Limit",
+            GameVariant::LimitHoldem => "Hold'em Limit",
+            GameVariant::ShortDeck => "6+ Hold'em No Limit",
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! What one player can see of the table: everything public, plus their own
+//! hole cards. Bots decide from a `PlayerView` rather than the `GameState`,
+//! so they can't look at anyone else's cards or the deck: two states that
+//! differ only in those give the same view.
+
+use super::actions::AvailableActions;
+use super::deck::Card;
+use super::history::{ActionRecord, HandOutcome, StreetRecord};
+use super::state::{GamePhase, GameState, Seat};
+use super::variant::GameVariant;
+
+/// A seat as the rest of the table sees it: chips and status, no cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicSeat {
+    pub stack: u32,
+    /// Chips put in on the current street.
+    pub bet: u32,
+    /// Chips put in over the whole hand.
+    pub committed: u32,
+    /// Dealt into the current hand.
+    pub in_hand: bool,
+    pub folded: bool,
+    /// Has acted since the last bet or raise on this street.
+    pub acted: bool,
+}
+
+impl PublicSeat {
+    /// Still contesting the pot.
+    pub fn is_active(&self) -> bool {
+        self.in_hand && !self.folded
+    }
+}
+
+impl From<&Seat> for PublicSeat {
+    fn from(seat: &Seat) -> Self {
+        Self {
+            stack: seat.stack,
+            bet: seat.bet,
+            committed: seat.committed,
+            in_hand: seat.in_hand,
+            folded: seat.folded,
+            acted: seat.acted,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerView {
+    /// The seat this view belongs to.
+    pub seat: usize,
+    pub phase: GamePhase,
+    pub variant: GameVariant,
+    /// This seat's own hole cards.
+    pub hole_cards: Vec<Card>,
+    pub board: Vec<Card>,
+    pub pot: u32,
+    pub small_blind: u32,
+    pub big_blind: u32,
+    pub ante: u32,
+    pub seats: Vec<PublicSeat>,
+    pub button: usize,
+    pub to_act: usize,
+    pub last_aggressor: Option<usize>,
+    pub preflop_aggressor: Option<usize>,
+    pub hand_number: u32,
+    /// Chips this seat needs to put in to call.
+    pub to_call: u32,
+    /// This seat's options; only meaningful when it is the one to act.
+    pub available: AvailableActions,
+    /// This seat acts after every other active seat postflop.
+    pub in_position: bool,
+    /// Other seats still contesting the pot.
+    pub opponents_in_hand: usize,
+    /// Every action so far this hand, street by street.
+    pub streets: Vec<StreetRecord>,
+    /// Hole cards turned over at showdown, by seat.
+    pub shown_cards: Vec<(usize, Vec<Card>)>,
+    /// How the hand ended, once it has.
+    pub outcome: Option<HandOutcome>,
+}
+
+impl PlayerView {
+    pub fn new(state: &GameState, seat: usize) -> Self {
+        let available = if seat == state.to_act {
+            state.available_actions()
+        } else {
+            AvailableActions::new(0, 0, 0, 0, 0)
+        };
+        Self {
+            seat,
+            phase: state.phase,
+            variant: state.variant,
+            hole_cards: state.seats[seat].cards.clone(),
+            board: state.board.clone(),
+            pot: state.pot,
+            small_blind: state.small_blind,
+            big_blind: state.big_blind,
+            ante: state.ante,
+            seats: state.seats.iter().map(PublicSeat::from).collect(),
+            button: state.button,
+            to_act: state.to_act,
+            last_aggressor: state.last_aggressor,
+            preflop_aggressor: state.preflop_aggressor,
+            hand_number: state.hand_number,
+            to_call: state.amount_to_call(seat),
+            available,
+            in_position: state.is_in_position(seat),
+            opponents_in_hand: state.opponents_in_hand(seat),
+            streets: state.current_hand.streets.clone(),
+            shown_cards: shown_cards(state),
+            outcome: state.current_hand.result.clone(),
+        }
+    }
+
+    /// This seat's remaining chips.
+    pub fn stack(&self) -> u32 {
+        self.seats[self.seat].stack
+    }
+
+    /// Chips this seat has put in on the current street.
+    pub fn bet(&self) -> u32 {
+        self.seats[self.seat].bet
+    }
+
+    /// The largest bet on the current street.
+    pub fn max_bet(&self) -> u32 {
+        self.seats.iter().map(|s| s.bet).max().unwrap_or(0)
+    }
+
+    /// Actions on the current street so far.
+    pub fn street_actions(&self) -> &[ActionRecord] {
+        self.streets
+            .last()
+            .map(|street| street.actions.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+/// Cards of the seats that went to showdown. Nothing is shown before then,
+/// or when the hand ended with a fold.
+fn shown_cards(state: &GameState) -> Vec<(usize, Vec<Card>)> {
+    match (&state.phase, &state.showdown_result) {
+        (GamePhase::Showdown, Some(result)) => result
+            .hands
+            .iter()
+            .map(|&(seat, _)| (seat, state.seats[seat].cards.clone()))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::Action;
+    use crate::game::state::HUMAN;
+
+    #[test]
+    fn test_view_has_own_cards_and_table() {
+        let state = GameState::with_seed(100, 3);
+        let view = PlayerView::new(&state, 1);
+        assert_eq!(view.hole_cards, state.seats[1].cards);
+        assert_eq!(view.seats.len(), state.seats.len());
+        assert_eq!(view.stack(), state.seats[1].stack);
+        assert_eq!(view.to_call, state.amount_to_call(1));
+    }
+
+    #[test]
+    fn test_hidden_cards_do_not_change_the_view() {
+        let state = GameState::with_seed(100, 3);
+        let mut other = state.clone();
+        // Deal the human different cards, which also moves the deck on
+        other.seats[HUMAN].cards = other.deck.deal_n(2);
+        assert_ne!(other.seats[HUMAN].cards, state.seats[HUMAN].cards);
+        assert_eq!(PlayerView::new(&other, 1), PlayerView::new(&state, 1));
+        assert_ne!(PlayerView::new(&other, HUMAN), PlayerView::new(&state, HUMAN));
+    }
+
+    #[test]
+    fn test_actions_are_recorded() {
+        let mut state = GameState::with_seed(100, 3);
+        let seat = state.to_act;
+        state.apply_action(seat, Action::Call(state.amount_to_call(seat)));
+        let view = PlayerView::new(&state, 1);
+        let actions = view.street_actions();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].seat, seat);
+        assert!(matches!(actions[0].action, Action::Call(_)));
+    }
+
+    #[test]
+    fn test_only_showdown_hands_are_shown() {
+        let mut folded = GameState::with_seed(100, 3);
+        folded.apply_action(folded.to_act, Action::Fold);
+        let view = PlayerView::new(&folded, 1);
+        assert!(view.shown_cards.is_empty());
+        assert!(view.outcome.is_some());
+
+        let mut shown = GameState::with_seed(100, 3);
+        while !matches!(shown.phase, GamePhase::Showdown | GamePhase::HandComplete) {
+            let to_call = shown.amount_to_call(shown.to_act);
+            let action = if to_call > 0 {
+                Action::Call(to_call)
+            } else {
+                Action::Check
+            };
+            shown.apply_action(shown.to_act, action);
+        }
+        let view = PlayerView::new(&shown, 1);
+        assert_eq!(
+            view.shown_cards,
+            vec![(0, shown.seats[0].cards.clone()), (1, shown.seats[1].cards.clone())]
+        );
+    }
+}
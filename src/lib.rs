@@ -1,4 +1,7 @@
 pub mod bot;
+pub mod config;
 pub mod game;
+pub mod session;
+pub mod solver;
 pub mod stats;
 pub mod ui;
@@ -1,27 +1,50 @@
-mod bot;
-mod game;
-mod stats;
-mod ui;
-
+use std::fs;
 use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use clap::Parser;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use ratatui::{backend::CrosstermBackend, Terminal};
 
-use game::state::GamePhase;
-use stats::persistence::StatsStore;
-use ui::app::App;
+use terminal_poker::bot::external::{ExternalBot, DEFAULT_TIMEOUT};
+use terminal_poker::bot::personality::Personality;
+use terminal_poker::bot::simulation::Simulation;
+use terminal_poker::bot::{BotKind, Strategy};
+use terminal_poker::config::ConfigFile;
+use terminal_poker::game::deck::{parse_cards, Card};
+use terminal_poker::game::equity::{EquityCalculator, DEFAULT_ITERATIONS};
+use terminal_poker::game::export;
+use terminal_poker::game::hand::evaluate_hand_with;
+use terminal_poker::game::pushfold::{self, MAX_STACK_BB};
+use terminal_poker::game::range::Range;
+use terminal_poker::game::state::{GameConfig, GamePhase};
+use terminal_poker::game::tournament::BlindSchedule;
+use terminal_poker::game::variant::GameVariant;
+use terminal_poker::session::{SavedSession, SessionStore};
+use terminal_poker::solver;
+use terminal_poker::solver::cache::SolutionCache;
+use terminal_poker::solver::report;
+use terminal_poker::solver::tree::BetSizes;
+use terminal_poker::stats::persistence::{HandHistoryStore, StatsStore};
+use terminal_poker::ui;
+use terminal_poker::ui::app::App;
 
 #[derive(Parser, Debug)]
 #[command(name = "terminal-poker")]
-#[command(about = "A heads-up No-Limit Texas Hold'em training tool")]
+#[command(about = "A No-Limit Texas Hold'em training tool, heads-up or against a table of bots")]
 #[command(version)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Starting stack size in big blinds
     #[arg(long, default_value = "100", value_parser = clap::value_parser!(u32).range(2..))]
     stack: u32,
@@ -29,6 +52,321 @@ struct Args {
     /// Bot aggression level (0.0 = passive, 1.0 = aggressive)
     #[arg(long, default_value = "0.5", value_parser = parse_aggression, allow_negative_numbers = true)]
     aggression: f64,
+
+    /// Seed for the card and bot RNG; the same seed replays the same session
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Game to play
+    #[arg(long, value_enum, default_value_t = Game::Nlhe)]
+    game: Game,
+
+    /// Bot to play against
+    #[arg(long, value_enum, default_value_t = Bot::RuleBased)]
+    bot: Bot,
+
+    /// Play against a type of player instead of setting --bot and --aggression
+    #[arg(long, value_enum, conflicts_with_all = ["bot", "aggression"])]
+    opponent: Option<Opponent>,
+
+    /// Play against a program of your own, started with this shell command
+    /// for each opponent seat; it plays over stdin and stdout in JSON lines
+    #[arg(long, value_name = "COMMAND", conflicts_with_all = ["bot", "aggression", "opponent", "resume"])]
+    bot_cmd: Option<String>,
+
+    /// How long the --bot-cmd program gets to answer, in milliseconds
+    #[arg(long, value_name = "MS", requires = "bot_cmd", value_parser = clap::value_parser!(u64).range(1..))]
+    bot_timeout: Option<u64>,
+
+    /// Number of seats at the table, including yours (2 = heads-up)
+    #[arg(long, default_value = "2", value_parser = clap::value_parser!(u8).range(2..=9))]
+    seats: u8,
+
+    /// Play a sit-and-go tournament with rising blinds instead of a cash game
+    #[arg(long)]
+    tournament: bool,
+
+    /// Blind structure file (JSON) for tournament mode; implies --tournament
+    #[arg(long, value_name = "FILE", value_parser = parse_structure)]
+    structure: Option<BlindSchedule>,
+
+    /// Small blind in chips (default: half the big blind)
+    #[arg(long, value_name = "CHIPS")]
+    small_blind: Option<u32>,
+
+    /// Big blind in chips (default: 2)
+    #[arg(long, value_name = "CHIPS", value_parser = clap::value_parser!(u32).range(1..))]
+    big_blind: Option<u32>,
+
+    /// Ante in chips, posted by every player (default: 0)
+    #[arg(long, value_name = "CHIPS")]
+    ante: Option<u32>,
+
+    /// Big-blind ante: the big blind posts the ante for the whole table
+    #[arg(long)]
+    bb_ante: bool,
+
+    /// Pick up the session saved when you last quit (its table settings
+    /// are used instead of the options above)
+    #[arg(long)]
+    resume: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Export recorded hand histories for use in external tools
+    Export {
+        /// Hand history format to write
+        #[arg(long, value_enum, default_value_t = ExportFormat::Pokerstars)]
+        format: ExportFormat,
+
+        /// File to write the exported hands to
+        #[arg(short, long, default_value = "hand_history.txt")]
+        output: PathBuf,
+    },
+    /// Win and tie chances of hands, or two ranges, against each other
+    Equity {
+        /// Hole cards such as AsKs, or (heads-up) a range such as "TT+,AQo+"
+        #[arg(required = true, num_args = 2..)]
+        hands: Vec<String>,
+
+        /// Board cards dealt so far, e.g. 2s7s9h
+        #[arg(long, default_value = "")]
+        board: String,
+
+        /// Game the hands are played in
+        #[arg(long, value_enum, default_value_t = Game::Nlhe)]
+        game: Game,
+
+        /// Runouts to sample when there are too many to deal them all out
+        #[arg(long, default_value_t = DEFAULT_ITERATIONS, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Seed for sampled runouts, to get the same result every time
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Heads-up push/fold charts for stacks up to 20BB, or a quiz on them
+    PushFold {
+        /// Show the ranges at this effective stack in big blinds, rather
+        /// than the largest stack each hand is played at
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=MAX_STACK_BB as i64))]
+        stack: Option<u32>,
+
+        /// Quiz yourself on the charts (at --stack, or random stacks)
+        #[arg(long)]
+        quiz: bool,
+
+        /// Questions to ask in the quiz
+        #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+        questions: u32,
+
+        /// Seed for the quiz hands, to get the same questions every time
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Solve a heads-up spot for a strategy close to an equilibrium
+    Solve {
+        #[command(subcommand)]
+        spot: Spot,
+    },
+    /// Play two bots against each other heads-up, with no table to watch,
+    /// and report how they did
+    Simulate {
+        /// Hands to play
+        #[arg(long, default_value_t = 10_000, value_parser = clap::value_parser!(u64).range(1..))]
+        hands: u64,
+
+        /// Bot A: a --bot name with an aggression, e.g. rule:0.3 or equity
+        /// (0.5 if left out), an --opponent type such as nit, or
+        /// cmd:<COMMAND> for a program of your own
+        #[arg(long, value_name = "BOT", value_parser = parse_contender)]
+        a: Contender,
+
+        /// Bot B, in the same form as bot A
+        #[arg(long, value_name = "BOT", value_parser = parse_contender)]
+        b: Contender,
+
+        /// Play every deal twice, the second time with the bots in each
+        /// other's seats, to cancel out most of the luck of the cards
+        #[arg(long)]
+        duplicate: bool,
+
+        /// Stack in big blinds each hand starts from
+        #[arg(long, default_value = "100", value_parser = clap::value_parser!(u32).range(2..))]
+        stack: u32,
+
+        /// Game to play
+        #[arg(long, value_enum, default_value_t = Game::Nlhe)]
+        game: Game,
+
+        /// Seed for the cards and the bots, to get the same result every time
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum Spot {
+    /// The river, between two ranges
+    River {
+        /// Range of the player out of position, who acts first, e.g. "TT+,AQs+"
+        oop: String,
+
+        /// Range of the player in position
+        ip: String,
+
+        /// The five board cards, e.g. AhKd7c4s2h
+        #[arg(long)]
+        board: String,
+
+        /// Pot at the start of the river, in big blinds
+        #[arg(long, default_value_t = 10.0)]
+        pot: f64,
+
+        /// Effective stack behind, in big blinds
+        #[arg(long, default_value_t = 20.0)]
+        stack: f64,
+
+        /// Bet sizes, in percent of the pot
+        #[arg(long, value_delimiter = ',', default_value = "50,100")]
+        bets: Vec<u32>,
+
+        /// Raise sizes, in percent of the pot after calling (later raises
+        /// can only go all in)
+        #[arg(long, value_delimiter = ',', default_value = "100")]
+        raises: Vec<u32>,
+
+        /// CFR iterations to run
+        #[arg(long, default_value_t = solver::DEFAULT_ITERATIONS, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+    },
+    /// Small blind against big blind preflop: fold, limp, open or jam. Pots
+    /// that aren't all in preflop are checked down, so keep stacks short
+    Preflop {
+        /// Effective stack in big blinds
+        #[arg(long, default_value_t = 15, value_parser = clap::value_parser!(u32).range(2..=40))]
+        stack: u32,
+
+        /// Sizes the small blind can open to, in big blinds
+        #[arg(long, value_delimiter = ',', default_value = "2")]
+        opens: Vec<f64>,
+
+        /// CFR iterations to run
+        #[arg(long, default_value_t = solver::DEFAULT_ITERATIONS, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Game {
+    /// No-Limit Texas Hold'em
+    Nlhe,
+    /// Pot-Limit Omaha
+    Plo,
+    /// Fixed-Limit Texas Hold'em
+    Lhe,
+    /// Short-deck (6+) Hold'em: 36 cards, flush beats full house
+    ShortDeck,
+}
+
+impl From<Game> for GameVariant {
+    fn from(game: Game) -> Self {
+        match game {
+            Game::Nlhe => GameVariant::NoLimitHoldem,
+            Game::Plo => GameVariant::PotLimitOmaha,
+            Game::Lhe => GameVariant::LimitHoldem,
+            Game::ShortDeck => GameVariant::ShortDeck,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Bot {
+    /// Hand-strength rules with preflop charts and board texture reads
+    #[value(alias = "rule")]
+    RuleBased,
+    /// Simulates its equity against your likely range and plays the pot odds
+    Equity,
+    /// The equity bot, adjusting to your stats: lifetime and this session
+    Adaptive,
+    /// Solves heads-up rivers as they come up, and plays short stacks by the
+    /// push/fold charts
+    Solver,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Opponent {
+    /// Plays only premium hands and gives up without them
+    Nit,
+    /// Tight-aggressive: a solid range, played hard
+    Tag,
+    /// Loose-aggressive: lots of hands, lots of pressure
+    Lag,
+    /// Plays too many hands and calls them down
+    CallingStation,
+    /// Raises almost anything, big and often
+    Maniac,
+    /// Sound, middle-of-the-road play
+    BalancedReg,
+}
+
+impl From<Opponent> for Personality {
+    fn from(opponent: Opponent) -> Self {
+        match opponent {
+            Opponent::Nit => Personality::Nit,
+            Opponent::Tag => Personality::Tag,
+            Opponent::Lag => Personality::Lag,
+            Opponent::CallingStation => Personality::CallingStation,
+            Opponent::Maniac => Personality::Maniac,
+            Opponent::BalancedReg => Personality::BalancedReg,
+        }
+    }
+}
+
+impl From<Bot> for BotKind {
+    fn from(bot: Bot) -> Self {
+        match bot {
+            Bot::RuleBased => BotKind::RuleBased,
+            Bot::Equity => BotKind::Equity,
+            Bot::Adaptive => BotKind::Adaptive,
+            Bot::Solver => BotKind::Solver,
+        }
+    }
+}
+
+/// A bot for `poker simulate` to play.
+#[derive(Clone, Debug)]
+enum Contender {
+    Bot(Bot, f64),
+    Opponent(Opponent),
+    Command(String),
+}
+
+impl Contender {
+    fn create(&self, seed: u64) -> io::Result<Box<dyn Strategy>> {
+        Ok(match self {
+            Contender::Bot(bot, aggression) => BotKind::from(*bot).create(*aggression, Some(seed)),
+            Contender::Opponent(opponent) => Personality::from(*opponent).create(Some(seed)),
+            Contender::Command(command) => Box::new(ExternalBot::spawn(command, DEFAULT_TIMEOUT)?),
+        })
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Contender::Bot(bot, aggression) => {
+                format!("{}, aggression {}", BotKind::from(*bot).name(), aggression)
+            }
+            Contender::Opponent(opponent) => Personality::from(*opponent).name().to_string(),
+            Contender::Command(command) => format!("`{command}`"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum ExportFormat {
+    /// PokerStars text format, readable by most trackers
+    Pokerstars,
 }
 
 fn parse_aggression(s: &str) -> Result<f64, String> {
@@ -40,9 +378,112 @@ fn parse_aggression(s: &str) -> Result<f64, String> {
     }
 }
 
+fn parse_contender(s: &str) -> Result<Contender, String> {
+    if let Some(command) = s.strip_prefix("cmd:") {
+        if command.trim().is_empty() {
+            return Err("cmd: needs a command to run".to_string());
+        }
+        return Ok(Contender::Command(command.to_string()));
+    }
+    let (name, aggression) = match s.split_once(':') {
+        Some((name, aggression)) => (name, Some(parse_aggression(aggression)?)),
+        None => (s, None),
+    };
+    if let Ok(bot) = Bot::from_str(name, true) {
+        return Ok(Contender::Bot(bot, aggression.unwrap_or(0.5)));
+    }
+    match Opponent::from_str(name, true) {
+        Ok(_) if aggression.is_some() => Err(format!("'{name}' plays at its own aggression")),
+        Ok(opponent) => Ok(Contender::Opponent(opponent)),
+        Err(_) => Err(format!(
+            "'{name}' is not a bot (rule-based, equity, adaptive, solver), an opponent type (nit, tag, lag, calling-station, maniac, balanced-reg) or cmd:<COMMAND>"
+        )),
+    }
+}
+
+fn parse_structure(s: &str) -> Result<BlindSchedule, String> {
+    BlindSchedule::load(Path::new(s))
+}
+
 fn main() -> io::Result<()> {
     let args = Args::parse();
 
+    if let Some(command) = args.command {
+        return run_command(command);
+    }
+
+    // Command-line blinds override the config file
+    let overrides = ConfigFile {
+        small_blind: args.small_blind,
+        big_blind: args.big_blind,
+        ante: args.ante,
+        big_blind_ante: args.bb_ante.then_some(true),
+    };
+    let stakes = match ConfigFile::load_or_default().stakes(&overrides) {
+        Ok(stakes) => stakes,
+        Err(e) => Args::command()
+            .error(ErrorKind::ValueValidation, format!("invalid blinds: {e}"))
+            .exit(),
+    };
+
+    let sessions = SessionStore::open();
+    let resumed = if args.resume {
+        let loaded = sessions.load().map_err(|e| e.to_string());
+        match loaded.and_then(|saved| saved.map(App::from_save).transpose()) {
+            Ok(Some(app)) => Some(app),
+            Ok(None) => Args::command()
+                .error(ErrorKind::Io, "no saved session to resume")
+                .exit(),
+            Err(e) => Args::command()
+                .error(
+                    ErrorKind::Io,
+                    format!(
+                        "could not load saved session from {}: {e}",
+                        sessions.path().display()
+                    ),
+                )
+                .exit(),
+        }
+    } else {
+        None
+    };
+
+    let tournament = match args.structure {
+        Some(schedule) => Some(schedule),
+        None if args.tournament => Some(BlindSchedule::sit_and_go()),
+        None => None,
+    };
+    let config = GameConfig {
+        seats: args.seats as usize,
+        starting_stack_bb: args.stack,
+        variant: args.game.into(),
+        stakes,
+        tournament,
+    };
+
+    // Start any external bots before the terminal is taken over, so a
+    // command that can't be run is reported plainly
+    let external = args.bot_cmd.as_deref().map(|command| {
+        let timeout = args
+            .bot_timeout
+            .map_or(DEFAULT_TIMEOUT, Duration::from_millis);
+        let bots: io::Result<Vec<Box<dyn Strategy>>> = (1..args.seats)
+            .map(|_| {
+                ExternalBot::spawn(command, timeout).map(|bot| Box::new(bot) as Box<dyn Strategy>)
+            })
+            .collect();
+        bots.map_err(|e| e.to_string())
+            .and_then(|bots| App::with_strategies(config.clone(), bots, args.seed))
+            .unwrap_or_else(|e| {
+                Args::command()
+                    .error(
+                        ErrorKind::Io,
+                        format!("could not start bot `{command}`: {e}"),
+                    )
+                    .exit()
+            })
+    });
+
     // Set up panic hook to restore terminal state on panic
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
@@ -67,11 +508,37 @@ fn main() -> io::Result<()> {
     let mut stats_store = StatsStore::load_or_create();
 
     // Create app state
-    let mut app = App::new(args.stack, args.aggression);
-    app.initialize(&mut stats_store);
+    let mut app = match resumed {
+        Some(mut app) => {
+            app.resume(&mut stats_store);
+            app
+        }
+        None => {
+            let mut app = match (external, args.opponent) {
+                (Some(app), _) => app,
+                (None, Some(opponent)) => App::with_opponent(config, opponent.into(), args.seed),
+                (None, None) => App::with_bot(config, args.bot.into(), args.aggression, args.seed),
+            };
+            app.initialize(&mut stats_store);
+            app
+        }
+    };
+
+    // Every completed hand is appended to the history file as it finishes
+    let history = HandHistoryStore::open();
+    let mut history_error = None;
+    // Set when the player quits a session that isn't over
+    let mut saved = None;
 
     // Main game loop
-    let result = run_game_loop(&mut terminal, &mut app, &mut stats_store);
+    let result = run_game_loop(
+        &mut terminal,
+        &mut app,
+        &mut stats_store,
+        &history,
+        &mut history_error,
+        &mut saved,
+    );
 
     // Restore terminal
     disable_raw_mode()?;
@@ -85,13 +552,295 @@ fn main() -> io::Result<()> {
     // Save stats on exit
     stats_store.save();
 
+    if let Some(e) = history_error {
+        eprintln!(
+            "Warning: Could not write hand history to {}: {}",
+            history.path().display(),
+            e
+        );
+    }
+
+    // Keep an unfinished session for --resume, and drop the save once a
+    // session has been played out. An external bot can't be brought back,
+    // so a session against one leaves any earlier save alone.
+    if result.is_ok() && !app.can_save() {
+        if !app.game_state.is_session_over() {
+            println!("Sessions against --bot-cmd can't be resumed, so this one wasn't saved.");
+        }
+    } else if result.is_ok() {
+        let saving = saved.is_some();
+        let session_result = match saved {
+            Some(ref session) => sessions.save(session),
+            None => sessions.clear(),
+        };
+        match session_result {
+            Ok(()) if saving => println!("Session saved. Run `poker --resume` to continue it."),
+            Ok(()) => {}
+            Err(e) => eprintln!(
+                "Warning: Could not update saved session at {}: {}",
+                sessions.path().display(),
+                e
+            ),
+        }
+    }
+
     result
 }
 
+fn run_command(command: Command) -> io::Result<()> {
+    match command {
+        Command::Export { format, output } => {
+            let history = HandHistoryStore::open();
+            let hands = history.load()?;
+            let text = match format {
+                ExportFormat::Pokerstars => export::to_pokerstars(&hands),
+            };
+            fs::write(&output, text)?;
+            println!("Exported {} hands to {}", hands.len(), output.display());
+            Ok(())
+        }
+        Command::Equity {
+            hands,
+            board,
+            game,
+            iterations,
+            seed,
+        } => {
+            if let Err(e) = run_equity(&hands, &board, game.into(), iterations, seed) {
+                Args::command()
+                    .error(ErrorKind::ValueValidation, e)
+                    .exit();
+            }
+            Ok(())
+        }
+        Command::PushFold {
+            stack,
+            quiz,
+            questions,
+            seed,
+        } => {
+            let mut out = io::stdout().lock();
+            if quiz {
+                let mut rng = match seed {
+                    Some(seed) => StdRng::seed_from_u64(seed),
+                    None => StdRng::from_entropy(),
+                };
+                pushfold::run_quiz(io::stdin().lock(), &mut out, &mut rng, stack, questions)
+            } else {
+                pushfold::write_charts(&mut out, stack)
+            }
+        }
+        Command::Solve { spot } => {
+            let cache = SolutionCache::open();
+            let mut out = io::stdout().lock();
+            match spot {
+                Spot::River {
+                    oop,
+                    ip,
+                    board,
+                    pot,
+                    stack,
+                    bets,
+                    raises,
+                    iterations,
+                } => {
+                    let sizes = BetSizes {
+                        bets: bets.iter().map(|&b| b as f64 / 100.0).collect(),
+                        raises: raises.iter().map(|&r| r as f64 / 100.0).collect(),
+                    };
+                    let spot = report::river_spot([&oop, &ip], &board, pot, stack, sizes)
+                        .unwrap_or_else(|e| {
+                            Args::command().error(ErrorKind::ValueValidation, e).exit()
+                        });
+                    report::river(&mut out, &cache, &spot, iterations)
+                }
+                Spot::Preflop {
+                    stack,
+                    opens,
+                    iterations,
+                } => {
+                    let spot = report::preflop_spot(stack, opens).unwrap_or_else(|e| {
+                        Args::command().error(ErrorKind::ValueValidation, e).exit()
+                    });
+                    report::preflop(&mut out, &cache, &spot, iterations)
+                }
+            }
+        }
+        Command::Simulate {
+            hands,
+            a,
+            b,
+            duplicate,
+            stack,
+            game,
+            seed,
+        } => {
+            let config = GameConfig {
+                variant: game.into(),
+                ..GameConfig::heads_up(stack)
+            };
+            run_simulation(hands, [&a, &b], duplicate, config, seed)
+        }
+    }
+}
+
+/// Play out `hands` between bots A and B and print the report.
+fn run_simulation(
+    hands: u64,
+    contenders: [&Contender; 2],
+    duplicate: bool,
+    config: GameConfig,
+    seed: Option<u64>,
+) -> io::Result<()> {
+    let seed = seed.unwrap_or_else(rand::random);
+    let mut master = StdRng::seed_from_u64(seed);
+    let sim_seed = master.gen();
+    let mut bots = Vec::new();
+    for contender in contenders {
+        match contender.create(master.gen()) {
+            Ok(bot) => bots.push(bot),
+            Err(e) => Args::command()
+                .error(
+                    ErrorKind::Io,
+                    format!("could not start bot {}: {e}", contender.label()),
+                )
+                .exit(),
+        }
+    }
+    let bots: [Box<dyn Strategy>; 2] = bots.try_into().unwrap_or_else(|_| unreachable!());
+
+    let mut simulation = Simulation::new(config, bots, sim_seed, duplicate);
+    simulation.play(hands);
+    let labels = contenders.map(Contender::label);
+    simulation
+        .report()
+        .write(&mut io::stdout().lock(), [&labels[0], &labels[1]], seed)
+}
+
+/// One player's cards in `poker equity`: exact hole cards, or a range.
+enum Holding {
+    Hand(Vec<Card>),
+    Range(Range),
+}
+
+impl Holding {
+    fn parse(text: &str, variant: GameVariant) -> Result<Self, String> {
+        let hole_cards = variant.hole_cards();
+        let card_error = match parse_cards(text) {
+            Ok(cards) if cards.len() == hole_cards => return Ok(Holding::Hand(cards)),
+            Ok(cards) => format!("{} cards, not {}", cards.len(), hole_cards),
+            Err(e) => e,
+        };
+        if hole_cards != 2 {
+            return Err(format!("'{}' is not a hand: {}", text, card_error));
+        }
+        Range::parse(text).map(Holding::Range).map_err(|range_error| {
+            format!(
+                "'{}' is neither a hand ({}) nor a range ({})",
+                text, card_error, range_error
+            )
+        })
+    }
+
+    /// Every combo the player could hold, leaving out cards the game
+    /// doesn't deal.
+    fn combos(&self, variant: GameVariant) -> Vec<(Vec<Card>, f64)> {
+        match self {
+            Holding::Hand(cards) => vec![(cards.clone(), 1.0)],
+            Holding::Range(range) => {
+                let deck = variant.deck().deal_n(52);
+                range
+                    .combos()
+                    .into_iter()
+                    .filter(|(cards, _)| cards.iter().all(|card| deck.contains(card)))
+                    .map(|(cards, weight)| (cards.to_vec(), weight))
+                    .collect()
+            }
+        }
+    }
+}
+
+fn run_equity(
+    hands: &[String],
+    board: &str,
+    variant: GameVariant,
+    iterations: u32,
+    seed: Option<u64>,
+) -> Result<(), String> {
+    let board = parse_cards(board).map_err(|e| format!("invalid board: {e}"))?;
+    let holdings = hands
+        .iter()
+        .map(|text| Holding::parse(text, variant))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut calculator = match seed {
+        Some(seed) => EquityCalculator::with_seed(variant, iterations, seed),
+        None => EquityCalculator::new(variant, iterations),
+    };
+
+    let exact: Vec<Vec<Card>> = holdings
+        .iter()
+        .filter_map(|holding| match holding {
+            Holding::Hand(cards) => Some(cards.clone()),
+            Holding::Range(_) => None,
+        })
+        .collect();
+    let equities = if exact.len() == holdings.len() {
+        calculator.hands(&exact, &board)?
+    } else if let [hero, villain] = holdings.as_slice() {
+        calculator
+            .range_vs_range(&hero.combos(variant), &villain.combos(variant), &board)?
+            .to_vec()
+    } else {
+        return Err("ranges can only be run heads-up, against one other hand or range".to_string());
+    };
+
+    let board_text: Vec<String> = board.iter().map(Card::to_string).collect();
+    if board.is_empty() {
+        println!("Board: none");
+    } else {
+        println!("Board: {}", board_text.join(" "));
+    }
+    for ((text, holding), equity) in hands.iter().zip(&holdings).zip(&equities) {
+        let (name, note) = match holding {
+            Holding::Hand(cards) => (
+                cards.iter().map(Card::to_string).collect(),
+                evaluate_hand_with(cards, &board, variant.ranking()).description,
+            ),
+            Holding::Range(range) => (text.clone(), format!("{} combos", range.len())),
+        };
+        println!(
+            "{:<12} win {:>6.2}%  tie {:>6.2}%  equity {:>6.2}%  {}",
+            name,
+            equity.win * 100.0,
+            equity.tie * 100.0,
+            equity.equity * 100.0,
+            note
+        );
+    }
+    Ok(())
+}
+
+/// Persist hands as soon as they finish. Errors can't be printed while the
+/// TUI owns the terminal, so keep the first one for after exit.
+fn save_finished_hands(
+    app: &mut App,
+    history: &HandHistoryStore,
+    history_error: &mut Option<io::Error>,
+) {
+    for record in app.game_state.take_finished_hands() {
+        if let Err(e) = history.append(&record) {
+            history_error.get_or_insert(e);
+        }
+    }
+}
+
 fn run_game_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
     stats_store: &mut StatsStore,
+    history: &HandHistoryStore,
+    history_error: &mut Option<io::Error>,
+    saved: &mut Option<SavedSession>,
 ) -> io::Result<()> {
     loop {
         app.tick_count = app.tick_count.wrapping_add(1);
@@ -112,10 +861,7 @@ fn run_game_loop(
                     // Record stats unless already recorded (Summary is entered
                     // after 'q' which already calls these)
                     if !matches!(app.game_state.phase, GamePhase::Summary) {
-                        stats_store.record_session_end();
-                        stats_store.record_profit(
-                            (app.game_state.session_profit_bb() * 2.0).round() as i64,
-                        );
+                        *saved = app.quit(stats_store);
                     }
                     break;
                 }
@@ -124,12 +870,12 @@ fn run_game_loop(
                     GamePhase::Showdown => {
                         match key.code {
                             KeyCode::Char('q') | KeyCode::Char('Q') => {
-                                stats_store.record_session_end();
-                                stats_store.record_profit(
-                                    (app.game_state.session_profit_bb() * 2.0).round() as i64,
-                                );
+                                *saved = app.quit(stats_store);
                                 app.game_state.phase = GamePhase::Summary;
                             }
+                            KeyCode::Char('w') | KeyCode::Char('W') => {
+                                app.toggle_reasoning();
+                            }
                             _ => {
                                 app.continue_after_showdown(stats_store);
                             }
@@ -151,10 +897,7 @@ fn run_game_loop(
                     _ => {
                         match key.code {
                             KeyCode::Char('q') | KeyCode::Char('Q') => {
-                                stats_store.record_session_end();
-                                stats_store.record_profit(
-                                    (app.game_state.session_profit_bb() * 2.0).round() as i64,
-                                );
+                                *saved = app.quit(stats_store);
                                 app.game_state.phase = GamePhase::Summary;
                             }
                             KeyCode::Char('?') => {
@@ -163,9 +906,12 @@ fn run_game_loop(
                             KeyCode::Char('s') | KeyCode::Char('S') => {
                                 app.toggle_stats();
                             }
+                            KeyCode::Char('w') | KeyCode::Char('W') => {
+                                app.toggle_reasoning();
+                            }
                             _ => {
                                 // Block gameplay input while events are pending or overlays are open
-                                if !app.has_pending_events() && !app.show_help && !app.show_stats {
+                                if !app.has_pending_events() && !app.overlay_open() {
                                     if let Some(action) = ui::input::handle_key(
                                         key,
                                         &app.game_state,
@@ -182,13 +928,15 @@ fn run_game_loop(
             }
         }
 
+        save_finished_hands(app, history, history_error);
+
         // Check for session end after a fold resolves (showdown path handled by continue_after_showdown)
-        if app.game_state.phase == GamePhase::HandComplete {
-            if app.game_state.player_stack == 0 || app.game_state.bot_stack == 0 {
-                app.game_state.phase = GamePhase::SessionEnd;
-            }
+        if app.game_state.phase == GamePhase::HandComplete && app.game_state.is_session_over() {
+            app.end_session(stats_store);
         }
     }
 
+    // A hand can finish in the same pass as the key that quits
+    save_finished_hands(app, history, history_error);
     Ok(())
 }
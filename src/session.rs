@@ -0,0 +1,138 @@
+//! A session saved on exit so `poker --resume` can carry on where it left
+//! off: the table, the bots, and what the player has already been credited
+//! with in their stats.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bot::BotConfig;
+use crate::game::state::{GameConfig, GameState};
+use crate::stats::persistence::data_path;
+
+const SESSION_FILE: &str = "session.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionLogEntry {
+    pub street: String,
+    pub text: String,
+}
+
+/// What has already been counted towards the player's stats this hand, so
+/// nothing is recorded twice.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandTracking {
+    pub saw_flop: bool,
+    pub recorded_vpip: bool,
+    pub three_bet_opportunity_recorded: bool,
+    pub player_raised_preflop: bool,
+    pub cbet_opportunity_recorded: bool,
+    pub facing_cbet: bool,
+}
+
+/// A bot, and the seed its RNG restarts from.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SavedBot {
+    #[serde(flatten)]
+    pub bot: BotConfig,
+    pub rng_seed: u64,
+}
+
+/// A session saved on exit, for `poker --resume`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSession {
+    pub config: GameConfig,
+    pub game_state: GameState,
+    /// Restarts the card RNG where the saved session left off.
+    pub rng_seed: u64,
+    pub bots: Vec<SavedBot>,
+    pub action_log: Vec<ActionLogEntry>,
+    pub tracking: HandTracking,
+    pub visible_board_len: usize,
+    pub showdown_revealed: bool,
+    /// Session profit already added to the lifetime stats.
+    pub profit_recorded_bb: f64,
+}
+
+/// The session saved on exit for `poker --resume`. There is at most one;
+/// it is removed once the session ends with a player out of chips.
+pub struct SessionStore {
+    path: PathBuf,
+}
+
+impl SessionStore {
+    pub fn open() -> Self {
+        Self::at(data_path(SESSION_FILE))
+    }
+
+    pub fn at(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn save(&self, session: &SavedSession) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string(session)?;
+        fs::write(&self.path, json)
+    }
+
+    /// The saved session, or None if there isn't one.
+    pub fn load(&self) -> io::Result<Option<SavedSession>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        Ok(Some(serde_json::from_str(&contents)?))
+    }
+
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_save_load_and_clear() {
+        let path = std::env::temp_dir().join(format!(
+            "terminal-poker-session-{}.json",
+            std::process::id()
+        ));
+        let store = SessionStore::at(path.clone());
+        assert!(store.load().unwrap().is_none());
+
+        let config = GameConfig::heads_up(100);
+        let saved = SavedSession {
+            game_state: GameState::with_seed(100, 3),
+            config,
+            rng_seed: 3,
+            bots: Vec::new(),
+            action_log: Vec::new(),
+            tracking: HandTracking::default(),
+            visible_board_len: 0,
+            showdown_revealed: false,
+            profit_recorded_bb: 0.0,
+        };
+        store.save(&saved).unwrap();
+        let loaded = store.load().unwrap().unwrap();
+        assert_eq!(loaded.rng_seed, saved.rng_seed);
+        assert_eq!(loaded.game_state.hand_number, 1);
+
+        store.clear().unwrap();
+        assert!(store.load().unwrap().is_none());
+        store.clear().unwrap();
+    }
+}
@@ -0,0 +1,100 @@
+//! Solutions saved to disk, so each spot only has to be solved once. Each
+//! is stored under the data directory as `solver/<hash of its key>.json`,
+//! along with the key itself to rule out a clash.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::Solution;
+use crate::stats::persistence::data_path;
+
+const CACHE_DIR: &str = "solver";
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    key: String,
+    solution: Solution,
+}
+
+pub struct SolutionCache {
+    dir: PathBuf,
+}
+
+impl SolutionCache {
+    pub fn open() -> Self {
+        Self::at(data_path(CACHE_DIR))
+    }
+
+    pub fn at(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", fnv1a(key)))
+    }
+
+    /// The saved solution for the spot with this key, if there is one.
+    pub fn load(&self, key: &str) -> io::Result<Option<Solution>> {
+        let contents = match fs::read_to_string(self.path(key)) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let entry: Entry = serde_json::from_str(&contents)?;
+        Ok((entry.key == key).then_some(entry.solution))
+    }
+
+    pub fn save(&self, key: &str, solution: &Solution) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let entry = Entry {
+            key: key.to_string(),
+            solution: solution.clone(),
+        };
+        fs::write(self.path(key), serde_json::to_string(&entry)?)
+    }
+}
+
+/// FNV-1a, which unlike the standard library's hasher gives the same hash
+/// from one build to the next.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::preflop::PreflopSpot;
+
+    #[test]
+    fn test_save_and_load() {
+        let dir =
+            std::env::temp_dir().join(format!("terminal-poker-solver-{}", std::process::id()));
+        let cache = SolutionCache::at(dir.clone());
+        let spot = PreflopSpot::new(5, vec![2.0]);
+        let key = spot.key();
+        assert!(cache.load(&key).unwrap().is_none());
+
+        let solution = Solution {
+            tree: spot.tree(),
+            strategies: vec![vec![0.25; 4]; spot.tree().decisions],
+        };
+        cache.save(&key, &solution).unwrap();
+        let loaded = cache.load(&key).unwrap();
+        // A different spot that happened to hash the same isn't mistaken
+        // for this one
+        let mut other: Entry =
+            serde_json::from_str(&fs::read_to_string(cache.path(&key)).unwrap()).unwrap();
+        other.key.push('!');
+        fs::write(cache.path(&key), serde_json::to_string(&other).unwrap()).unwrap();
+        let clashed = cache.load(&key).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(loaded, Some(solution));
+        assert_eq!(clashed, None);
+    }
+}
@@ -0,0 +1,332 @@
+//! Counterfactual regret minimisation (CFR) for small heads-up spots: a
+//! river with ranges for both players, or preflop push/fold with a raise
+//! size or two. The betting is cut down to a few sizes (see `tree`), and
+//! the solver finds a mixed strategy for every hand at every decision that
+//! comes close to an equilibrium. The bot samples from these, and players
+//! can check their own decisions against them.
+//!
+//! The solver runs CFR+ over whole ranges at once: each pass walks the
+//! tree with the chance of reaching each node for every hand of both
+//! players, and regrets are kept per hand and action.
+
+pub mod cache;
+pub mod preflop;
+pub mod report;
+pub mod river;
+pub mod tree;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::actions::Action;
+
+use tree::{Node, Tree};
+
+/// Iterations that get a river or preflop spot well within 1% of the pot
+/// of an equilibrium.
+pub const DEFAULT_ITERATIONS: u32 = 500;
+
+/// The hands each player can hold in a spot, and how they fare against
+/// each other.
+pub trait Matchups {
+    /// How likely each of `player`'s hands is at the start of the spot.
+    fn reach(&self, player: usize) -> &[f64];
+
+    /// For each of `player`'s hands, the total of `opponent_reach` over the
+    /// opponent's hands it can be dealt against (without sharing a card),
+    /// and the same total weighted by the hand's share of the pot at
+    /// showdown.
+    fn against(&self, player: usize, opponent_reach: &[f64]) -> (Vec<f64>, Vec<f64>);
+}
+
+/// A mixed strategy for every hand at every decision of a tree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Solution {
+    pub tree: Tree,
+    /// Per decision, how often each hand takes each action: entry
+    /// `hand * actions + action`.
+    strategies: Vec<Vec<f64>>,
+}
+
+impl Solution {
+    /// How often `hand` takes each action at the decision node `node`.
+    pub fn strategy(&self, node: usize, hand: usize) -> &[f64] {
+        let Node::Decision {
+            decision, actions, ..
+        } = self.tree.node(node)
+        else {
+            return &[];
+        };
+        &self.strategies[*decision][hand * actions.len()..(hand + 1) * actions.len()]
+    }
+
+    /// Actions at the decision node `node` with how often `hand` takes them.
+    pub fn actions(&self, node: usize, hand: usize) -> Vec<(Action, f64)> {
+        match self.tree.node(node) {
+            Node::Decision { actions, .. } => actions
+                .iter()
+                .copied()
+                .zip(self.strategy(node, hand).iter().copied())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Run `iterations` of CFR+ on `tree`.
+pub fn solve<M: Matchups>(tree: Tree, matchups: &M, iterations: u32) -> Solution {
+    let mut solver = Solver::new(&tree, matchups);
+    for _ in 0..iterations {
+        solver.iteration += 1;
+        for player in 0..2 {
+            let reach = [matchups.reach(0).to_vec(), matchups.reach(1).to_vec()];
+            solver.walk(0, player, reach);
+        }
+    }
+    let strategies = solver.average();
+    Solution { tree, strategies }
+}
+
+/// How much a best response to each side of `solution` would win beyond
+/// the equilibrium, averaged over the two players, in chips per hand. Zero
+/// at an equilibrium.
+pub fn exploitability<M: Matchups>(solution: &Solution, matchups: &M) -> f64 {
+    let reach = [matchups.reach(0).to_vec(), matchups.reach(1).to_vec()];
+    let (deals, _) = matchups.against(0, &reach[1]);
+    let deals: f64 = deals.iter().zip(&reach[0]).map(|(d, r)| d * r).sum();
+    if deals == 0.0 {
+        return 0.0;
+    }
+    let best: f64 = (0..2)
+        .map(|player| {
+            let values = best_response(solution, matchups, 0, player, &reach[1 - player]);
+            values
+                .iter()
+                .zip(&reach[player])
+                .map(|(v, r)| v * r)
+                .sum::<f64>()
+        })
+        .sum();
+    // The two sides share the pot between them, so together they can't
+    // win more than it at an equilibrium
+    (best / deals - solution.tree.pot as f64) / 2.0
+}
+
+/// What each of `player`'s hands wins from `node` on by playing its best
+/// response to the other side's strategy, times the chance of reaching it.
+fn best_response<M: Matchups>(
+    solution: &Solution,
+    matchups: &M,
+    node: usize,
+    player: usize,
+    opponent_reach: &[f64],
+) -> Vec<f64> {
+    match solution.tree.node(node) {
+        Node::Decision {
+            player: actor,
+            children,
+            ..
+        } => {
+            let hands = matchups.reach(*actor).len();
+            if *actor == player {
+                let mut best = vec![f64::NEG_INFINITY; hands];
+                for &child in children {
+                    let values = best_response(solution, matchups, child, player, opponent_reach);
+                    for (best, value) in best.iter_mut().zip(values) {
+                        *best = best.max(value);
+                    }
+                }
+                best
+            } else {
+                let mut total = vec![0.0; matchups.reach(player).len()];
+                for (action, &child) in children.iter().enumerate() {
+                    let reach: Vec<f64> = (0..hands)
+                        .map(|hand| opponent_reach[hand] * solution.strategy(node, hand)[action])
+                        .collect();
+                    let values = best_response(solution, matchups, child, player, &reach);
+                    for (total, value) in total.iter_mut().zip(values) {
+                        *total += value;
+                    }
+                }
+                total
+            }
+        }
+        terminal => terminal_values(
+            terminal,
+            solution.tree.pot,
+            matchups,
+            player,
+            opponent_reach,
+        ),
+    }
+}
+
+/// What each of `player`'s hands wins at a fold or showdown, over what it
+/// put in this round, times the chance of getting there.
+fn terminal_values<M: Matchups>(
+    node: &Node,
+    pot: u32,
+    matchups: &M,
+    player: usize,
+    opponent_reach: &[f64],
+) -> Vec<f64> {
+    let (deals, shares) = matchups.against(player, opponent_reach);
+    match *node {
+        Node::Fold {
+            player: folder,
+            bets,
+        } => {
+            let won = if folder == player {
+                -(bets[player] as f64)
+            } else {
+                (pot + bets[folder]) as f64
+            };
+            deals.iter().map(|deals| deals * won).collect()
+        }
+        Node::Showdown { bets } => {
+            let total = (pot + bets[0] + bets[1]) as f64;
+            deals
+                .iter()
+                .zip(shares)
+                .map(|(deals, share)| share * total - deals * bets[player] as f64)
+                .collect()
+        }
+        Node::Decision { .. } => unreachable!("not a terminal node"),
+    }
+}
+
+struct Solver<'a, M: Matchups> {
+    tree: &'a Tree,
+    matchups: &'a M,
+    /// Per decision, each hand's regret for not taking each action, laid
+    /// out as in `Solution::strategies`.
+    regrets: Vec<Vec<f64>>,
+    /// Per decision, each hand's strategy summed over the iterations,
+    /// weighted by the iteration and the chance of the hand getting there.
+    totals: Vec<Vec<f64>>,
+    iteration: u32,
+}
+
+impl<'a, M: Matchups> Solver<'a, M> {
+    fn new(tree: &'a Tree, matchups: &'a M) -> Self {
+        let mut regrets = vec![Vec::new(); tree.decisions];
+        for node in &tree.nodes {
+            if let Node::Decision {
+                player,
+                decision,
+                actions,
+                ..
+            } = node
+            {
+                regrets[*decision] = vec![0.0; matchups.reach(*player).len() * actions.len()];
+            }
+        }
+        Self {
+            tree,
+            matchups,
+            totals: regrets.clone(),
+            regrets,
+            iteration: 0,
+        }
+    }
+
+    /// The current strategy at a decision: each action in proportion to
+    /// its positive regret, or all alike if none has any.
+    fn current(&self, decision: usize, actions: usize) -> Vec<f64> {
+        let regrets = &self.regrets[decision];
+        let mut strategy = vec![0.0; regrets.len()];
+        for (hand, regrets) in regrets.chunks(actions).enumerate() {
+            let total: f64 = regrets.iter().map(|r| r.max(0.0)).sum();
+            for (action, regret) in regrets.iter().enumerate() {
+                strategy[hand * actions + action] = if total > 0.0 {
+                    regret.max(0.0) / total
+                } else {
+                    1.0 / actions as f64
+                };
+            }
+        }
+        strategy
+    }
+
+    /// Update `player`'s regrets below `node`, and return what each of
+    /// their hands wins from there on, times the chance of the opponent
+    /// getting there.
+    fn walk(&mut self, node: usize, player: usize, reach: [Vec<f64>; 2]) -> Vec<f64> {
+        let Node::Decision {
+            player: actor,
+            decision,
+            actions,
+            children,
+        } = &self.tree.nodes[node]
+        else {
+            return terminal_values(
+                &self.tree.nodes[node],
+                self.tree.pot,
+                self.matchups,
+                player,
+                &reach[1 - player],
+            );
+        };
+        let (actor, decision, count) = (*actor, *decision, actions.len());
+        let strategy = self.current(decision, count);
+
+        let mut child_values = Vec::with_capacity(count);
+        for (action, &child) in children.iter().enumerate() {
+            let mut next = reach.clone();
+            for (hand, reach) in next[actor].iter_mut().enumerate() {
+                *reach *= strategy[hand * count + action];
+            }
+            child_values.push(self.walk(child, player, next));
+        }
+
+        let hands = reach[player].len();
+        if actor != player {
+            return (0..hands)
+                .map(|hand| child_values.iter().map(|values| values[hand]).sum())
+                .collect();
+        }
+
+        let weight = self.iteration as f64;
+        let mut values = vec![0.0; hands];
+        for hand in 0..hands {
+            let mixed: f64 = child_values
+                .iter()
+                .enumerate()
+                .map(|(action, child)| strategy[hand * count + action] * child[hand])
+                .sum();
+            values[hand] = mixed;
+            for (action, child) in child_values.iter().enumerate() {
+                let index = hand * count + action;
+                // CFR+: regrets never go below zero, and later iterations
+                // count for more in the average
+                let regret = &mut self.regrets[decision][index];
+                *regret = (*regret + child[hand] - mixed).max(0.0);
+                self.totals[decision][index] += weight * reach[player][hand] * strategy[index];
+            }
+        }
+        values
+    }
+
+    /// The average strategy over all iterations, which is what converges.
+    fn average(&self) -> Vec<Vec<f64>> {
+        let mut strategies = self.totals.clone();
+        for node in &self.tree.nodes {
+            let Node::Decision {
+                decision, actions, ..
+            } = node
+            else {
+                continue;
+            };
+            for hand in strategies[*decision].chunks_mut(actions.len()) {
+                let total: f64 = hand.iter().sum();
+                for share in hand.iter_mut() {
+                    *share = if total > 0.0 {
+                        *share / total
+                    } else {
+                        1.0 / actions.len() as f64
+                    };
+                }
+            }
+        }
+        strategies
+    }
+}
@@ -0,0 +1,157 @@
+//! Heads-up preflop at short stacks: the small blind folds, limps, opens to
+//! a set size or moves all in, and the big blind answers. A pot that isn't
+//! all in by the end of the betting is checked down, so the stacks behind
+//! never come into play: a fair model only when they are short.
+
+use crate::game::pushfold::{Equities, HANDS};
+
+use super::tree::{BetSizes, Start, Tree};
+use super::{solve, Matchups, Solution};
+
+/// Random deals per matchup when estimating the starting hand equities.
+pub const EQUITY_SAMPLES: u32 = 500;
+
+/// Chips in a big blind. Two, so the small blind is a whole chip.
+pub const BIG_BLIND: u32 = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreflopSpot {
+    /// Effective stack in big blinds, counting the blinds.
+    pub stack_bb: u32,
+    /// Sizes the small blind can open to, in big blinds.
+    pub opens: Vec<f64>,
+}
+
+impl PreflopSpot {
+    pub fn new(stack_bb: u32, opens: Vec<f64>) -> Self {
+        Self { stack_bb, opens }
+    }
+
+    pub fn tree(&self) -> Tree {
+        let stack = self.stack_bb.max(1) * BIG_BLIND;
+        let start = Start {
+            pot: 0,
+            bets: [BIG_BLIND / 2, BIG_BLIND],
+            stacks: [stack - BIG_BLIND / 2, stack - BIG_BLIND],
+            to_act: 0,
+            acted: [false, false],
+            last_raise: BIG_BLIND,
+            raises: 0,
+            big_blind: BIG_BLIND,
+        };
+        // An open to `x` big blinds raises by `x - 1` into the two big
+        // blinds there are after the small blind calls
+        let sizes = BetSizes {
+            bets: Vec::new(),
+            raises: self.opens.iter().map(|open| (open - 1.0) / 2.0).collect(),
+        };
+        Tree::build(start, &sizes)
+    }
+
+    /// Solve with `equities` between the starting hands, indexed as in
+    /// `pushfold::hand_index`. Player 0 is the small blind.
+    pub fn solve(&self, equities: &Equities, iterations: u32) -> Solution {
+        solve(self.tree(), &PreflopMatchups::new(equities), iterations)
+    }
+
+    /// Text that tells this spot apart from any other, for caching its
+    /// solution.
+    pub fn key(&self) -> String {
+        format!(
+            "preflop {}bb opens {:?} samples {}",
+            self.stack_bb, self.opens, EQUITY_SAMPLES
+        )
+    }
+}
+
+/// The 169 starting hands against each other, dealt as often as they can
+/// be.
+pub struct PreflopMatchups<'a> {
+    equities: &'a Equities,
+    reach: Vec<f64>,
+}
+
+impl<'a> PreflopMatchups<'a> {
+    pub fn new(equities: &'a Equities) -> Self {
+        Self {
+            equities,
+            reach: vec![1.0; HANDS],
+        }
+    }
+}
+
+impl Matchups for PreflopMatchups<'_> {
+    fn reach(&self, _player: usize) -> &[f64] {
+        &self.reach
+    }
+
+    fn against(&self, _player: usize, opponent_reach: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let mut deals = vec![0.0; HANDS];
+        let mut shares = vec![0.0; HANDS];
+        for hand in 0..HANDS {
+            for (other, &reach) in opponent_reach.iter().enumerate() {
+                let dealt = self.equities.combos(hand, other) * reach;
+                deals[hand] += dealt;
+                shares[hand] += dealt * self.equities.equity(hand, other);
+            }
+        }
+        (deals, shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::Action;
+    use crate::game::deck::parse_cards;
+    use crate::game::pushfold::hand_index;
+    use crate::solver::exploitability;
+    use crate::solver::tree::Node;
+
+    /// Equities where the higher index loses: a made-up order of hands,
+    /// quick to build.
+    fn ordered_equities() -> Equities {
+        Equities::from_fn(|a, b| match a.cmp(&b) {
+            std::cmp::Ordering::Less => 0.7,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Greater => 0.3,
+        })
+    }
+
+    #[test]
+    fn test_tree_has_the_open_sizes() {
+        let tree = PreflopSpot::new(10, vec![2.0, 3.0]).tree();
+        let Node::Decision { actions, .. } = tree.root() else {
+            unreachable!()
+        };
+        assert_eq!(
+            actions,
+            &[
+                Action::Fold,
+                Action::Call(1),
+                Action::Raise(4),
+                Action::Raise(6),
+                Action::AllIn(20)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_push_fold_jams_the_best_hands() {
+        let equities = ordered_equities();
+        let spot = PreflopSpot::new(10, Vec::new());
+        let matchups = PreflopMatchups::new(&equities);
+        let solution = solve(spot.tree(), &matchups, 100);
+        assert!(exploitability(&solution, &matchups) < 0.05 * BIG_BLIND as f64);
+
+        let hand = |cards: &str| {
+            let cards = parse_cards(cards).unwrap();
+            hand_index(cards[0], cards[1])
+        };
+        // Fold, limp or jam: aces mostly jam, limping now and then to
+        // trap, and the worst hand folds
+        let aces = solution.strategy(0, hand("AsAh"));
+        assert!(aces[0] < 0.01 && aces[2] > 0.5, "{aces:?}");
+        assert!(solution.strategy(0, HANDS - 1)[0] > 0.9);
+    }
+}
@@ -0,0 +1,380 @@
+//! What `poker solve` prints: the spot, then each player's strategy as
+//! tables (the river) or starting hand grids (preflop). Solutions come
+//! from the cache when the spot has been solved before.
+
+use std::cmp::Reverse;
+use std::io::{self, Write};
+
+use crate::game::actions::{format_bb, Action};
+use crate::game::deck::{parse_cards, Card};
+use crate::game::evaluator::evaluate;
+use crate::game::hand::Ranking;
+use crate::game::pushfold;
+use crate::game::range::Range;
+use crate::game::state::Stakes;
+
+use super::cache::SolutionCache;
+use super::preflop::{self, PreflopSpot};
+use super::river::RiverSpot;
+use super::tree::{BetSizes, Node, Start};
+use super::{exploitability, Matchups, Solution};
+
+/// The river spot for `poker solve river`: ranges for the player out of
+/// position and the one in position, a five-card board, and the pot and
+/// stack in big blinds.
+pub fn river_spot(
+    ranges: [&str; 2],
+    board: &str,
+    pot_bb: f64,
+    stack_bb: f64,
+    sizes: BetSizes,
+) -> Result<RiverSpot, String> {
+    let board = parse_cards(board).map_err(|e| format!("invalid board: {e}"))?;
+    let ranges = ranges
+        .iter()
+        .map(|text| Range::parse(text).map(|range| range.combos()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let [oop, ip]: [_; 2] = ranges.try_into().expect("two ranges");
+    if pot_bb <= 0.0 || stack_bb < 0.0 {
+        return Err("the pot must be more than 0 and the stack at least 0".to_string());
+    }
+    let big_blind = Stakes::default().big_blind;
+    let chips = |bb: f64| (bb * big_blind as f64).round() as u32;
+    let start = Start::new(chips(pot_bb), chips(stack_bb), big_blind);
+    RiverSpot::new(&board, Ranking::Standard, [oop, ip], start, sizes)
+}
+
+/// The preflop spot for `poker solve preflop`, with the small blind
+/// opening to each of `opens` big blinds.
+pub fn preflop_spot(stack_bb: u32, opens: Vec<f64>) -> Result<PreflopSpot, String> {
+    if let Some(open) = opens
+        .iter()
+        .find(|&&open| open < 2.0 || open >= stack_bb as f64)
+    {
+        return Err(format!(
+            "an open to {}BB must be at least 2BB and less than the {}BB stack",
+            open, stack_bb
+        ));
+    }
+    Ok(PreflopSpot::new(stack_bb, opens))
+}
+
+/// Solve `spot`, or load it from `cache`, and write both players'
+/// strategies: the first decision, then the answer to each action.
+pub fn river(
+    out: &mut impl Write,
+    cache: &SolutionCache,
+    spot: &RiverSpot,
+    iterations: u32,
+) -> io::Result<()> {
+    let solution = cached_solution(cache, &spot.key(), || spot.solve(iterations));
+    write_river(out, spot, &solution)
+}
+
+/// Solve `spot`, or load it from `cache`, and write a grid of the small
+/// blind's plays and one of the big blind's answers to each of them.
+pub fn preflop(
+    out: &mut impl Write,
+    cache: &SolutionCache,
+    spot: &PreflopSpot,
+    iterations: u32,
+) -> io::Result<()> {
+    let solution = cached_solution(cache, &spot.key(), || {
+        eprintln!("Working out starting hand equities, once per spot...");
+        let equities = pushfold::Equities::compute(preflop::EQUITY_SAMPLES, 1);
+        spot.solve(&equities, iterations)
+    });
+    write_preflop(out, spot, &solution)
+}
+
+/// The cached solution for `key`, or a new one from `solve`, which is
+/// then cached.
+fn cached_solution(cache: &SolutionCache, key: &str, solve: impl FnOnce() -> Solution) -> Solution {
+    match cache.load(key) {
+        Ok(Some(solution)) => return solution,
+        Ok(None) => {}
+        Err(e) => eprintln!("Warning: Could not read the saved solution: {}", e),
+    }
+    let solution = solve();
+    if let Err(e) = cache.save(key, &solution) {
+        eprintln!("Warning: Could not save the solution: {}", e);
+    }
+    solution
+}
+
+fn write_river(out: &mut impl Write, spot: &RiverSpot, solution: &Solution) -> io::Result<()> {
+    let big_blind = spot.start.big_blind;
+    let board_text: Vec<String> = spot.board.iter().map(Card::to_string).collect();
+    writeln!(
+        out,
+        "Board: {}  Pot: {}  Behind: {}  Exploitability: {:.2}% of the pot",
+        board_text.join(" "),
+        format_bb(spot.start.pot, big_blind),
+        format_bb(spot.start.stacks[0], big_blind),
+        exploitability(solution, spot) / spot.start.pot as f64 * 100.0
+    )?;
+
+    let Node::Decision {
+        children, actions, ..
+    } = solution.tree.root()
+    else {
+        return Ok(());
+    };
+    writeln!(out)?;
+    write_strategy(
+        out,
+        "Out of position, first to act",
+        solution,
+        0,
+        spot,
+        &hand_classes(spot, 0),
+    )?;
+    let ip_classes = hand_classes(spot, 1);
+    for (action, &child) in actions.iter().zip(children) {
+        if let Node::Decision { .. } = solution.tree.node(child) {
+            writeln!(out)?;
+            let title = format!("In position, {}", answering(*action, big_blind));
+            write_strategy(out, &title, solution, child, spot, &ip_classes)?;
+        }
+    }
+    Ok(())
+}
+
+/// `player`'s hands grouped by starting hand, strongest first.
+fn hand_classes(spot: &RiverSpot, player: usize) -> Vec<(usize, Vec<usize>)> {
+    let mut classes: Vec<(usize, Vec<usize>)> = Vec::new();
+    for (hand, cards) in spot.hands(player).iter().enumerate() {
+        let class = pushfold::hand_index(cards[0], cards[1]);
+        match classes.iter_mut().find(|(c, _)| *c == class) {
+            Some((_, hands)) => hands.push(hand),
+            None => classes.push((class, vec![hand])),
+        }
+    }
+    let best = |hands: &[usize]| {
+        hands
+            .iter()
+            .map(|&hand| evaluate(&spot.hands(player)[hand], &spot.board, Ranking::Standard))
+            .max()
+    };
+    classes.sort_by_key(|(_, hands)| Reverse(best(hands)));
+    classes
+}
+
+/// How often each group of hands takes each action at `node`, in a table.
+fn write_strategy(
+    out: &mut impl Write,
+    title: &str,
+    solution: &Solution,
+    node: usize,
+    spot: &RiverSpot,
+    classes: &[(usize, Vec<usize>)],
+) -> io::Result<()> {
+    let Node::Decision {
+        player, actions, ..
+    } = solution.tree.node(node)
+    else {
+        return Ok(());
+    };
+    writeln!(out, "{}", title)?;
+    let header: Vec<String> = actions
+        .iter()
+        .map(|&a| format!("{:>12}", action_label(a, spot.start.big_blind)))
+        .collect();
+    writeln!(out, "{:<6}{}", "Hand", header.join(""))?;
+
+    let weights = spot.reach(*player);
+    let mut overall = vec![0.0; actions.len()];
+    for (class, hands) in classes {
+        let mut shares = vec![0.0; actions.len()];
+        let total: f64 = hands.iter().map(|&hand| weights[hand]).sum();
+        for &hand in hands {
+            for (share, played) in shares.iter_mut().zip(solution.strategy(node, hand)) {
+                *share += played * weights[hand] / total;
+            }
+        }
+        for (overall, share) in overall.iter_mut().zip(&shares) {
+            *overall += share * total;
+        }
+        let cells: Vec<String> = shares
+            .iter()
+            .map(|s| format!("{:>11.0}%", s * 100.0))
+            .collect();
+        writeln!(out, "{:<6}{}", pushfold::hand_name(*class), cells.join(""))?;
+    }
+    let total: f64 = weights.iter().sum();
+    let cells: Vec<String> = overall
+        .iter()
+        .map(|s| format!("{:>11.0}%", s / total * 100.0))
+        .collect();
+    writeln!(out, "{:<6}{}", "All", cells.join(""))
+}
+
+fn write_preflop(out: &mut impl Write, spot: &PreflopSpot, solution: &Solution) -> io::Result<()> {
+    writeln!(
+        out,
+        "Heads-up at {}BB. Each hand's most frequent play; lower case when it's under 75%.",
+        spot.stack_bb
+    )?;
+    let Node::Decision {
+        children, actions, ..
+    } = solution.tree.root()
+    else {
+        return Ok(());
+    };
+    write_grid(out, "Small blind", solution, 0, preflop::BIG_BLIND)?;
+    for (action, &child) in actions.iter().zip(children) {
+        if let Node::Decision { .. } = solution.tree.node(child) {
+            let title = format!("Big blind, {}", answering(*action, preflop::BIG_BLIND));
+            write_grid(out, &title, solution, child, preflop::BIG_BLIND)?;
+        }
+    }
+    Ok(())
+}
+
+/// The starting hand grid, as in `push-fold`, with a letter in each cell
+/// for the hand's most frequent action at `node`.
+fn write_grid(
+    out: &mut impl Write,
+    title: &str,
+    solution: &Solution,
+    node: usize,
+    big_blind: u32,
+) -> io::Result<()> {
+    let Node::Decision { actions, .. } = solution.tree.node(node) else {
+        return Ok(());
+    };
+    let letter = |action: Action| match action {
+        Action::Fold => 'F',
+        Action::Check => 'X',
+        Action::Call(_) => 'C',
+        Action::Bet(_) | Action::Raise(_) => 'R',
+        Action::AllIn(_) => 'A',
+    };
+    let legend: Vec<String> = actions
+        .iter()
+        .map(|&action| format!("{} {}", letter(action), action_label(action, big_blind)))
+        .collect();
+    writeln!(out)?;
+    writeln!(out, "{} ({})", title, legend.join(", "))?;
+    for row in 0..13 {
+        let cells: Vec<String> = (0..13)
+            .map(|col| {
+                let strategy = solution.strategy(node, row * 13 + col);
+                let (best, share) = strategy.iter().enumerate().fold((0, 0.0), |best, (i, &s)| {
+                    if s > best.1 {
+                        (i, s)
+                    } else {
+                        best
+                    }
+                });
+                let c = letter(actions[best]);
+                if share < 0.75 {
+                    c.to_ascii_lowercase()
+                } else {
+                    c
+                }
+                .to_string()
+            })
+            .collect();
+        writeln!(out, "{}", cells.join(" "))?;
+    }
+    Ok(())
+}
+
+fn action_label(action: Action, big_blind: u32) -> String {
+    match action {
+        Action::Fold => "fold".to_string(),
+        Action::Check => "check".to_string(),
+        Action::Call(_) => "call".to_string(),
+        Action::Bet(to) => format!("bet {}", format_bb(to, big_blind)),
+        Action::Raise(to) => format!("raise to {}", format_bb(to, big_blind)),
+        Action::AllIn(to) => format!("all in {}", format_bb(to, big_blind)),
+    }
+}
+
+/// What the other player is answering, for a table's title.
+fn answering(action: Action, big_blind: u32) -> String {
+    match action {
+        Action::Check => "after a check".to_string(),
+        Action::Call(_) => "after a limp".to_string(),
+        Action::Bet(to) => format!("facing a {} bet", format_bb(to, big_blind)),
+        Action::Raise(to) => format!("facing a raise to {}", format_bb(to, big_blind)),
+        Action::Fold | Action::AllIn(_) => "facing an all-in".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::pushfold::Equities;
+
+    #[test]
+    fn test_river_report_lists_both_players() {
+        let dir =
+            std::env::temp_dir().join(format!("terminal-poker-report-{}", std::process::id()));
+        let cache = SolutionCache::at(dir.clone());
+        let sizes = BetSizes {
+            bets: vec![1.0],
+            raises: Vec::new(),
+        };
+        let spot = river_spot(["AA,KK,QJs", "AK,QQ"], "AhKd7c4s2h", 10.0, 50.0, sizes).unwrap();
+        let mut first = Vec::new();
+        river(&mut first, &cache, &spot, 50).unwrap();
+        // The second run loads what the first saved
+        let mut second = Vec::new();
+        river(&mut second, &cache, &spot, 1).unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let text = String::from_utf8(first.clone()).unwrap();
+        assert_eq!(first, second);
+        assert!(text.starts_with("Board: Ah Kd 7c 4s 2h  Pot: 10BB  Behind: 50BB"));
+        assert!(text.contains("Out of position, first to act\nHand         check    bet 10BB"));
+        assert!(text.contains("In position, after a check"));
+        assert!(text.contains("In position, facing a 10BB bet"));
+        // Sets first: AA makes a set of aces on this board
+        let oop_hands: Vec<&str> = text
+            .lines()
+            .skip_while(|line| !line.starts_with("Hand"))
+            .skip(1)
+            .map_while(|line| line.split_whitespace().next())
+            .collect();
+        assert_eq!(oop_hands, ["AA", "KK", "QJs", "All"]);
+    }
+
+    #[test]
+    fn test_bad_spots_are_rejected() {
+        let sizes = || BetSizes {
+            bets: vec![1.0],
+            raises: Vec::new(),
+        };
+        assert!(river_spot(["AA", "KK"], "AhKd7c", 10.0, 50.0, sizes()).is_err());
+        assert!(river_spot(["AA", "KK"], "2c3d4h5s9c", 0.0, 50.0, sizes()).is_err());
+        assert!(river_spot(["AA", "nonsense"], "2c3d4h5s9c", 10.0, 50.0, sizes()).is_err());
+        assert!(preflop_spot(10, vec![1.5]).is_err());
+        assert!(preflop_spot(10, vec![10.0]).is_err());
+        assert!(preflop_spot(10, vec![2.5]).is_ok());
+    }
+
+    #[test]
+    fn test_preflop_grids_mark_each_hand() {
+        // Better hands by index, so the worst hands fold
+        let equities = Equities::from_fn(|a, b| 0.5 + (b as f64 - a as f64) / 400.0);
+        let spot = preflop_spot(10, Vec::new()).unwrap();
+        let solution = spot.solve(&equities, 200);
+        let mut out = Vec::new();
+        write_preflop(&mut out, &spot, &solution).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("Heads-up at 10BB."));
+        assert!(lines[2].starts_with("Small blind (F fold, C call, A all in 10BB)"));
+        let grid: Vec<&str> = lines[3..16].to_vec();
+        assert!(grid.iter().all(|row| row.split(' ').count() == 13));
+        assert!(grid
+            .iter()
+            .flat_map(|row| row.split(' '))
+            .all(|cell| ["F", "C", "A"].contains(&cell.to_uppercase().as_str())));
+        assert!(grid[12].ends_with('F'));
+        assert!(text.contains("Big blind, facing an all-in"));
+    }
+}
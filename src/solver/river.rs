@@ -0,0 +1,326 @@
+//! River spots: the board is out, each player holds a range, and one round
+//! of betting is left before the showdown.
+
+use crate::game::deck::Card;
+use crate::game::evaluator::{evaluate, HandValue};
+use crate::game::hand::Ranking;
+use crate::game::range::card_index;
+
+use super::tree::{BetSizes, Start, Tree};
+use super::{solve, Matchups, Solution};
+
+pub struct RiverSpot {
+    pub board: Vec<Card>,
+    pub start: Start,
+    pub sizes: BetSizes,
+    /// Each player's hands, player 0 first: they act first on the river.
+    hands: [Vec<[Card; 2]>; 2],
+    /// Each hand's weight in its range.
+    weights: [Vec<f64>; 2],
+    values: [Vec<HandValue>; 2],
+    /// Each player's hands, weakest first.
+    order: [Vec<usize>; 2],
+    /// For each hand, where the same two cards are in the other range.
+    same: [Vec<Option<usize>>; 2],
+}
+
+impl RiverSpot {
+    /// A spot on a five-card `board` between two weighted ranges. Hands
+    /// using a board card are left out.
+    pub fn new(
+        board: &[Card],
+        ranking: Ranking,
+        ranges: [Vec<([Card; 2], f64)>; 2],
+        start: Start,
+        sizes: BetSizes,
+    ) -> Result<Self, String> {
+        if board.len() != 5 {
+            return Err(format!("a river board has 5 cards, not {}", board.len()));
+        }
+        let [first, second] = ranges.map(|range| {
+            let mut hands: Vec<([Card; 2], f64)> = range
+                .into_iter()
+                .filter(|(cards, weight)| {
+                    *weight > 0.0
+                        && cards[0] != cards[1]
+                        && !cards.iter().any(|c| board.contains(c))
+                })
+                .collect();
+            hands.sort_by_key(|([a, b], _)| combo_key(*a, *b));
+            hands.dedup_by_key(|([a, b], _)| combo_key(*a, *b));
+            hands
+        });
+        if first.is_empty() || second.is_empty() {
+            return Err("both ranges need a hand that doesn't use a board card".to_string());
+        }
+
+        let split = |range: Vec<([Card; 2], f64)>| -> (Vec<[Card; 2]>, Vec<f64>) {
+            range.into_iter().unzip()
+        };
+        let (hands_0, weights_0) = split(first);
+        let (hands_1, weights_1) = split(second);
+        let hands = [hands_0, hands_1];
+        let values = [0, 1].map(|player| {
+            hands[player]
+                .iter()
+                .map(|cards| evaluate(cards, board, ranking))
+                .collect::<Vec<_>>()
+        });
+        let order = [0, 1].map(|player| {
+            let mut order: Vec<usize> = (0..hands[player].len()).collect();
+            order.sort_by_key(|&hand| values[player][hand]);
+            order
+        });
+        let same = [0, 1].map(|player| {
+            hands[player]
+                .iter()
+                .map(|&[a, b]| {
+                    hands[1 - player]
+                        .binary_search_by_key(&combo_key(a, b), |&[x, y]| combo_key(x, y))
+                        .ok()
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Ok(Self {
+            board: board.to_vec(),
+            start,
+            sizes,
+            hands,
+            weights: [weights_0, weights_1],
+            values,
+            order,
+            same,
+        })
+    }
+
+    /// The hands `player` can hold in this spot.
+    pub fn hands(&self, player: usize) -> &[[Card; 2]] {
+        &self.hands[player]
+    }
+
+    /// Where `cards` are among `player`'s hands.
+    pub fn hand(&self, player: usize, cards: &[Card]) -> Option<usize> {
+        let [a, b] = cards else {
+            return None;
+        };
+        self.hands[player]
+            .binary_search_by_key(&combo_key(*a, *b), |&[x, y]| combo_key(x, y))
+            .ok()
+    }
+
+    pub fn tree(&self) -> Tree {
+        Tree::build(self.start, &self.sizes)
+    }
+
+    pub fn solve(&self, iterations: u32) -> Solution {
+        solve(self.tree(), self, iterations)
+    }
+
+    /// Text that tells this spot apart from any other, for caching its
+    /// solution.
+    pub fn key(&self) -> String {
+        let board: String = self.board.iter().map(Card::to_string).collect();
+        let mut key = format!("river {} {:?} {:?}", board, self.start, self.sizes);
+        for player in 0..2 {
+            key.push_str(" |");
+            for ([a, b], weight) in self.hands[player].iter().zip(&self.weights[player]) {
+                key.push_str(&format!(" {}{}:{}", a, b, weight));
+            }
+        }
+        key
+    }
+}
+
+impl Matchups for RiverSpot {
+    fn reach(&self, player: usize) -> &[f64] {
+        &self.weights[player]
+    }
+
+    fn against(&self, player: usize, opponent_reach: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let opponent = 1 - player;
+        let hands = &self.hands[player];
+        let mut deals = vec![0.0; hands.len()];
+        let mut shares = vec![0.0; hands.len()];
+
+        // Opponent hands are added weakest first, to everything below this
+        // hand and to everything no better than it. A hand's matchups are
+        // each total less the opponent hands sharing one of its cards.
+        let mut below = Blockers::default();
+        let mut up_to = Blockers::default();
+        let (mut next_below, mut next_up_to) = (0, 0);
+        let opponent_order = &self.order[opponent];
+        let opponent_values = &self.values[opponent];
+        for &hand in &self.order[player] {
+            let value = self.values[player][hand];
+            while let Some(&other) = opponent_order.get(next_below) {
+                if opponent_values[other] >= value {
+                    break;
+                }
+                below.add(self.hands[opponent][other], opponent_reach[other]);
+                next_below += 1;
+            }
+            while let Some(&other) = opponent_order.get(next_up_to) {
+                if opponent_values[other] > value {
+                    break;
+                }
+                up_to.add(self.hands[opponent][other], opponent_reach[other]);
+                next_up_to += 1;
+            }
+
+            // The same two cards are a tie, so only ever in `up_to`
+            let same = self.same[player][hand].map_or(0.0, |other| opponent_reach[other]);
+            let cards = hands[hand];
+            let beaten = below.without(cards);
+            let not_losing = up_to.without(cards) + same;
+            shares[hand] = (beaten + not_losing) / 2.0;
+        }
+
+        let mut all = Blockers::default();
+        for (other, &cards) in self.hands[opponent].iter().enumerate() {
+            all.add(cards, opponent_reach[other]);
+        }
+        for (hand, &cards) in hands.iter().enumerate() {
+            let same = self.same[player][hand].map_or(0.0, |other| opponent_reach[other]);
+            deals[hand] = all.without(cards) + same;
+        }
+        (deals, shares)
+    }
+}
+
+/// A total of opponent reach, and the part of it from hands holding each
+/// card.
+struct Blockers {
+    total: f64,
+    cards: [f64; 52],
+}
+
+impl Default for Blockers {
+    fn default() -> Self {
+        Self {
+            total: 0.0,
+            cards: [0.0; 52],
+        }
+    }
+}
+
+impl Blockers {
+    fn add(&mut self, [a, b]: [Card; 2], reach: f64) {
+        self.total += reach;
+        self.cards[card_index(a)] += reach;
+        self.cards[card_index(b)] += reach;
+    }
+
+    /// The total less every hand sharing a card with `cards`. A hand of
+    /// the very same two cards is taken off twice.
+    fn without(&self, [a, b]: [Card; 2]) -> f64 {
+        self.total - self.cards[card_index(a)] - self.cards[card_index(b)]
+    }
+}
+
+/// The same number for a hand whichever way round its cards are.
+fn combo_key(a: Card, b: Card) -> (usize, usize) {
+    let (a, b) = (card_index(a), card_index(b));
+    (a.max(b), a.min(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::Action;
+    use crate::game::deck::parse_cards;
+    use crate::game::range::Range;
+    use crate::solver::exploitability;
+    use crate::solver::tree::Node;
+
+    fn combos(notation: &str) -> Vec<([Card; 2], f64)> {
+        Range::parse(notation).unwrap().combos()
+    }
+
+    fn spot(oop: &str, ip: &str, start: Start, sizes: BetSizes) -> RiverSpot {
+        let board = parse_cards("AhKd7c4s2h").unwrap();
+        RiverSpot::new(
+            &board,
+            Ranking::Standard,
+            [combos(oop), combos(ip)],
+            start,
+            sizes,
+        )
+        .unwrap()
+    }
+
+    /// How often `player` takes each action at `node` with `hands`.
+    fn frequencies(
+        spot: &RiverSpot,
+        solution: &Solution,
+        node: usize,
+        player: usize,
+        hands: &str,
+    ) -> Vec<f64> {
+        let mut total = Vec::new();
+        let mut count = 0.0;
+        for ([a, b], _) in combos(hands) {
+            let Some(hand) = spot.hand(player, &[a, b]) else {
+                continue;
+            };
+            let strategy = solution.strategy(node, hand);
+            total.resize(strategy.len(), 0.0);
+            for (total, share) in total.iter_mut().zip(strategy) {
+                *total += share;
+            }
+            count += 1.0;
+        }
+        total.iter().map(|t| t / count).collect()
+    }
+
+    #[test]
+    fn test_showdown_shares_count_card_removal() {
+        let spot = spot(
+            "AA, 98s",
+            "KQ, AA, 98s",
+            Start::new(10, 10, 2),
+            BetSizes::default(),
+        );
+        let reach = spot.reach(1).to_vec();
+        let (deals, shares) = spot.against(0, &reach);
+        let hand = |cards: &str| spot.hand(0, &parse_cards(cards).unwrap()).unwrap();
+
+        // AsAc beats the 12 KQ combos and the 4 98s, and every other set
+        // of aces holds the As or the Ac
+        assert_eq!(deals[hand("AsAc")], 16.0);
+        assert_eq!(shares[hand("AsAc")], 16.0);
+        // 9s8s loses to the 15 KQ and AA combos, and splits with the three
+        // other 98s
+        assert_eq!(deals[hand("9s8s")], 18.0);
+        assert_eq!(shares[hand("9s8s")], 1.5);
+    }
+
+    #[test]
+    fn test_polarised_bettor_bluffs_to_make_calls_break_even() {
+        // Sets or air against a bluff catcher, with one pot-sized bet left
+        let sizes = BetSizes {
+            bets: vec![],
+            raises: vec![],
+        };
+        let spot = spot("AA, 98s", "KQ", Start::new(100, 100, 2), sizes);
+        let solution = spot.solve(2_000);
+        assert!(exploitability(&solution, &spot) < 1.0);
+
+        let Node::Decision {
+            actions, children, ..
+        } = solution.tree.root()
+        else {
+            unreachable!()
+        };
+        assert_eq!(actions, &[Action::Check, Action::AllIn(100)]);
+        // Every set bets, and with 3 sets and 4 air hands, 1.5 of the
+        // air hands bluff: a pot-sized bet is called when it's a bluff a
+        // third of the time
+        assert!(frequencies(&spot, &solution, 0, 0, "AA")[1] > 0.95);
+        let bluffs = frequencies(&spot, &solution, 0, 0, "98s")[1];
+        assert!((bluffs - 0.375).abs() < 0.05, "bluffs {bluffs}");
+        // and the bluff catcher calls half the time, so bluffs break even
+        let calls = frequencies(&spot, &solution, children[1], 1, "KQ")[1];
+        assert!((calls - 0.5).abs() < 0.05, "calls {calls}");
+    }
+}
@@ -0,0 +1,355 @@
+//! The betting tree of a heads-up spot, cut down to a few bet sizes. Every
+//! line either ends in a fold or goes to showdown when the round closes, so
+//! the tree covers one betting round only.
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::actions::Action;
+
+/// Bet sizes the tree allows. Going all in is always allowed as well.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BetSizes {
+    /// Opening bets, as fractions of the pot.
+    pub bets: Vec<f64>,
+    /// The first raise, as fractions of the pot after calling. After that
+    /// the only raise is all in.
+    pub raises: Vec<f64>,
+}
+
+impl Default for BetSizes {
+    fn default() -> Self {
+        Self {
+            bets: vec![0.5, 1.0],
+            raises: vec![1.0],
+        }
+    }
+}
+
+/// Where the betting round starts from. Players are numbered 0 and 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Start {
+    /// Chips in the pot from earlier rounds.
+    pub pot: u32,
+    /// Chips each player has put in this round.
+    pub bets: [u32; 2],
+    /// Chips each player has behind. Only the effective stack can be won,
+    /// so the larger is cut down to match.
+    pub stacks: [u32; 2],
+    pub to_act: usize,
+    /// Each player has acted since the last bet or raise.
+    pub acted: [bool; 2],
+    /// Size of the last bet or raise, the least the next raise adds.
+    pub last_raise: u32,
+    /// Raises made this round, not counting the opening bet.
+    pub raises: u32,
+    pub big_blind: u32,
+}
+
+impl Start {
+    /// A new betting round with nothing bet yet and `stack` behind each,
+    /// player 0 to act.
+    pub fn new(pot: u32, stack: u32, big_blind: u32) -> Self {
+        Self {
+            pot,
+            bets: [0, 0],
+            stacks: [stack, stack],
+            to_act: 0,
+            acted: [false, false],
+            last_raise: 0,
+            raises: 0,
+            big_blind,
+        }
+        .effective()
+    }
+
+    fn effective(mut self) -> Self {
+        let total = (self.bets[0] + self.stacks[0]).min(self.bets[1] + self.stacks[1]);
+        for player in 0..2 {
+            self.stacks[player] = total.saturating_sub(self.bets[player]);
+        }
+        self
+    }
+
+    fn max_bet(&self) -> u32 {
+        self.bets[0].max(self.bets[1])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Node {
+    /// `player` picks one of `actions`, leading to the node at the same
+    /// place in `children`. `decision` numbers the decision nodes.
+    Decision {
+        player: usize,
+        decision: usize,
+        actions: Vec<Action>,
+        children: Vec<usize>,
+    },
+    /// `player` folded, after each player put in `bets` this round.
+    Fold { player: usize, bets: [u32; 2] },
+    /// The round closed with `bets` in from each player.
+    Showdown { bets: [u32; 2] },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tree {
+    /// Chips in the pot from earlier rounds.
+    pub pot: u32,
+    /// Every node, the root first.
+    pub nodes: Vec<Node>,
+    /// Number of decision nodes.
+    pub decisions: usize,
+}
+
+impl Tree {
+    pub fn build(start: Start, sizes: &BetSizes) -> Self {
+        let mut tree = Self {
+            pot: start.pot,
+            nodes: Vec::new(),
+            decisions: 0,
+        };
+        tree.add(start.effective(), sizes);
+        tree
+    }
+
+    /// Add the node for `state` and everything below it, and return its index.
+    fn add(&mut self, state: Start, sizes: &BetSizes) -> usize {
+        let index = self.nodes.len();
+        let player = state.to_act;
+        let decision = self.decisions;
+        self.decisions += 1;
+        self.nodes.push(Node::Decision {
+            player,
+            decision,
+            actions: Vec::new(),
+            children: Vec::new(),
+        });
+
+        let actions = actions(&state, sizes);
+        let children = actions
+            .iter()
+            .map(|&action| self.add_after(state, action, sizes))
+            .collect();
+        if let Node::Decision {
+            actions: slot,
+            children: child_slot,
+            ..
+        } = &mut self.nodes[index]
+        {
+            *slot = actions;
+            *child_slot = children;
+        }
+        index
+    }
+
+    fn add_after(&mut self, mut state: Start, action: Action, sizes: &BetSizes) -> usize {
+        let player = state.to_act;
+        let opponent = 1 - player;
+        let max_bet = state.max_bet();
+        let terminal = match action {
+            Action::Fold => Some(Node::Fold {
+                player,
+                bets: state.bets,
+            }),
+            Action::Check | Action::Call(_) => {
+                put_in(&mut state, player, max_bet);
+                state.acted[player] = true;
+                state.acted[opponent].then_some(Node::Showdown { bets: state.bets })
+            }
+            Action::Bet(to) | Action::Raise(to) | Action::AllIn(to) => {
+                put_in(&mut state, player, to);
+                if to > max_bet {
+                    if max_bet > 0 {
+                        state.raises += 1;
+                    }
+                    state.last_raise = to - max_bet;
+                    state.acted = [false, false];
+                }
+                state.acted[player] = true;
+                // All in for no more than a call closes the round
+                (state.acted[opponent] && state.bets[0] == state.bets[1])
+                    .then_some(Node::Showdown { bets: state.bets })
+            }
+        };
+        match terminal {
+            Some(node) => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+            None => {
+                state.to_act = opponent;
+                self.add(state, sizes)
+            }
+        }
+    }
+
+    pub fn root(&self) -> &Node {
+        &self.nodes[0]
+    }
+
+    pub fn node(&self, index: usize) -> &Node {
+        &self.nodes[index]
+    }
+}
+
+/// Bring `player`'s bet up to `to`.
+fn put_in(state: &mut Start, player: usize, to: u32) {
+    let added = to
+        .saturating_sub(state.bets[player])
+        .min(state.stacks[player]);
+    state.bets[player] += added;
+    state.stacks[player] -= added;
+}
+
+/// What the player to act can do, with bets and raises limited to `sizes`.
+fn actions(state: &Start, sizes: &BetSizes) -> Vec<Action> {
+    let player = state.to_act;
+    let opponent = 1 - player;
+    let max_bet = state.max_bet();
+    let to_call = max_bet - state.bets[player];
+    let all_in_to = state.bets[player] + state.stacks[player];
+
+    let mut actions = Vec::new();
+    if to_call == 0 {
+        actions.push(Action::Check);
+    } else {
+        actions.push(Action::Fold);
+        if state.stacks[player] <= to_call {
+            actions.push(Action::AllIn(all_in_to));
+            return actions;
+        }
+        actions.push(Action::Call(to_call));
+    }
+    if state.stacks[opponent] == 0 {
+        return actions;
+    }
+
+    let pot = state.pot + state.bets[0] + state.bets[1];
+    let (fractions, min_to): (&[f64], u32) = if max_bet == 0 {
+        (&sizes.bets, state.big_blind)
+    } else if state.raises == 0 {
+        let min_raise = state.last_raise.max(state.big_blind);
+        (&sizes.raises, max_bet + min_raise)
+    } else {
+        (&[], all_in_to)
+    };
+    for &fraction in fractions {
+        let to = max_bet + ((pot + to_call) as f64 * fraction).round() as u32;
+        let to = to.max(min_to);
+        if to >= all_in_to {
+            continue;
+        }
+        let action = if max_bet == 0 {
+            Action::Bet(to)
+        } else {
+            Action::Raise(to)
+        };
+        if !actions.contains(&action) {
+            actions.push(action);
+        }
+    }
+    actions.push(Action::AllIn(all_in_to));
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root_actions(tree: &Tree) -> &[Action] {
+        match tree.root() {
+            Node::Decision { actions, .. } => actions,
+            node => panic!("root is {node:?}"),
+        }
+    }
+
+    #[test]
+    fn test_river_tree_sizes() {
+        let tree = Tree::build(Start::new(100, 300, 2), &BetSizes::default());
+        assert_eq!(
+            root_actions(&tree),
+            [
+                Action::Check,
+                Action::Bet(50),
+                Action::Bet(100),
+                Action::AllIn(300)
+            ]
+        );
+
+        // A pot-sized bet faces a pot-sized raise: call 100, then 300 more
+        let Node::Decision { children, .. } = tree.root() else {
+            unreachable!()
+        };
+        let Node::Decision { actions, .. } = tree.node(children[2]) else {
+            panic!("no decision after a bet");
+        };
+        assert_eq!(
+            actions,
+            &[Action::Fold, Action::Call(100), Action::AllIn(300)]
+        );
+    }
+
+    #[test]
+    fn test_every_line_ends() {
+        let tree = Tree::build(Start::new(10, 1000, 2), &BetSizes::default());
+        let mut showdowns = 0;
+        for node in &tree.nodes {
+            match node {
+                Node::Decision {
+                    actions, children, ..
+                } => assert_eq!(actions.len(), children.len()),
+                Node::Showdown { bets } => {
+                    assert_eq!(bets[0], bets[1]);
+                    showdowns += 1;
+                }
+                Node::Fold { .. } => {}
+            }
+        }
+        // Check-check, and a call of every bet and raise
+        assert!(showdowns > 5);
+        assert_eq!(
+            tree.decisions,
+            tree.nodes
+                .iter()
+                .filter(|node| matches!(node, Node::Decision { .. }))
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_preflop_limp_gives_the_big_blind_an_option() {
+        let start = Start {
+            pot: 0,
+            bets: [1, 2],
+            stacks: [19, 18],
+            to_act: 0,
+            acted: [false, false],
+            last_raise: 2,
+            raises: 0,
+            big_blind: 2,
+        };
+        let tree = Tree::build(
+            start,
+            &BetSizes {
+                bets: vec![],
+                raises: vec![],
+            },
+        );
+        // Stacks are cut to the effective 20
+        assert_eq!(
+            root_actions(&tree),
+            [Action::Fold, Action::Call(1), Action::AllIn(20)]
+        );
+        let Node::Decision { children, .. } = tree.root() else {
+            unreachable!()
+        };
+        let Node::Decision {
+            player, actions, ..
+        } = tree.node(children[1])
+        else {
+            panic!("a limp closed the round");
+        };
+        assert_eq!(*player, 1);
+        assert_eq!(actions, &[Action::Check, Action::AllIn(20)]);
+    }
+}
@@ -29,9 +29,18 @@ pub struct PlayerStats {
     pub calls: u64,
 
     // Results
-    pub total_profit_chips: i64,
+    #[serde(default)]
+    pub total_profit_bb: f64,
     pub biggest_pot_won: u32,
     pub biggest_pot_lost: u32,
+    /// Profit saved before blinds were configurable, in chips at 1/2.
+    /// Folded into `total_profit_bb` on load and never written back.
+    #[serde(default, rename = "total_profit_chips", skip_serializing)]
+    legacy_profit_chips: i64,
+
+    // Tournaments (finishing position of each completed sit-and-go)
+    #[serde(default)]
+    pub tournament_finishes: Vec<u32>,
 }
 
 #[allow(dead_code)]
@@ -104,11 +113,32 @@ impl PlayerStats {
         }
     }
 
+    pub fn tournament_wins(&self) -> usize {
+        self.tournament_finishes.iter().filter(|&&p| p == 1).count()
+    }
+
+    pub fn average_finish(&self) -> f64 {
+        if self.tournament_finishes.is_empty() {
+            0.0
+        } else {
+            self.tournament_finishes.iter().sum::<u32>() as f64
+                / self.tournament_finishes.len() as f64
+        }
+    }
+
     pub fn win_rate_bb_per_100(&self) -> f64 {
         if self.total_hands == 0 {
             0.0
         } else {
-            self.total_profit_chips as f64 / 2.0 / self.total_hands as f64 * 100.0
+            self.total_profit_bb / self.total_hands as f64 * 100.0
+        }
+    }
+
+    /// Convert fields from older stats files.
+    pub fn migrate(&mut self) {
+        if self.legacy_profit_chips != 0 {
+            self.total_profit_bb += self.legacy_profit_chips as f64 / 2.0;
+            self.legacy_profit_chips = 0;
         }
     }
 }
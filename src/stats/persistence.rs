@@ -1,9 +1,19 @@
 use super::models::PlayerStats;
-use std::fs;
+use crate::game::history::HandRecord;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
 use std::path::PathBuf;
 
 const APP_NAME: &str = "terminal-poker";
 const STATS_FILE: &str = "stats.json";
+const HISTORY_FILE: &str = "hands.jsonl";
+
+pub(crate) fn data_path(file: &str) -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(APP_NAME)
+        .join(file)
+}
 
 pub struct StatsStore {
     pub stats: PlayerStats,
@@ -12,12 +22,17 @@ pub struct StatsStore {
 
 impl StatsStore {
     pub fn load_or_create() -> Self {
-        let path = Self::stats_path();
+        Self::at(Self::stats_path())
+    }
 
+    pub fn at(path: PathBuf) -> Self {
         let stats = if path.exists() {
             match fs::read_to_string(&path) {
-                Ok(contents) => match serde_json::from_str(&contents) {
-                    Ok(stats) => stats,
+                Ok(contents) => match serde_json::from_str::<PlayerStats>(&contents) {
+                    Ok(mut stats) => {
+                        stats.migrate();
+                        stats
+                    }
                     Err(e) => {
                         eprintln!("Warning: Could not parse stats file, starting fresh: {}", e);
                         PlayerStats::default()
@@ -56,10 +71,7 @@ impl StatsStore {
     }
 
     fn stats_path() -> PathBuf {
-        dirs::data_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join(APP_NAME)
-            .join(STATS_FILE)
+        data_path(STATS_FILE)
     }
 
     pub fn record_hand_start(&mut self) {
@@ -97,8 +109,8 @@ impl StatsStore {
         }
     }
 
-    pub fn record_profit(&mut self, amount: i64) {
-        self.stats.total_profit_chips += amount;
+    pub fn record_profit(&mut self, profit_bb: f64) {
+        self.stats.total_profit_bb += profit_bb;
     }
 
     pub fn record_pot_won(&mut self, pot: u32) {
@@ -107,6 +119,10 @@ impl StatsStore {
         }
     }
 
+    pub fn record_tournament_finish(&mut self, position: usize) {
+        self.stats.tournament_finishes.push(position as u32);
+    }
+
     pub fn record_pot_lost(&mut self, pot: u32) {
         if pot > self.stats.biggest_pot_lost {
             self.stats.biggest_pot_lost = pot;
@@ -140,5 +156,110 @@ impl StatsStore {
     pub fn record_session_end(&mut self) {
         self.stats.total_sessions += 1;
     }
+}
+
+/// Append-only log of every completed hand, one JSON record per line,
+/// stored next to `stats.json`.
+pub struct HandHistoryStore {
+    path: PathBuf,
+}
 
+impl HandHistoryStore {
+    pub fn open() -> Self {
+        Self::at(data_path(HISTORY_FILE))
+    }
+
+    pub fn at(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn append(&self, record: &HandRecord) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string(record)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", json)
+    }
+
+    /// Load every recorded hand in the order it was played. Lines that
+    /// cannot be parsed (e.g. a write cut short by a crash) are skipped.
+    pub fn load(&self) -> io::Result<Vec<HandRecord>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        Ok(contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::Action;
+    use crate::game::state::GameState;
+
+    #[test]
+    fn test_history_append_and_load() {
+        let path = std::env::temp_dir().join(format!(
+            "terminal-poker-history-{}.jsonl",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        let store = HandHistoryStore::at(path.clone());
+
+        let mut state = GameState::with_seed(100, 1);
+        state.apply_action(state.to_act, Action::Fold);
+        state.start_new_hand();
+        state.apply_action(state.to_act, Action::Fold);
+        let hands = state.take_finished_hands();
+        for hand in &hands {
+            store.append(hand).unwrap();
+        }
+
+        // A truncated trailing line is ignored
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        write!(file, "{{\"hand_number\":").unwrap();
+
+        let loaded = store.load().unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(loaded, hands);
+    }
+
+    #[test]
+    fn test_history_missing_file_is_empty() {
+        let path = PathBuf::from("/nonexistent/terminal-poker/hands.jsonl");
+        let store = HandHistoryStore::at(path);
+        assert!(store.load().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_old_stats_profit_is_converted_to_bb() {
+        // Stats files used to store profit in chips at 1/2 blinds
+        let mut old = serde_json::to_value(PlayerStats::default()).unwrap();
+        old["total_hands"] = 100.into();
+        old["total_profit_chips"] = 40.into();
+        old.as_object_mut().unwrap().remove("total_profit_bb");
+
+        let mut stats: PlayerStats = serde_json::from_value(old).unwrap();
+        stats.migrate();
+        assert_eq!(stats.total_profit_bb, 20.0);
+        assert_eq!(stats.win_rate_bb_per_100(), 20.0);
+
+        let json = serde_json::to_string(&stats).unwrap();
+        assert!(!json.contains("total_profit_chips"));
+    }
 }
@@ -1,12 +1,22 @@
 use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
-use crate::bot::rule_based::RuleBasedBot;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::bot::personality::Personality;
+use crate::bot::reasoning::Reasoning;
+use crate::bot::{BotConfig, BotKind, Strategy};
 use crate::game::actions::Action;
-use crate::game::state::{GamePhase, GameState, Player, BIG_BLIND, SMALL_BLIND};
+use crate::game::state::{
+    GameConfig, GamePhase, GameState, SeatOutcome, HUMAN,
+};
+use crate::game::view::PlayerView;
+use crate::session::{ActionLogEntry, HandTracking, SavedBot, SavedSession};
 use crate::stats::persistence::StatsStore;
 
 const DELAY_BOT_ACTION_MS: u64 = 2500;
+const DELAY_BOT_ACTION_MULTIWAY_MS: u64 = 1200;
 const DELAY_BOT_ACTION_AFTER_REVEAL_MS: u64 = 3500;
 const DELAY_CARD_REVEAL_MS: u64 = 500;
 const DELAY_CARD_REVEAL_AFTER_BOT_MS: u64 = 2500;
@@ -28,17 +38,24 @@ pub enum GameEvent {
     ShowResult,
 }
 
+/// Why a bot took one of its actions in a hand.
 #[derive(Debug, Clone)]
-pub struct ActionLogEntry {
-    pub street: String,
-    pub text: String,
+pub struct Explanation {
+    pub seat: usize,
+    pub street: &'static str,
+    pub reasoning: Reasoning,
 }
 
 pub struct App {
     pub game_state: GameState,
-    pub bot: RuleBasedBot,
+    /// One bot per opponent seat: `bots[0]` plays seat 1, and so on.
+    pub bots: Vec<Box<dyn Strategy>>,
+    /// How each bot was set up, or None for one started elsewhere, which a
+    /// save can't bring back.
+    bot_configs: Vec<Option<BotConfig>>,
     pub show_help: bool,
     pub show_stats: bool,
+    pub show_reasoning: bool,
     pub raise_input: String,
     pub message: Option<String>,
     pub action_log: Vec<ActionLogEntry>,
@@ -46,34 +63,102 @@ pub struct App {
     pub next_event_at: Option<Instant>,
     pub raise_mode: bool,
     pub visible_board_len: usize,
-    pub visible_player_bet: u32,
-    pub visible_bot_bet: u32,
-    pub player_last_action: Option<Action>,
-    pub bot_last_action: Option<Action>,
+    /// Bets shown in front of each seat, lagging the engine for pacing.
+    pub visible_bets: Vec<u32>,
+    /// Last action of each seat on the current street.
+    pub last_actions: Vec<Option<Action>>,
     pub bot_thinking: bool,
     pub tick_count: u64,
     pub thinking_start_tick: u64,
     pub showdown_revealed: bool,
     pub showdown_result_shown: bool,
-    starting_stack_bb: u32,
-    last_phase: GamePhase,
-    saw_flop_this_hand: bool,
-    recorded_vpip_this_hand: bool,
-    three_bet_opportunity_recorded: bool,
-    player_raised_preflop: bool,
-    cbet_opportunity_recorded: bool,
-    facing_cbet: bool,
+    config: GameConfig,
+    tracking: HandTracking,
+    profit_recorded_bb: f64,
+    /// The bots' reasoning in the hand being played, and in the one before.
+    explanations: Vec<Explanation>,
+    last_explanations: Vec<Explanation>,
 }
 
 impl App {
-    pub fn new(starting_stack_bb: u32, aggression: f64) -> Self {
-        let game_state = GameState::new(starting_stack_bb);
-        let initial_phase = game_state.phase;
+    /// Create a new session. With a `seed`, the cards and the bot's choices
+    /// are reproducible: the same seed and the same human actions replay the
+    /// same session.
+    pub fn new(config: GameConfig, aggression: f64, seed: Option<u64>) -> Self {
+        Self::with_bot(config, BotKind::default(), aggression, seed)
+    }
+
+    /// Create a new session against bots of the given kind.
+    pub fn with_bot(config: GameConfig, bot: BotKind, aggression: f64, seed: Option<u64>) -> Self {
+        Self::with_bot_config(config, BotConfig::new(bot, aggression), seed)
+    }
+
+    /// Create a new session against rule-based bots playing as `personality`.
+    pub fn with_opponent(config: GameConfig, personality: Personality, seed: Option<u64>) -> Self {
+        Self::with_bot_config(config, BotConfig::playing_as(personality), seed)
+    }
+
+    /// Create a new session against bots started elsewhere, one for each
+    /// opponent seat in turn. Such a session can't be saved.
+    pub fn with_strategies(
+        config: GameConfig,
+        bots: Vec<Box<dyn Strategy>>,
+        seed: Option<u64>,
+    ) -> Result<Self, String> {
+        let (game_state, _) = Self::deal(config.clone(), seed);
+        let opponents = game_state.num_seats() - 1;
+        if bots.len() != opponents {
+            return Err(format!(
+                "{} bots for {} opponent seats",
+                bots.len(),
+                opponents
+            ));
+        }
+        let bot_configs = vec![None; opponents];
+        Ok(Self::with_parts(config, game_state, bots, bot_configs))
+    }
+
+    /// Create a new session with a bot built from `bot` in every opponent
+    /// seat, given its own seed if the session has one.
+    fn with_bot_config(config: GameConfig, bot: BotConfig, seed: Option<u64>) -> Self {
+        let (game_state, mut seeds) = Self::deal(config.clone(), seed);
+        let opponents = game_state.num_seats() - 1;
+        let bots = (0..opponents)
+            .map(|_| bot.create(seeds.as_mut().map(|rng| rng.gen())))
+            .collect();
+        Self::with_parts(config, game_state, bots, vec![Some(bot); opponents])
+    }
+
+    /// The first hand of a new session. With a `seed`, also the RNG the bots'
+    /// seeds are drawn from.
+    fn deal(config: GameConfig, seed: Option<u64>) -> (GameState, Option<StdRng>) {
+        match seed {
+            Some(seed) => {
+                // Derive independent streams so the deal order does not depend
+                // on how many random draws the bots happen to make.
+                let mut master = StdRng::seed_from_u64(seed);
+                let game_state =
+                    GameState::with_config(config, StdRng::seed_from_u64(master.gen()));
+                (game_state, Some(master))
+            }
+            None => (GameState::with_config(config, StdRng::from_entropy()), None),
+        }
+    }
+
+    fn with_parts(
+        config: GameConfig,
+        game_state: GameState,
+        bots: Vec<Box<dyn Strategy>>,
+        bot_configs: Vec<Option<BotConfig>>,
+    ) -> Self {
+        let seats = game_state.num_seats();
         Self {
             game_state,
-            bot: RuleBasedBot::new(aggression),
+            bots,
+            bot_configs,
             show_help: false,
             show_stats: false,
+            show_reasoning: false,
             raise_input: String::new(),
             message: None,
             action_log: Vec::new(),
@@ -81,31 +166,89 @@ impl App {
             next_event_at: None,
             raise_mode: false,
             visible_board_len: 0,
-
-            visible_player_bet: 0,
-            visible_bot_bet: 0,
-            player_last_action: None,
-            bot_last_action: None,
+            visible_bets: vec![0; seats],
+            last_actions: vec![None; seats],
             bot_thinking: false,
             tick_count: 0,
             thinking_start_tick: 0,
             showdown_revealed: false,
             showdown_result_shown: false,
-            starting_stack_bb,
-            last_phase: initial_phase,
-            saw_flop_this_hand: false,
-            recorded_vpip_this_hand: false,
-            three_bet_opportunity_recorded: false,
-            player_raised_preflop: false,
-            cbet_opportunity_recorded: false,
-            facing_cbet: false,
+            config,
+            tracking: HandTracking::default(),
+            profit_recorded_bb: 0.0,
+            explanations: Vec::new(),
+            last_explanations: Vec::new(),
         }
     }
 
+    /// Rebuild a session saved by `snapshot`. Call `resume` to start play.
+    pub fn from_save(saved: SavedSession) -> Result<Self, String> {
+        let opponents = saved.game_state.num_seats() - 1;
+        if saved.bots.len() != opponents {
+            return Err(format!(
+                "the save has {} bots for {} opponent seats",
+                saved.bots.len(),
+                opponents
+            ));
+        }
+        let mut game_state = saved.game_state;
+        game_state.rng = StdRng::seed_from_u64(saved.rng_seed);
+        let bots = saved
+            .bots
+            .iter()
+            .map(|saved| saved.bot.create(Some(saved.rng_seed)))
+            .collect();
+        let bot_configs = saved.bots.iter().map(|saved| Some(saved.bot)).collect();
+        let mut app = Self::with_parts(saved.config, game_state, bots, bot_configs);
+        app.action_log = saved.action_log;
+        app.tracking = saved.tracking;
+        app.visible_board_len = saved.visible_board_len;
+        app.showdown_revealed = saved.showdown_revealed;
+        app.profit_recorded_bb = saved.profit_recorded_bb;
+        Ok(app)
+    }
+
+    /// Whether every bot can be rebuilt from a save, so the session can be
+    /// resumed.
+    pub fn can_save(&self) -> bool {
+        self.bot_configs.iter().all(Option::is_some)
+    }
+
+    /// Capture the session for `--resume`, or None if it can't be saved.
+    /// The card and bot RNGs are restarted from seeds stored in the save, so
+    /// a seeded session plays on identically whether or not it was saved in
+    /// between.
+    pub fn snapshot(&mut self) -> Option<SavedSession> {
+        let configs: Vec<BotConfig> = self.bot_configs.iter().copied().collect::<Option<_>>()?;
+        let bots: Vec<SavedBot> = configs
+            .into_iter()
+            .map(|bot| SavedBot {
+                bot,
+                rng_seed: self.game_state.rng.gen(),
+            })
+            .collect();
+        self.bots = bots
+            .iter()
+            .map(|saved| saved.bot.create(Some(saved.rng_seed)))
+            .collect();
+        Some(SavedSession {
+            config: self.config.clone(),
+            rng_seed: self.game_state.reseed(),
+            game_state: self.game_state.clone(),
+            bots,
+            action_log: self.action_log.clone(),
+            tracking: self.tracking,
+            visible_board_len: self.visible_board_len,
+            showdown_revealed: self.showdown_revealed,
+            profit_recorded_bb: self.profit_recorded_bb,
+        })
+    }
+
     pub fn toggle_help(&mut self) {
         self.show_help = !self.show_help;
         if self.show_help {
             self.show_stats = false;
+            self.show_reasoning = false;
         }
     }
 
@@ -113,29 +256,57 @@ impl App {
         self.show_stats = !self.show_stats;
         if self.show_stats {
             self.show_help = false;
+            self.show_reasoning = false;
+        }
+    }
+
+    pub fn toggle_reasoning(&mut self) {
+        self.show_reasoning = !self.show_reasoning;
+        if self.show_reasoning {
+            self.show_help = false;
+            self.show_stats = false;
+        }
+    }
+
+    /// Whether any overlay is open over the table.
+    pub fn overlay_open(&self) -> bool {
+        self.show_help || self.show_stats || self.show_reasoning
+    }
+
+    /// The bots' reasoning for the last hand that finished: this one once
+    /// it's over, otherwise the one before. A hand in play is kept hidden,
+    /// since it would give away the bots' cards.
+    pub fn explanations(&self) -> &[Explanation] {
+        let over = match self.game_state.phase {
+            GamePhase::Showdown => self.showdown_revealed,
+            GamePhase::HandComplete | GamePhase::SessionEnd | GamePhase::Summary => true,
+            _ => false,
+        };
+        if over {
+            &self.explanations
+        } else {
+            &self.last_explanations
         }
     }
 
     pub fn new_session(&mut self, stats: &mut StatsStore) {
-        self.game_state = GameState::new(self.starting_stack_bb);
-        self.last_phase = self.game_state.phase;
-        self.saw_flop_this_hand = false;
-        self.recorded_vpip_this_hand = false;
-        self.three_bet_opportunity_recorded = false;
-        self.player_raised_preflop = false;
-        self.cbet_opportunity_recorded = false;
-        self.facing_cbet = false;
+        // Continue the existing card stream so seeded runs stay reproducible
+        let rng = self.game_state.rng.clone();
+        self.game_state = GameState::with_config(self.config.clone(), rng);
+        self.tracking = HandTracking::default();
+        self.profit_recorded_bb = 0.0;
         self.action_log.clear();
         self.pending_events.clear();
         self.next_event_at = None;
         self.raise_mode = false;
         self.raise_input.clear();
         self.visible_board_len = 0;
-        self.player_last_action = None;
-        self.bot_last_action = None;
+        self.clear_last_actions();
         self.bot_thinking = false;
         self.showdown_revealed = false;
         self.showdown_result_shown = false;
+        self.explanations.clear();
+        self.last_explanations.clear();
         self.message = Some("New session started!".to_string());
         self.initialize(stats);
     }
@@ -144,6 +315,33 @@ impl App {
         !self.pending_events.is_empty()
     }
 
+    /// Short name used in the action log and on screen.
+    pub fn seat_name(&self, seat: usize) -> String {
+        if seat == HUMAN {
+            "You".to_string()
+        } else if self.game_state.is_heads_up() {
+            "Opp".to_string()
+        } else {
+            format!("Bot {}", seat)
+        }
+    }
+
+    /// The player type the bots are playing as, if they were given one.
+    pub fn opponent_profile(&self) -> Option<Personality> {
+        self.bot_configs.first().copied().flatten()?.personality
+    }
+
+    fn clear_last_actions(&mut self) {
+        self.last_actions.iter_mut().for_each(|a| *a = None);
+    }
+
+    /// Show every seat's current bet.
+    fn sync_visible_bets(&mut self) {
+        for (visible, seat) in self.visible_bets.iter_mut().zip(&self.game_state.seats) {
+            *visible = seat.bet;
+        }
+    }
+
     fn phase_name(phase: GamePhase) -> &'static str {
         match phase {
             GamePhase::Preflop => "Pre-Flop",
@@ -178,15 +376,15 @@ impl App {
             _ => false,
         };
 
-        // 3-bet tracking: preflop, facing bot's raise, player hasn't raised yet
+        // 3-bet tracking: preflop, facing a bot's raise, player hasn't raised yet
         // Must run BEFORE player_raised_preflop is set below
-        if !self.three_bet_opportunity_recorded
+        if !self.tracking.three_bet_opportunity_recorded
             && self.game_state.board.is_empty()
-            && self.game_state.last_aggressor == Some(Player::Bot)
-            && self.game_state.amount_to_call(Player::Human) > 0
-            && !self.player_raised_preflop
+            && self.game_state.last_aggressor.is_some_and(|s| s != HUMAN)
+            && self.game_state.amount_to_call(HUMAN) > 0
+            && !self.tracking.player_raised_preflop
         {
-            self.three_bet_opportunity_recorded = true;
+            self.tracking.three_bet_opportunity_recorded = true;
             stats.record_three_bet_opportunity();
             if is_aggressive_action {
                 stats.record_three_bet();
@@ -195,29 +393,29 @@ impl App {
 
         // Track whether player has raised preflop (to distinguish 3-bet from 4-bet+)
         if self.game_state.board.is_empty() && is_aggressive_action {
-            self.player_raised_preflop = true;
+            self.tracking.player_raised_preflop = true;
         }
 
         // C-bet tracking: flop, player was preflop aggressor, no bet yet this street
-        if !self.cbet_opportunity_recorded
+        if !self.tracking.cbet_opportunity_recorded
             && self.game_state.phase == GamePhase::Flop
-            && self.game_state.preflop_aggressor == Some(Player::Human)
+            && self.game_state.preflop_aggressor == Some(HUMAN)
             && self.game_state.last_aggressor.is_none()
         {
-            self.cbet_opportunity_recorded = true;
+            self.tracking.cbet_opportunity_recorded = true;
             stats.record_cbet_opportunity();
             if is_aggressive_action {
                 stats.record_cbet();
             }
         }
 
-        // Fold-to-cbet tracking: player faces a c-bet from the bot
-        if self.facing_cbet {
+        // Fold-to-cbet tracking: player faces a c-bet from a bot
+        if self.tracking.facing_cbet {
             stats.record_fold_to_cbet_opportunity();
             if matches!(action, Action::Fold) {
                 stats.record_fold_to_cbet();
             }
-            self.facing_cbet = false;
+            self.tracking.facing_cbet = false;
         }
 
         // Record action type stats
@@ -252,25 +450,25 @@ impl App {
         }
 
         // VPIP: only track preflop voluntary money, once per hand
-        if !self.recorded_vpip_this_hand
+        if !self.tracking.recorded_vpip
             && self.game_state.board.is_empty()
             && !matches!(action, Action::Fold | Action::Check)
         {
             stats.record_vpip();
-            self.recorded_vpip_this_hand = true;
+            self.tracking.recorded_vpip = true;
         }
 
         self.raise_mode = false;
         self.raise_input.clear();
-        self.player_last_action = Some(action);
+        self.last_actions[HUMAN] = Some(action);
 
         // Snapshot visible state before apply_action (which may advance phase and clear bets/pot)
-        self.visible_player_bet = self.projected_bet(Player::Human, action);
-        self.visible_bot_bet = self.game_state.bot_bet;
+        self.sync_visible_bets();
+        self.visible_bets[HUMAN] = self.projected_bet(HUMAN, action);
 
         let street = Self::phase_name(self.game_state.phase);
-        let desc = action.description_for("You");
-        self.game_state.apply_action(Player::Human, action);
+        let desc = action.description_for("You", self.game_state.big_blind);
+        self.game_state.apply_action(HUMAN, action);
         self.log_action(street, format!("You {}", desc));
         self.message = Some(format!("You {}", desc));
 
@@ -286,68 +484,66 @@ impl App {
         }
 
         // Track flop stat
-        if !self.saw_flop_this_hand && self.game_state.board.len() >= 3 {
-            self.saw_flop_this_hand = true;
+        if !self.tracking.saw_flop
+            && self.game_state.board.len() >= 3
+            && self.game_state.seats[HUMAN].is_active()
+        {
+            self.tracking.saw_flop = true;
             stats.record_saw_flop();
         }
 
-        // Detect street transitions for extra pause
-        let phase_changed = self.game_state.phase != self.last_phase;
-        if phase_changed {
-            self.last_phase = self.game_state.phase;
-        }
-
         match self.game_state.phase {
             GamePhase::HandComplete => {
                 // Log the fold result
-                if let Some((player, _)) = self.game_state.last_action {
-                    let winner_text = if player == Player::Bot {
-                        "You win the pot"
+                if let Some(&winner) = self.game_state.active_seats().first() {
+                    let winner_text = if winner == HUMAN {
+                        "You win the pot".to_string()
                     } else {
-                        "Opp wins the pot"
+                        format!("{} wins the pot", self.seat_name(winner))
                     };
-                    self.log_action("", winner_text.to_string());
+                    self.log_action("", winner_text);
                 }
-                if self.game_state.player_stack > 0 && self.game_state.bot_stack > 0 {
+                if !self.game_state.is_session_over() {
                     self.pending_events.push_back(GameEvent::StartNewHand);
                     self.next_event_at =
                         Some(Instant::now() + Duration::from_millis(DELAY_NEW_HAND_MS));
                 }
                 // else: session over, main loop detects busted stacks on HandComplete
             }
-            GamePhase::Showdown => {
-                self.pending_events
-                    .push_back(GameEvent::RevealShowdown);
-                self.next_event_at =
-                    Some(Instant::now() + Duration::from_millis(DELAY_SHOWDOWN_REVEAL_MS));
-            }
             GamePhase::SessionEnd | GamePhase::Summary => {
                 // Terminal states — nothing to enqueue
             }
             _ => {
-                if phase_changed && self.visible_board_len < self.game_state.board.len() {
+                if self.visible_board_len < self.game_state.board.len() {
                     // Street transition delay
-                    let reveal_delay =
-                        if self.game_state.player_stack == 0 || self.game_state.bot_stack == 0 {
-                            // All-in runout: consistent pacing between streets
-                            DELAY_ALLIN_RUNOUT_MS
-                        } else if self.game_state.last_action.map(|(p, _)| p) == Some(Player::Bot)
-                        {
-                            // Normal: longer pause after bot's closing action
-                            DELAY_CARD_REVEAL_AFTER_BOT_MS
-                        } else {
-                            DELAY_CARD_REVEAL_MS
-                        };
+                    let reveal_delay = if self.game_state.is_all_in_runout() {
+                        // All-in runout: consistent pacing between streets
+                        DELAY_ALLIN_RUNOUT_MS
+                    } else if self.game_state.last_action.is_some_and(|(s, _)| s != HUMAN) {
+                        // Normal: longer pause after a bot's closing action
+                        DELAY_CARD_REVEAL_AFTER_BOT_MS
+                    } else {
+                        DELAY_CARD_REVEAL_MS
+                    };
                     self.pending_events.push_back(GameEvent::RevealCards);
                     self.next_event_at =
                         Some(Instant::now() + Duration::from_millis(reveal_delay));
-                } else if self.game_state.to_act == Player::Bot {
-                    self.pending_events.push_back(GameEvent::BotAction);
+                } else if self.game_state.phase == GamePhase::Showdown {
+                    self.pending_events.push_back(GameEvent::RevealShowdown);
                     self.next_event_at =
-                        Some(Instant::now() + Duration::from_millis(DELAY_BOT_ACTION_MS));
+                        Some(Instant::now() + Duration::from_millis(DELAY_SHOWDOWN_REVEAL_MS));
+                } else if self.game_state.to_act != HUMAN {
+                    let delay = if self.game_state.is_heads_up() {
+                        DELAY_BOT_ACTION_MS
+                    } else {
+                        // Several bots act per orbit; keep the table moving
+                        DELAY_BOT_ACTION_MULTIWAY_MS
+                    };
+                    self.pending_events.push_back(GameEvent::BotAction);
+                    self.next_event_at = Some(Instant::now() + Duration::from_millis(delay));
                     self.bot_thinking = true;
                     self.thinking_start_tick = self.tick_count;
-                    self.bot_last_action = None;
+                    self.last_actions[self.game_state.to_act] = None;
                 }
                 // else: player's turn, wait for input
             }
@@ -376,9 +572,18 @@ impl App {
         match event {
             GameEvent::BotAction => {
                 self.bot_thinking = false;
+                let seat = self.game_state.to_act;
                 let street = Self::phase_name(self.game_state.phase);
-                let bot_action = self.bot.decide(&self.game_state);
-                self.bot_last_action = Some(bot_action);
+                let view = PlayerView::new(&self.game_state, seat);
+                let (bot_action, reasoning) = self.bots[seat - 1].decide_explained(&view);
+                if let Some(reasoning) = reasoning {
+                    self.explanations.push(Explanation {
+                        seat,
+                        street,
+                        reasoning,
+                    });
+                }
+                self.last_actions[seat] = Some(bot_action);
 
                 // Detect bot c-bet: flop, bot was preflop aggressor, no bet yet, aggressive action
                 let bot_is_aggressive = match bot_action {
@@ -387,46 +592,49 @@ impl App {
                     _ => false,
                 };
                 if self.game_state.phase == GamePhase::Flop
-                    && self.game_state.preflop_aggressor == Some(Player::Bot)
+                    && self.game_state.preflop_aggressor == Some(seat)
                     && self.game_state.last_aggressor.is_none()
                     && bot_is_aggressive
+                    && self.game_state.seats[HUMAN].is_active()
                 {
-                    self.facing_cbet = true;
+                    self.tracking.facing_cbet = true;
                 }
 
                 // Snapshot visible bets before apply_action (which may advance phase and clear bets)
-                self.visible_bot_bet = self.projected_bet(Player::Bot, bot_action);
-                self.visible_player_bet = self.game_state.player_bet;
-
-                let desc = bot_action.description_for("Opp");
-                self.game_state.apply_action(Player::Bot, bot_action);
-                self.log_action(street, format!("Opp {}", desc));
-                self.message = Some(format!("Opp {}", desc));
+                self.sync_visible_bets();
+                self.visible_bets[seat] = self.projected_bet(seat, bot_action);
+
+                let name = self.seat_name(seat);
+                let desc = bot_action.description_for(&name, self.game_state.big_blind);
+                self.game_state.apply_action(seat, bot_action);
+                self.log_action(street, format!("{} {}", name, desc));
+                self.message = Some(format!("{} {}", name, desc));
             }
             GameEvent::StartNewHand => {
-                self.saw_flop_this_hand = false;
-                self.recorded_vpip_this_hand = false;
-                self.three_bet_opportunity_recorded = false;
-                self.player_raised_preflop = false;
-                self.cbet_opportunity_recorded = false;
-                self.facing_cbet = false;
+                self.tracking = HandTracking::default();
                 stats.record_hand_start();
                 self.raise_mode = false;
                 self.raise_input.clear();
-                self.player_last_action = None;
-                self.bot_last_action = None;
+                self.clear_last_actions();
                 self.showdown_revealed = false;
                 self.showdown_result_shown = false;
+                let previous_big_blind = self.game_state.big_blind;
+                self.last_explanations = std::mem::take(&mut self.explanations);
+                self.end_bot_hands();
                 self.game_state.start_new_hand();
+                self.start_bot_hands();
                 self.visible_board_len = 0;
-                self.visible_player_bet = 0;
-                self.visible_bot_bet = 0;
-                self.last_phase = self.game_state.phase;
+                self.visible_bets.iter_mut().for_each(|b| *b = 0);
                 // Add a separator for the new hand in the historical log
                 self.action_log.push(ActionLogEntry {
                     street: String::new(),
                     text: format!("── Hand #{} ──", self.game_state.hand_number),
                 });
+                if self.game_state.big_blind != previous_big_blind {
+                    let text = format!("Blinds up: {}", self.game_state.stakes_label());
+                    self.message = Some(text.clone());
+                    self.log_action("", text);
+                }
                 self.log_blinds();
                 self.pending_events.push_back(GameEvent::PostSmallBlind);
                 self.next_event_at =
@@ -434,57 +642,54 @@ impl App {
                 return;
             }
             GameEvent::PostSmallBlind => {
-                match self.game_state.button {
-                    Player::Human => self.visible_player_bet = self.game_state.player_bet,
-                    Player::Bot => self.visible_bot_bet = self.game_state.bot_bet,
-                }
+                let sb = self.game_state.small_blind_seat;
+                self.visible_bets[sb] = self.game_state.seats[sb].committed;
                 self.pending_events.push_back(GameEvent::PostBigBlind);
                 self.next_event_at =
                     Some(Instant::now() + Duration::from_millis(DELAY_POST_BB_MS));
                 return;
             }
             GameEvent::PostBigBlind => {
-                match self.game_state.button {
-                    Player::Human => self.visible_bot_bet = self.game_state.bot_bet,
-                    Player::Bot => self.visible_player_bet = self.game_state.player_bet,
-                }
+                let bb = self.game_state.big_blind_seat;
+                self.visible_bets[bb] = self.game_state.seats[bb].committed;
             }
             GameEvent::RevealCards => {
-                self.visible_board_len = self.game_state.board.len();
-                self.visible_player_bet = 0;
-                self.visible_bot_bet = 0;
-                self.player_last_action = None;
-                self.bot_last_action = None;
-
-                // All-in runout: skip betting and advance to next street
-                if self.game_state.player_stack == 0 || self.game_state.bot_stack == 0 {
-                    self.game_state.advance_phase();
-                    // Fall through to enqueue_next_events which queues
-                    // the next RevealCards or RevealShowdown
+                // An all-in runout deals the whole board at once; show it a
+                // street at a time
+                let next_street = if self.visible_board_len < 3 {
+                    3
                 } else {
-                    // Normal: check if bot should act next
-                    if self.game_state.to_act == Player::Bot {
-                        self.pending_events.push_back(GameEvent::BotAction);
-                        self.next_event_at =
-                            Some(Instant::now() + Duration::from_millis(DELAY_BOT_ACTION_AFTER_REVEAL_MS));
-                        self.bot_thinking = true;
-                        self.thinking_start_tick = self.tick_count;
-                        return;
-                    }
+                    self.visible_board_len + 1
+                };
+                self.visible_board_len = next_street.min(self.game_state.board.len());
+                self.visible_bets.iter_mut().for_each(|b| *b = 0);
+                self.clear_last_actions();
+
+                // Check if a bot should act on the new street. Otherwise fall
+                // through to enqueue_next_events, which queues the next
+                // RevealCards or RevealShowdown
+                if self.game_state.phase != GamePhase::Showdown && self.game_state.to_act != HUMAN {
+                    self.pending_events.push_back(GameEvent::BotAction);
+                    self.next_event_at =
+                        Some(Instant::now() + Duration::from_millis(DELAY_BOT_ACTION_AFTER_REVEAL_MS));
+                    self.bot_thinking = true;
+                    self.thinking_start_tick = self.tick_count;
+                    return;
                 }
             }
             GameEvent::RevealShowdown => {
                 self.showdown_revealed = true;
-                self.player_last_action = None;
-                self.bot_last_action = None;
-                // Record stats
+                self.clear_last_actions();
+                // Record stats (only if the player was still in the hand)
                 if let Some(ref result) = self.game_state.showdown_result {
-                    let won = result.winner == Some(Player::Human);
-                    stats.record_showdown(won);
-                    if won {
-                        stats.record_pot_won(result.pot_won);
-                    } else if result.winner == Some(Player::Bot) {
-                        stats.record_pot_lost(result.pot_won);
+                    if result.hand(HUMAN).is_some() {
+                        let outcome = result.outcome(HUMAN);
+                        stats.record_showdown(outcome == SeatOutcome::Won);
+                        match outcome {
+                            SeatOutcome::Won => stats.record_pot_won(result.pot_won),
+                            SeatOutcome::Lost => stats.record_pot_lost(result.pot_won),
+                            SeatOutcome::Split => {}
+                        }
                     }
                 }
                 self.pending_events.push_back(GameEvent::ShowResult);
@@ -505,12 +710,17 @@ impl App {
     }
 
     fn log_blinds(&mut self) {
-        let sb_bb = SMALL_BLIND as f64 / BIG_BLIND as f64;
-        let (sb_player, bb_player) = if self.game_state.button == Player::Human {
-            ("You", "Opp")
-        } else {
-            ("Opp", "You")
-        };
+        let state = &self.game_state;
+        let sb_bb = state.small_blind as f64 / state.big_blind as f64;
+        let ante_bb = state.ante as f64 / state.big_blind as f64;
+        let sb_player = self.seat_name(state.small_blind_seat);
+        let bb_player = self.seat_name(state.big_blind_seat);
+        if ante_bb > 0.0 && state.big_blind_ante {
+            let text = format!("{} post BB ante ({:.2}BB)", bb_player, ante_bb);
+            self.log_action("Pre-Flop", text);
+        } else if ante_bb > 0.0 {
+            self.log_action("Pre-Flop", format!("All post ante ({:.2}BB)", ante_bb));
+        }
         self.log_action(
             "Pre-Flop",
             format!("{} post SB ({:.1}BB)", sb_player, sb_bb),
@@ -519,24 +729,68 @@ impl App {
     }
 
     pub fn initialize(&mut self, stats: &mut StatsStore) {
+        self.show_bots_stats(stats);
         stats.record_hand_start();
-        self.visible_player_bet = 0;
-        self.visible_bot_bet = 0;
+        self.start_bot_hands();
+        self.visible_bets.iter_mut().for_each(|b| *b = 0);
         self.log_blinds();
         self.pending_events.push_back(GameEvent::PostSmallBlind);
         self.next_event_at = Some(Instant::now() + Duration::from_millis(DELAY_POST_SB_MS));
     }
 
-    /// Compute what a player's bet will be after an action, before apply_action clears it.
-    fn projected_bet(&self, player: Player, action: Action) -> u32 {
-        let current = match player {
-            Player::Human => self.game_state.player_bet,
-            Player::Bot => self.game_state.bot_bet,
-        };
-        let stack = match player {
-            Player::Human => self.game_state.player_stack,
-            Player::Bot => self.game_state.bot_stack,
-        };
+    /// Pick a saved session back up where it was left.
+    pub fn resume(&mut self, stats: &mut StatsStore) {
+        self.show_bots_stats(stats);
+        self.sync_visible_bets();
+        self.message = Some(format!(
+            "Session resumed at hand #{}",
+            self.game_state.hand_number
+        ));
+        match self.game_state.phase {
+            GamePhase::HandComplete if !self.game_state.is_session_over() => {
+                self.pending_events.push_back(GameEvent::StartNewHand);
+                self.next_event_at =
+                    Some(Instant::now() + Duration::from_millis(DELAY_NEW_HAND_MS));
+            }
+            GamePhase::Showdown if self.showdown_revealed => {
+                self.showdown_result_shown = true;
+            }
+            _ if self.visible_board_len < self.game_state.board.len() => {
+                self.pending_events.push_back(GameEvent::RevealCards);
+                self.next_event_at =
+                    Some(Instant::now() + Duration::from_millis(DELAY_CARD_REVEAL_MS));
+            }
+            _ => self.enqueue_next_events(stats),
+        }
+    }
+
+    /// Add the session profit not yet counted to the lifetime stats.
+    /// Tournament chips aren't money: a tournament only counts its finish.
+    pub fn record_profit(&mut self, stats: &mut StatsStore) {
+        if self.game_state.tournament.is_some() {
+            return;
+        }
+        let profit = self.game_state.session_profit_bb();
+        stats.record_profit(profit - self.profit_recorded_bb);
+        self.profit_recorded_bb = profit;
+    }
+
+    /// The player is leaving. Returns the session to save, unless it is
+    /// already over or can't be saved.
+    pub fn quit(&mut self, stats: &mut StatsStore) -> Option<SavedSession> {
+        stats.record_session_end();
+        self.record_profit(stats);
+        if self.game_state.is_session_over() {
+            None
+        } else {
+            self.snapshot()
+        }
+    }
+
+    /// Compute what a seat's bet will be after an action, before apply_action clears it.
+    fn projected_bet(&self, seat: usize, action: Action) -> u32 {
+        let current = self.game_state.seats[seat].bet;
+        let stack = self.game_state.seats[seat].stack;
         match action {
             Action::Fold | Action::Check => current,
             Action::Call(amount) => current + amount.min(stack),
@@ -551,14 +805,44 @@ impl App {
         }
     }
 
-    pub fn continue_after_showdown(&mut self, _stats: &mut StatsStore) {
+    /// Give the bots the player's lifetime stats to read.
+    fn show_bots_stats(&mut self, stats: &StatsStore) {
+        for bot in &mut self.bots {
+            bot.player_stats(&stats.stats);
+        }
+    }
+
+    /// Show each bot the hand just dealt, from its own seat.
+    fn start_bot_hands(&mut self) {
+        for (i, bot) in self.bots.iter_mut().enumerate() {
+            bot.hand_started(&PlayerView::new(&self.game_state, i + 1));
+        }
+    }
+
+    /// Show each bot how the hand ended, from its own seat.
+    fn end_bot_hands(&mut self) {
+        for (i, bot) in self.bots.iter_mut().enumerate() {
+            bot.hand_ended(&PlayerView::new(&self.game_state, i + 1));
+        }
+    }
+
+    /// Someone ran out of chips. Tournaments record where the player finished.
+    pub fn end_session(&mut self, stats: &mut StatsStore) {
+        self.end_bot_hands();
+        if self.game_state.tournament.is_some() {
+            stats.record_tournament_finish(self.game_state.finishing_position());
+        }
+        self.game_state.phase = GamePhase::SessionEnd;
+    }
+
+    pub fn continue_after_showdown(&mut self, stats: &mut StatsStore) {
         if self.game_state.phase == GamePhase::Showdown && self.showdown_result_shown {
             self.pending_events.clear();
-            if self.game_state.player_stack > 0 && self.game_state.bot_stack > 0 {
+            if !self.game_state.is_session_over() {
                 self.pending_events.push_back(GameEvent::StartNewHand);
                 self.next_event_at = Some(Instant::now()); // immediate — user pressed key
             } else {
-                self.game_state.phase = GamePhase::SessionEnd;
+                self.end_session(stats);
                 self.next_event_at = None;
             }
         }
@@ -1,5 +1,6 @@
 use crate::game::actions::Action;
-use crate::game::state::{GameState, Player, BIG_BLIND};
+use crate::game::state::{GameState, HUMAN};
+use crate::game::variant::BettingLimit;
 use crossterm::event::{KeyCode, KeyEvent};
 
 pub fn handle_key(
@@ -13,7 +14,8 @@ pub fn handle_key(
     }
 
     let available = game_state.available_actions();
-    let stack = game_state.player_stack;
+    let stack = game_state.seats[HUMAN].stack;
+    let limit = game_state.variant.limit();
 
     // When in raise mode, only raise-related keys are accepted
     if *raise_mode {
@@ -41,10 +43,26 @@ pub fn handle_key(
             }
         }
 
-        // All-in
+        // All-in, or a pot-sized bet when pot-limit caps it. Limit has no
+        // all-in key: bets only come in the fixed size.
         KeyCode::Char('a') | KeyCode::Char('A') => {
-            if stack > 0 {
-                Some(Action::AllIn(game_state.player_bet + stack))
+            let to_call = game_state.amount_to_call(HUMAN);
+            if stack == 0 || limit == BettingLimit::FixedLimit {
+                None
+            } else if to_call > 0 && available.min_raise.is_none() {
+                // A short all-in left the betting closed: calling is as far
+                // as we can go
+                available.can_call.map(Action::Call)
+            } else {
+                Some(bet_or_raise_to(available.max_raise, game_state, to_call, stack))
+            }
+        }
+
+        // Limit: bet or raise the fixed amount straight away
+        KeyCode::Char('r') | KeyCode::Char('R') if limit == BettingLimit::FixedLimit => {
+            if available.min_raise.is_some() || available.min_bet.is_some() {
+                let to_call = game_state.amount_to_call(HUMAN);
+                Some(bet_or_raise_to(available.max_raise, game_state, to_call, stack))
             } else {
                 None
             }
@@ -70,8 +88,8 @@ fn handle_raise_mode_key(
     raise_mode: &mut bool,
 ) -> Option<Action> {
     let available = game_state.available_actions();
-    let to_call = game_state.amount_to_call(Player::Human);
-    let stack = game_state.player_stack;
+    let to_call = game_state.amount_to_call(HUMAN);
+    let stack = game_state.seats[HUMAN].stack;
 
     match key.code {
         // Digits: append to BB input
@@ -89,8 +107,8 @@ fn handle_raise_mode_key(
         // Up arrow: +1BB
         KeyCode::Up => {
             let current_bb = raise_input.parse::<u32>().unwrap_or(0);
-            let min_bb = min_raise_bb(&available);
-            let max_bb = (game_state.player_bet + stack) / BIG_BLIND;
+            let min_bb = min_raise_bb(&available, game_state.big_blind);
+            let max_bb = available.max_raise / game_state.big_blind;
             let new_bb = (current_bb + 1).min(max_bb).max(min_bb);
             *raise_input = new_bb.to_string();
             None
@@ -99,7 +117,7 @@ fn handle_raise_mode_key(
         // Down arrow: -1BB
         KeyCode::Down => {
             let current_bb = raise_input.parse::<u32>().unwrap_or(0);
-            let min_bb = min_raise_bb(&available);
+            let min_bb = min_raise_bb(&available, game_state.big_blind);
             let new_bb = if current_bb > min_bb {
                 current_bb - 1
             } else {
@@ -132,12 +150,12 @@ fn handle_raise_mode_key(
     }
 }
 
-fn min_raise_bb(available: &crate::game::actions::AvailableActions) -> u32 {
+fn min_raise_bb(available: &crate::game::actions::AvailableActions, big_blind: u32) -> u32 {
     let min_chips = available
         .min_raise
-        .unwrap_or(available.min_bet.unwrap_or(BIG_BLIND));
+        .unwrap_or(available.min_bet.unwrap_or(big_blind));
     // Convert chips to BB, rounding up
-    (min_chips + BIG_BLIND - 1) / BIG_BLIND
+    min_chips.div_ceil(big_blind)
 }
 
 fn submit_raise(
@@ -152,19 +170,56 @@ fn submit_raise(
         return None;
     }
 
-    let chips = typed_bb * BIG_BLIND;
+    let chips = typed_bb * game_state.big_blind;
     let min_raise = available
         .min_raise
-        .unwrap_or(available.min_bet.unwrap_or(BIG_BLIND));
-    let max_bet = game_state.player_bet + stack;
+        .unwrap_or(available.min_bet.unwrap_or(game_state.big_blind));
+    let actual = chips.max(min_raise).min(available.max_raise);
 
-    let actual = chips.max(min_raise).min(max_bet);
+    Some(bet_or_raise_to(actual, game_state, to_call, stack))
+}
 
-    if actual >= max_bet {
-        Some(Action::AllIn(max_bet))
+/// Bet or raise to `amount` total, or go all-in if that's the whole stack.
+fn bet_or_raise_to(amount: u32, game_state: &GameState, to_call: u32, stack: u32) -> Action {
+    let max_bet = game_state.seats[HUMAN].bet + stack;
+    if amount >= max_bet {
+        Action::AllIn(max_bet)
     } else if to_call > 0 {
-        Some(Action::Raise(actual))
+        Action::Raise(amount)
     } else {
-        Some(Action::Bet(actual))
+        Action::Bet(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::state::GameConfig;
+    use crossterm::event::KeyModifiers;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn press(game_state: &GameState, c: char) -> Option<Action> {
+        let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        handle_key(key, game_state, &mut String::new(), &mut false)
+    }
+
+    #[test]
+    fn test_all_in_key_calls_when_a_short_all_in_closed_the_betting() {
+        let config = GameConfig {
+            seats: 3,
+            ..GameConfig::heads_up(100)
+        };
+        let mut state = GameState::with_config(config, StdRng::seed_from_u64(3));
+        // We raise on the button, and the small blind's all-in is five short
+        // of a full raise
+        state.apply_action(HUMAN, Action::Raise(20));
+        state.seats[1].stack = 24;
+        state.apply_action(1, Action::AllIn(25));
+        state.apply_action(2, Action::Call(23));
+        assert!(state.is_player_turn());
+
+        assert_eq!(press(&state, 'a'), Some(Action::Call(5)));
+        assert_eq!(press(&state, 'r'), None);
     }
 }
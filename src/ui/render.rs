@@ -1,8 +1,11 @@
-use crate::game::actions::Action;
+use crate::game::actions::{format_bb, Action};
 use crate::game::deck::Card;
-use crate::game::state::{GamePhase, Player, BIG_BLIND};
+use crate::game::state::{GamePhase, SeatOutcome, HUMAN};
+use crate::game::tournament::ordinal;
+use crate::game::variant::BettingLimit;
 use crate::stats::models::STAT_DEFINITIONS;
 use crate::ui::app::App;
+use std::time::Instant;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -40,15 +43,6 @@ const CHIP_FLAT: Color = Color::Rgb(255, 255, 255);
 
 // ── Helpers ────────────────────────────────────────────────
 
-fn format_bb(chips: u32) -> String {
-    let bb = chips as f64 / BIG_BLIND as f64;
-    if bb == bb.floor() {
-        format!("{}BB", bb as u32)
-    } else {
-        format!("{:.1}BB", bb)
-    }
-}
-
 fn overlay_block(title: &str) -> Block<'_> {
     Block::default()
         .title(format!(" {} ", title))
@@ -211,36 +205,40 @@ pub fn render(frame: &mut Frame, app: &App) {
 
     render_status_bar(frame, app, chunks[0]);
     // chunks[1] = spacer
-    render_opponent_label(frame, app, chunks[2]);
-    // chunks[3] = spacer
-    render_opponent_stack(frame, app, chunks[4]);
-    render_opponent_cards(frame, app, chunks[5]);
-    // chunks[6] = spacer
-    // chunks[7] = bot action indicator / showdown result
-    if app.showdown_result_shown {
-        if let Some(ref result) = app.game_state.showdown_result {
-            let line = showdown_indicator_line(result.winner, Player::Bot, &result.bot_hand.description);
-            frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), chunks[7]);
-        }
-    } else if app.bot_thinking {
-        let elapsed = app.tick_count.wrapping_sub(app.thinking_start_tick);
-        let flower_frames = ["·", "✢", "✳", "✴", "✻", "✽", "✻", "✴", "✳", "✢"];
-        let flower_idx = ((elapsed / 3) % flower_frames.len() as u64) as usize;
-        frame.render_widget(
-            Paragraph::new(Line::from(Span::styled(
-                flower_frames[flower_idx],
-                Style::default().fg(Color::Rgb(150, 160, 230)),
+    if app.game_state.is_heads_up() {
+        render_opponent_label(frame, app, chunks[2]);
+        // chunks[3] = spacer
+        render_opponent_stack(frame, app, chunks[4]);
+        render_opponent_cards(frame, app, chunks[5]);
+        // chunks[6] = spacer
+        // chunks[7] = bot action indicator / showdown result
+        if app.showdown_result_shown {
+            if let Some(ref result) = app.game_state.showdown_result {
+                if let Some(hand) = result.hand(1) {
+                    let line = showdown_indicator_line(result.outcome(1), &hand.description);
+                    frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), chunks[7]);
+                }
+            }
+        } else if app.bot_thinking {
+            frame.render_widget(
+                Paragraph::new(Line::from(thinking_span(app))).alignment(Alignment::Center),
+                chunks[7],
+            );
+        } else if let Some(ref action) = app.last_actions[1] {
+            let paragraph = Paragraph::new(Line::from(Span::styled(
+                action_label(action),
+                Style::default().fg(Color::Rgb(255, 255, 255)),
             )))
-            .alignment(Alignment::Center),
-            chunks[7],
-        );
-    } else if let Some(ref action) = app.bot_last_action {
-        let paragraph = Paragraph::new(Line::from(Span::styled(
-            action_label(action),
-            Style::default().fg(Color::Rgb(255, 255, 255)),
-        )))
-        .alignment(Alignment::Center);
-        frame.render_widget(paragraph, chunks[7]);
+            .alignment(Alignment::Center);
+            frame.render_widget(paragraph, chunks[7]);
+        }
+    } else {
+        // Multiway: one column per opponent across the opponent rows
+        let area = Rect {
+            height: chunks[7].y + chunks[7].height - chunks[2].y,
+            ..chunks[2]
+        };
+        render_opponents_panel(frame, app, area);
     }
     // chunks[8] = spacer
     render_board_box(frame, app, chunks[9]);
@@ -260,6 +258,8 @@ pub fn render(frame: &mut Frame, app: &App) {
         render_stats_overlay(frame, app);
     } else if app.show_help {
         render_help_overlay(frame);
+    } else if app.show_reasoning {
+        render_reasoning_overlay(frame, app);
     } else {
         match app.game_state.phase {
             GamePhase::SessionEnd => render_session_end_overlay(frame, app),
@@ -281,20 +281,57 @@ fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
         ])
         .split(area);
 
-    let hand_num = Paragraph::new(Line::from(vec![
+    let mut hand_num = vec![
         Span::styled(" Hand ", Style::default().fg(DIM)),
         Span::styled(
             format!("#{}", app.game_state.hand_number),
             Style::default().fg(DIM),
         ),
-    ]));
+    ];
+    if let Some(personality) = app.opponent_profile() {
+        hand_num.push(Span::styled(" · vs ", Style::default().fg(DIM)));
+        hand_num.push(Span::styled(personality.name(), Style::default().fg(LABEL)));
+    }
+    let hand_num = Paragraph::new(Line::from(hand_num));
     frame.render_widget(hand_num, cols[0]);
 
+    if let Some(ref tournament) = app.game_state.tournament {
+        let state = &app.game_state;
+        let blinds = state.stakes_label();
+        let countdown = if let Some(left) = tournament.time_left(Instant::now()) {
+            let secs = left.as_secs();
+            format!(" · next in {}:{:02}", secs / 60, secs % 60)
+        } else if let Some(left) = tournament.hands_left(state.hands_played) {
+            format!(" · next in {} hand{}", left, if left == 1 { "" } else { "s" })
+        } else {
+            String::new()
+        };
+        let level = Paragraph::new(Line::from(vec![
+            Span::styled(
+                format!("Level {} ", tournament.level + 1),
+                Style::default().fg(GOLD).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(blinds, Style::default().fg(LABEL)),
+            Span::styled(countdown, Style::default().fg(DIM)),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(level, cols[1]);
+    } else {
+        let stakes = Paragraph::new(Span::styled(
+            app.game_state.stakes_label(),
+            Style::default().fg(DIM),
+        ))
+        .alignment(Alignment::Center);
+        frame.render_widget(stakes, cols[1]);
+    }
+
     let controls = Paragraph::new(Line::from(vec![
         Span::styled("S", Style::default().fg(Color::Blue)),
         Span::styled("tats ", Style::default().fg(DIM)),
         Span::styled("?", Style::default().fg(Color::Blue)),
         Span::styled("Help ", Style::default().fg(DIM)),
+        Span::styled("W", Style::default().fg(Color::Blue)),
+        Span::styled("hy ", Style::default().fg(DIM)),
         Span::styled("Q", Style::default().fg(ACTION_FOLD)),
         Span::styled("uit ", Style::default().fg(DIM)),
     ]))
@@ -315,11 +352,11 @@ fn render_opponent_label(frame: &mut Frame, _app: &App, area: Rect) {
 
 fn render_opponent_stack(frame: &mut Frame, app: &App, area: Rect) {
     let mut spans: Vec<Span<'static>> = vec![Span::styled(
-        format_bb(app.game_state.bot_stack),
+        format_bb(app.game_state.seats[1].stack, app.game_state.big_blind),
         Style::default().fg(GOLD),
     )];
 
-    if app.game_state.button == Player::Bot {
+    if app.game_state.button == 1 {
         spans.push(Span::styled(" [D]", Style::default().fg(BTN_COLOR)));
     }
 
@@ -331,13 +368,13 @@ fn render_opponent_stack(frame: &mut Frame, app: &App, area: Rect) {
 
 fn render_opponent_cards(frame: &mut Frame, app: &App, area: Rect) {
     let card_data: Vec<[Line<'static>; 5]> = if app.showdown_revealed {
-        app.game_state
-            .bot_cards
+        app.game_state.seats[1]
+            .cards
             .iter()
             .map(|c| render_card_lines(c))
             .collect()
     } else {
-        vec![render_facedown_lines(), render_facedown_lines()]
+        vec![render_facedown_lines(); app.game_state.variant.hole_cards()]
     };
 
     let card_lines = compose_card_row(&card_data, " ");
@@ -345,14 +382,139 @@ fn render_opponent_cards(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(paragraph, area);
 }
 
+/// Animated "thinking" glyph for the bot to act.
+fn thinking_span(app: &App) -> Span<'static> {
+    let elapsed = app.tick_count.wrapping_sub(app.thinking_start_tick);
+    let flower_frames = ["·", "✢", "✳", "✴", "✻", "✽", "✻", "✴", "✳", "✢"];
+    let flower_idx = ((elapsed / 3) % flower_frames.len() as u64) as usize;
+    Span::styled(
+        flower_frames[flower_idx],
+        Style::default().fg(Color::Rgb(150, 160, 230)),
+    )
+}
+
+/// Compact one-line hole cards, e.g. `A♠ K♥`.
+fn mini_card_spans(cards: &[Card]) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    for (i, card) in cards.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(" "));
+        }
+        let color = if card.suit.is_red() {
+            CARD_RED
+        } else {
+            CARD_BLACK
+        };
+        spans.push(Span::styled(
+            format!("{}{}", card.rank.symbol(), card.suit.symbol()),
+            Style::default()
+                .fg(color)
+                .bg(CARD_BG)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    spans
+}
+
+// ── Opponents Panel (multiway) ─────────────────────────────
+
+fn render_opponents_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let opponents: Vec<usize> = (1..app.game_state.num_seats()).collect();
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, opponents.len() as u32); opponents.len()])
+        .split(area);
+
+    for (&seat, &column) in opponents.iter().zip(columns.iter()) {
+        let state = &app.game_state;
+        let info = &state.seats[seat];
+        let acting = app.bot_thinking && state.to_act == seat;
+
+        let name_style = if acting {
+            Style::default().fg(GOLD_BRIGHT).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(LABEL).add_modifier(Modifier::BOLD)
+        };
+        let mut name_line = vec![Span::styled(app.seat_name(seat).to_uppercase(), name_style)];
+        if state.button == seat {
+            name_line.push(Span::styled(" [D]", Style::default().fg(BTN_COLOR)));
+        }
+
+        let stack_line = Line::from(Span::styled(
+            format_bb(info.stack, state.big_blind),
+            Style::default().fg(GOLD),
+        ));
+
+        let shown = app.showdown_revealed
+            && state
+                .showdown_result
+                .as_ref()
+                .is_some_and(|r| r.hand(seat).is_some());
+        let cards_line = if !info.in_hand {
+            Line::from(Span::styled("out", Style::default().fg(DIM)))
+        } else if info.folded {
+            Line::from(Span::styled("folded", Style::default().fg(DIM)))
+        } else if shown {
+            Line::from(mini_card_spans(&info.cards))
+        } else {
+            let backs = vec!["▒▒"; info.cards.len()].join(" ");
+            Line::from(Span::styled(backs, Style::default().fg(CARD_BACK)))
+        };
+
+        let status_line = if app.showdown_result_shown {
+            match state.showdown_result.as_ref().and_then(|r| r.hand(seat).map(|h| (r, h))) {
+                Some((result, hand)) => showdown_indicator_line(result.outcome(seat), &hand.description),
+                None => Line::from(""),
+            }
+        } else if acting {
+            Line::from(thinking_span(app))
+        } else {
+            let mut spans = Vec::new();
+            if let Some(ref action) = app.last_actions[seat] {
+                spans.push(Span::styled(
+                    action_label(action),
+                    Style::default().fg(Color::Rgb(255, 255, 255)),
+                ));
+            }
+            let bet = app.visible_bets[seat];
+            if bet > 0 {
+                if !spans.is_empty() {
+                    spans.push(Span::raw(" "));
+                }
+                spans.push(Span::styled("⦿ ", Style::default().fg(CHIP_FLAT)));
+                spans.push(Span::styled(
+                    format_bb(bet, state.big_blind),
+                    Style::default().fg(GOLD_BRIGHT),
+                ));
+            }
+            Line::from(spans)
+        };
+
+        let lines = vec![
+            Line::from(name_line),
+            Line::from(""),
+            stack_line,
+            cards_line,
+            Line::from(""),
+            status_line,
+        ];
+        frame.render_widget(
+            Paragraph::new(lines)
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true }),
+            column,
+        );
+    }
+}
+
 // ── Board Box (bordered, green felt, pot + to-call inside) ──
 
-fn render_bet_chips(frame: &mut Frame, bet: u32, area: Rect) {
+fn render_bet_chips(frame: &mut Frame, bet: u32, big_blind: u32, area: Rect) {
     if bet == 0 || area.is_empty() {
         return;
     }
 
-    let amount = format_bb(bet);
+    let amount = format_bb(bet, big_blind);
     let amount_style = Style::default().fg(GOLD_BRIGHT).bg(FELT_GREEN);
     // TODO: allow players to customize the chip symbol
     let mut line_spans = vec![Span::styled(
@@ -394,7 +556,9 @@ fn render_board_box(frame: &mut Frame, app: &App, area: Rect) {
         .split(inner);
 
     // Bet chips (use visible snapshots so they persist until card reveal)
-    render_bet_chips(frame, app.visible_bot_bet, inner_chunks[0]);
+    if app.game_state.is_heads_up() {
+        render_bet_chips(frame, app.visible_bets[1], app.game_state.big_blind, inner_chunks[0]);
+    }
 
     // Pot + To Call info line — padded to card-row width so centering stays stable
     let pot_style = Style::default()
@@ -407,10 +571,15 @@ fn render_board_box(frame: &mut Frame, app: &App, area: Rect) {
     } else {
         app.game_state.pot
     };
-    let pot_text = format!("POT: {}", format_bb(display_pot));
-    let to_call = app.game_state.amount_to_call(Player::Human);
+    let big_blind = app.game_state.big_blind;
+    let pot_text = format!("POT: {}", format_bb(display_pot, big_blind));
+    let to_call = if app.game_state.seats[HUMAN].is_active() {
+        app.game_state.amount_to_call(HUMAN)
+    } else {
+        0
+    };
     let call_text = if to_call > 0 {
-        format!("To call: {}", format_bb(to_call))
+        format!("To call: {}", format_bb(to_call, big_blind))
     } else {
         String::new()
     };
@@ -421,13 +590,13 @@ fn render_board_box(frame: &mut Frame, app: &App, area: Rect) {
 
     let mut info_spans: Vec<Span<'static>> = vec![
         Span::styled("POT: ", pot_style),
-        Span::styled(format_bb(display_pot), pot_style),
+        Span::styled(format_bb(display_pot, big_blind), pot_style),
         Span::raw(" ".repeat(padding)),
     ];
     if to_call > 0 {
         info_spans.push(Span::styled("To call: ", Style::default().fg(LABEL)));
         info_spans.push(Span::styled(
-            format_bb(to_call),
+            format_bb(to_call, big_blind),
             Style::default()
                 .fg(ACTION_CALL)
                 .add_modifier(Modifier::BOLD),
@@ -454,7 +623,7 @@ fn render_board_box(frame: &mut Frame, app: &App, area: Rect) {
     let paragraph = Paragraph::new(card_lines).alignment(Alignment::Center);
     frame.render_widget(paragraph, inner_chunks[3]);
 
-    render_bet_chips(frame, app.visible_player_bet, inner_chunks[5]);
+    render_bet_chips(frame, app.visible_bets[HUMAN], app.game_state.big_blind, inner_chunks[5]);
 }
 
 // ── Player Info ────────────────────────────────────────────
@@ -470,22 +639,22 @@ fn action_label(action: &Action) -> &'static str {
     }
 }
 
-fn showdown_indicator_line(winner: Option<Player>, this_player: Player, description: &str) -> Line<'static> {
+fn showdown_indicator_line(outcome: SeatOutcome, description: &str) -> Line<'static> {
     let mut spans = Vec::new();
-    match winner {
-        Some(w) if w == this_player => {
+    match outcome {
+        SeatOutcome::Won => {
             spans.push(Span::styled(
                 "[WIN] ",
                 Style::default().fg(GOLD_BRIGHT).add_modifier(Modifier::BOLD),
             ));
         }
-        None => {
+        SeatOutcome::Split => {
             spans.push(Span::styled(
                 "[TIE] ",
                 Style::default().fg(GOLD_BRIGHT).add_modifier(Modifier::BOLD),
             ));
         }
-        _ => {
+        SeatOutcome::Lost => {
             spans.push(Span::styled(
                 "[LOSE] ",
                 Style::default().fg(Color::Rgb(140, 140, 140)).add_modifier(Modifier::BOLD),
@@ -502,10 +671,12 @@ fn showdown_indicator_line(winner: Option<Player>, this_player: Player, descript
 fn render_player_label(frame: &mut Frame, app: &App, area: Rect) {
     if app.showdown_result_shown {
         if let Some(ref result) = app.game_state.showdown_result {
-            let line = showdown_indicator_line(result.winner, Player::Human, &result.player_hand.description);
-            frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+            if let Some(hand) = result.hand(HUMAN) {
+                let line = showdown_indicator_line(result.outcome(HUMAN), &hand.description);
+                frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+            }
         }
-    } else if let Some(ref action) = app.player_last_action {
+    } else if let Some(ref action) = app.last_actions[HUMAN] {
         let paragraph = Paragraph::new(Line::from(Span::styled(
             action_label(action),
             Style::default().fg(Color::Rgb(255, 255, 255)),
@@ -517,11 +688,11 @@ fn render_player_label(frame: &mut Frame, app: &App, area: Rect) {
 
 fn render_player_stack(frame: &mut Frame, app: &App, area: Rect) {
     let mut spans: Vec<Span<'static>> = vec![Span::styled(
-        format_bb(app.game_state.player_stack),
+        format_bb(app.game_state.seats[HUMAN].stack, app.game_state.big_blind),
         Style::default().fg(GOLD),
     )];
 
-    if app.game_state.button == Player::Human {
+    if app.game_state.button == HUMAN {
         spans.push(Span::styled(" [D]", Style::default().fg(BTN_COLOR)));
     }
 
@@ -534,7 +705,8 @@ fn render_player_stack(frame: &mut Frame, app: &App, area: Rect) {
 fn render_player_cards(frame: &mut Frame, app: &App, area: Rect) {
     let card_data: Vec<[Line<'static>; 5]> = app
         .game_state
-        .player_cards
+        .seats[HUMAN]
+        .cards
         .iter()
         .map(|c| render_card_lines(c))
         .collect();
@@ -555,7 +727,7 @@ fn render_action_bar(frame: &mut Frame, app: &App, area: Rect) {
     let mut spans: Vec<Span<'static>> = Vec::new();
 
     if app.showdown_result_shown {
-        if app.game_state.player_stack == 0 || app.game_state.bot_stack == 0 {
+        if app.game_state.is_session_over() {
             spans.push(Span::styled(
                 " Game Over! Press any key ",
                 Style::default()
@@ -590,22 +762,43 @@ fn render_action_bar(frame: &mut Frame, app: &App, area: Rect) {
         }
         if let Some(amount) = available.can_call {
             spans.push(Span::styled(
-                format!(" C Call {} ", format_bb(amount)),
+                format!(" C Call {} ", format_bb(amount, app.game_state.big_blind)),
                 Style::default().fg(Color::Rgb(255, 255, 255)).bg(ACTION_CALL_BG),
             ));
             spans.push(Span::raw("   "));
         }
+        let fixed_limit = app.game_state.variant.limit() == BettingLimit::FixedLimit;
         if available.min_bet.is_some() || available.min_raise.is_some() {
+            // Limit bets are a fixed size, so show it on the key
+            let label = if !fixed_limit {
+                " R Raise ".to_string()
+            } else if available.min_bet.is_some() {
+                format!(" R Bet {} ", format_bb(available.max_raise, app.game_state.big_blind))
+            } else {
+                format!(
+                    " R Raise to {} ",
+                    format_bb(available.max_raise, app.game_state.big_blind)
+                )
+            };
             spans.push(Span::styled(
-                " R Raise ",
+                label,
                 Style::default().fg(Color::Rgb(255, 255, 255)).bg(ACTION_RAISE_BG),
             ));
             spans.push(Span::raw("   "));
         }
-        spans.push(Span::styled(
-            " A All-in ",
-            Style::default().fg(Color::Rgb(255, 255, 255)).bg(ACTION_ALLIN_BG),
-        ));
+        if !fixed_limit {
+            // Pot-limit caps the shove at the size of the pot
+            let hero = &app.game_state.seats[HUMAN];
+            let label = if available.max_raise < hero.bet + hero.stack {
+                " A Pot "
+            } else {
+                " A All-in "
+            };
+            spans.push(Span::styled(
+                label,
+                Style::default().fg(Color::Rgb(255, 255, 255)).bg(ACTION_ALLIN_BG),
+            ));
+        }
     }
 
     let paragraph = Paragraph::new(Line::from(spans)).alignment(Alignment::Center);
@@ -617,12 +810,15 @@ fn render_raise_bar(
     app: &App,
     available: &crate::game::actions::AvailableActions,
 ) {
+    let big_blind = app.game_state.big_blind;
     let min_chips = available
         .min_raise
-        .unwrap_or(available.min_bet.unwrap_or(2));
-    let min_bb = (min_chips + 1) / 2;
-    let pot_bb = app.game_state.pot / 2;
-    let stack_bb = (app.game_state.player_bet + app.game_state.player_stack) / 2;
+        .unwrap_or(available.min_bet.unwrap_or(big_blind));
+    let min_bb = min_chips.div_ceil(big_blind);
+    let pot_bb = app.game_state.pot / big_blind;
+    let hero = &app.game_state.seats[HUMAN];
+    let stack_bb = (hero.bet + hero.stack) / big_blind;
+    let capped = available.max_raise < hero.bet + hero.stack;
 
     spans.push(Span::styled(
         "Raise to: ",
@@ -644,7 +840,9 @@ fn render_raise_bar(
                 .fg(BRIGHT_WHITE)
                 .add_modifier(Modifier::BOLD),
         ));
-        if typed_bb > 0 && typed_bb * 2 >= app.game_state.player_bet + app.game_state.player_stack {
+        if typed_bb > 0 && capped && typed_bb * big_blind >= available.max_raise {
+            spans.push(Span::styled(" (pot)", Style::default().fg(GOLD)));
+        } else if typed_bb > 0 && typed_bb * big_blind >= hero.bet + hero.stack {
             spans.push(Span::styled(" (all-in)", Style::default().fg(GOLD)));
         } else if typed_bb > 0 && typed_bb < min_bb {
             spans.push(Span::styled(
@@ -656,13 +854,20 @@ fn render_raise_bar(
 
     spans.push(Span::styled("BB", Style::default().fg(BRIGHT_WHITE)));
 
-    spans.push(Span::styled(
+    let limits = if capped {
+        format!(
+            "          min {}BB · max {}BB · stack {}BB",
+            min_bb,
+            available.max_raise / big_blind,
+            stack_bb
+        )
+    } else {
         format!(
             "          min {}BB · pot {}BB · stack {}BB",
             min_bb, pot_bb, stack_bb
-        ),
-        Style::default().fg(DIM),
-    ));
+        )
+    };
+    spans.push(Span::styled(limits, Style::default().fg(DIM)));
 
     spans.push(Span::styled(
         "          Esc cancel",
@@ -758,7 +963,11 @@ fn render_help_overlay(frame: &mut Frame) {
         ]),
         Line::from(vec![
             Span::styled("  A ", key_style),
-            Span::styled("All-in", desc_style),
+            Span::styled("All-in (pot in pot-limit)", desc_style),
+        ]),
+        Line::from(vec![
+            Span::styled("  R ", key_style),
+            Span::styled("Bet/raise the fixed amount (limit)", desc_style),
         ]),
         Line::from(""),
         Line::from(Span::styled("Raise Mode", section_style)),
@@ -790,6 +999,10 @@ fn render_help_overlay(frame: &mut Frame) {
             Span::styled("Q ", key_style),
             Span::styled("Quit", desc_style),
         ]),
+        Line::from(vec![
+            Span::styled("  W ", key_style),
+            Span::styled("Why the bot played the last hand as it did", desc_style),
+        ]),
         Line::from(""),
         Line::from(Span::styled("Press ? to close", Style::default().fg(DIM))),
     ];
@@ -865,17 +1078,79 @@ fn render_stats_overlay(frame: &mut Frame, app: &App) {
     frame.render_widget(paragraph, area);
 }
 
+fn render_reasoning_overlay(frame: &mut Frame, app: &App) {
+    let area = centered_rect(70, 80, frame.area());
+    frame.render_widget(Clear, area);
+
+    let street_style = Style::default().fg(GOLD).add_modifier(Modifier::BOLD);
+    let action_style = Style::default()
+        .fg(Color::Rgb(255, 255, 255))
+        .add_modifier(Modifier::BOLD);
+    let reason_style = Style::default().fg(Color::Rgb(180, 180, 180));
+
+    let explanations = app.explanations();
+    let mut lines = vec![Line::from("")];
+    if explanations.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  Nothing to explain yet. The rule-based bot's reasoning shows here once a hand is over.",
+            reason_style,
+        )));
+    }
+    for explanation in explanations {
+        let name = app.seat_name(explanation.seat);
+        let action = explanation
+            .reasoning
+            .action
+            .description_for(&name, app.game_state.big_blind);
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", explanation.street), street_style),
+            Span::styled(format!("{} {}", name, action), action_style),
+        ]));
+        for line in explanation.reasoning.lines() {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", line),
+                reason_style,
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        "Press W to close",
+        Style::default().fg(DIM),
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(overlay_block("Why"))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
 
 fn render_session_end_overlay(frame: &mut Frame, app: &App) {
     let area = centered_rect(50, 50, frame.area());
     frame.render_widget(Clear, area);
 
-    let winner = if app.game_state.player_stack == 0 {
-        "You busted!"
+    let busted = app.game_state.seats[HUMAN].stack == 0;
+    let big_blind = app.game_state.big_blind;
+    let winner = if app.game_state.tournament.is_some() {
+        let position = app.game_state.finishing_position();
+        if position == 1 {
+            "You won the tournament!".to_string()
+        } else {
+            format!(
+                "You finished {} of {}",
+                ordinal(position),
+                app.game_state.num_seats()
+            )
+        }
+    } else if busted {
+        "You busted!".to_string()
+    } else if app.game_state.is_heads_up() {
+        "Bot busted! You win!".to_string()
     } else {
-        "Bot busted! You win!"
+        "All bots busted! You win!".to_string()
     };
-    let winner_color = if app.game_state.player_stack == 0 {
+    let winner_color = if busted {
         ACTION_FOLD
     } else {
         ACTION_CHECK
@@ -914,11 +1189,11 @@ fn render_session_end_overlay(frame: &mut Frame, app: &App) {
         ]),
         Line::from(vec![
             Span::styled("  Biggest pot won: ", label_style),
-            Span::styled(format_bb(app.game_state.biggest_pot_won), value_style),
+            Span::styled(format_bb(app.game_state.biggest_pot_won, big_blind), value_style),
         ]),
         Line::from(vec![
             Span::styled("  Biggest pot lost: ", label_style),
-            Span::styled(format_bb(app.game_state.biggest_pot_lost), value_style),
+            Span::styled(format_bb(app.game_state.biggest_pot_lost, big_blind), value_style),
         ]),
         Line::from(""),
         Line::from(vec![